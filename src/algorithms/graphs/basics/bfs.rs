@@ -1,5 +1,5 @@
 use super::LIST_GRAPH;
-use std::collections::{HashSet, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 /// Implementa el algoritmo de Búsqueda en Anchura (BFS - Breadth-First Search)
 /// sobre el grafo estático LIST_GRAPH definido en el módulo padre.
@@ -78,9 +78,64 @@ pub fn bfs() {
     }
 }
 
+/// Resultado de un recorrido BFS desde un nodo de inicio, con la información
+/// necesaria para reconstruir todos los caminos más cortos.
+#[derive(Debug, Default)]
+pub struct BfsTree {
+    /// Nodos alcanzados en el orden en que fueron visitados (por niveles)
+    pub order: Vec<usize>,
+    /// Cantidad de aristas del camino más corto desde el inicio hasta cada nodo
+    pub distance: HashMap<usize, usize>,
+    /// Para cada nodo, los nodos del nivel anterior que lo alcanzan con una
+    /// arista; es decir, sus predecesores en algún camino más corto
+    pub predecessors: HashMap<usize, Vec<usize>>,
+}
+
+/// Ejecuta BFS desde `start` sobre el grafo recibido y retorna el árbol de
+/// caminos más cortos en vez de imprimir el recorrido.
+///
+/// # Características:
+/// - Mismo recorrido por niveles que [`bfs`], pero sin depender de `LIST_GRAPH`
+/// - Registra la distancia de cada nodo alcanzado y todos sus predecesores en
+///   caminos más cortos, no solo el primero que lo descubrió
+/// - Los nodos que no aparecen en `distance` no son alcanzables desde `start`
+pub fn bfs_tree(graph: &HashMap<usize, Vec<usize>>, start: usize) -> BfsTree {
+    let mut tree = BfsTree::default();
+    let mut queue: VecDeque<usize> = VecDeque::new();
+
+    tree.distance.insert(start, 0);
+    tree.predecessors.insert(start, Vec::new());
+    queue.push_back(start);
+
+    while let Some(node) = queue.pop_front() {
+        tree.order.push(node);
+        let next_distance = tree.distance[&node] + 1;
+
+        if let Some(neighbors) = graph.get(&node) {
+            for &neighbor in neighbors {
+                match tree.distance.get(&neighbor) {
+                    // Primera vez que lo vemos: queda en el siguiente nivel
+                    None => {
+                        tree.distance.insert(neighbor, next_distance);
+                        tree.predecessors.insert(neighbor, vec![node]);
+                        queue.push_back(neighbor);
+                    }
+                    // Ya estaba en el siguiente nivel: otro camino más corto
+                    Some(&distance) if distance == next_distance => {
+                        tree.predecessors.entry(neighbor).or_default().push(node);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+
+    tree
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::algorithms::graphs::basics::fill_list_graph;
+    use crate::algorithms::graphs::basics::{fill_list_graph, list_graph};
 
     use super::*;
 
@@ -164,4 +219,25 @@ mod tests {
         // - Confirmar el orden de exploración
         // - Validar que no hay duplicados en los nodos visitados
     }
+
+    /// Test que verifica las distancias y predecesores que calcula bfs_tree()
+    /// sobre el mismo grafo que construye fill_list_graph().
+    #[test]
+    fn test_bfs_tree() {
+        let graph = list_graph();
+
+        let tree = bfs_tree(&graph, 0);
+
+        // Los niveles desde el nodo 0 son {0}, {1, 2}, {3}, {4}
+        assert_eq!(tree.order, vec![0, 1, 2, 3, 4]);
+        assert_eq!(tree.distance[&3], 2);
+        assert_eq!(tree.distance[&4], 3);
+        // El nodo 3 se alcanza en dos pasos tanto por 1 como por 2
+        assert_eq!(tree.predecessors[&3], vec![1, 2]);
+        assert_eq!(tree.predecessors[&0], Vec::<usize>::new());
+
+        // Desde el nodo 5, que no existe en el grafo, solo se alcanza él mismo
+        let tree = bfs_tree(&graph, 5);
+        assert_eq!(tree.order, vec![5]);
+    }
 }
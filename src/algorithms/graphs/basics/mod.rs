@@ -2,10 +2,19 @@ use std::collections::HashMap;
 
 pub mod bfs;
 pub mod dfs;
+pub mod parallel_bfs;
 
 pub static mut LIST_GRAPH: Option<HashMap<usize, Vec<usize>>> = None;
 
 pub fn fill_list_graph() {
+    unsafe {
+        LIST_GRAPH = Some(list_graph());
+    }
+}
+
+/// El grafo de ejemplo que [`fill_list_graph`] guarda en `LIST_GRAPH`, como
+/// un valor propio: sirve para usarlo sin pasar por la variable estática.
+pub fn list_graph() -> HashMap<usize, Vec<usize>> {
     let mut graph: HashMap<usize, Vec<usize>> = HashMap::new();
 
     // Crear nodos y aristas (incluyendo ciclos)
@@ -15,7 +24,5 @@ pub fn fill_list_graph() {
     graph.insert(3, vec![2, 4]); // Ciclo: 1 -> 3 -> 2 -> 1
     graph.insert(4, vec![0, 3]); // Ciclos: 3 -> 4 -> 0 -> 1 -> 3
 
-    unsafe {
-        LIST_GRAPH = Some(graph);
-    }
+    graph
 }
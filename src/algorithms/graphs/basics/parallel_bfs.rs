@@ -0,0 +1,209 @@
+use crate::algorithms::graphs::nodes;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+// Marca de "nodo no visitado" en el arreglo de distancias
+const UNVISITED: usize = usize::MAX;
+
+// Por debajo de este tamaño de frontera no compensa lanzar hilos
+const SEQUENTIAL_FRONTIER: usize = 1024;
+
+/// Implementa BFS paralelo sincronizado por niveles sobre un grafo con nodos
+/// numerados de `0` a `adjacency.len() - 1`.
+///
+/// En cada nivel la frontera (los nodos a distancia `d`) se reparte entre
+/// `threads` hilos de `std::thread::scope`. Cada hilo recorre los vecinos de
+/// su parte y reclama los no visitados con una operación atómica; el hilo que
+/// gana la reclamación agrega el nodo a su parte de la siguiente frontera. Al
+/// terminar el nivel se juntan las partes y se pasa al nivel `d + 1`.
+///
+/// # Características:
+/// - Produce exactamente las mismas distancias que la versión secuencial:
+///   un nodo solo puede reclamarse desde el nivel anterior, así que su
+///   distancia es la del primer nivel que lo alcanza
+/// - La marca de visitado es la propia distancia (`AtomicUsize`), reclamada
+///   con `compare_exchange` para que cada nodo entre una sola vez a la frontera
+/// - Las fronteras pequeñas se procesan en el hilo actual para no pagar el
+///   costo de crear hilos
+/// - Retorna la distancia de cada nodo, o `None` si no es alcanzable
+///
+/// # Panics
+/// Si `start` no es menor que `adjacency.len()`.
+pub fn parallel_bfs(adjacency: &[Vec<usize>], start: usize, threads: usize) -> Vec<Option<usize>> {
+    let distance: Vec<AtomicUsize> = (0..adjacency.len())
+        .map(|_| AtomicUsize::new(UNVISITED))
+        .collect();
+    let threads = threads.max(1);
+
+    distance[start].store(0, Ordering::Relaxed);
+    let mut frontier = vec![start];
+    let mut level = 0;
+
+    while !frontier.is_empty() {
+        level += 1;
+
+        frontier = if threads == 1 || frontier.len() < SEQUENTIAL_FRONTIER {
+            expand(adjacency, &distance, &frontier, level)
+        } else {
+            // Cada hilo expande un trozo contiguo de la frontera
+            let chunk = frontier.len().div_ceil(threads);
+            thread::scope(|scope| {
+                let workers: Vec<_> = frontier
+                    .chunks(chunk)
+                    .map(|part| scope.spawn(|| expand(adjacency, &distance, part, level)))
+                    .collect();
+
+                workers
+                    .into_iter()
+                    .flat_map(|worker| worker.join().expect("un hilo del BFS entró en pánico"))
+                    .collect()
+            })
+        };
+    }
+
+    distance
+        .into_iter()
+        .map(|d| match d.into_inner() {
+            UNVISITED => None,
+            d => Some(d),
+        })
+        .collect()
+}
+
+/// Versión de [`parallel_bfs`] para los grafos en listas de adyacencia del
+/// proyecto. Retorna la distancia desde `start` a cada nodo alcanzable, igual
+/// que el campo `distance` de [`bfs_tree`](super::bfs::bfs_tree).
+///
+/// Primero numera los nodos de forma consecutiva (trabajo secuencial O(m)) y
+/// luego ejecuta el recorrido paralelo sobre vectores.
+pub fn parallel_bfs_distances(
+    graph: &HashMap<usize, Vec<usize>>,
+    start: usize,
+    threads: usize,
+) -> HashMap<usize, usize> {
+    let mut nodes = nodes(graph);
+    if nodes.binary_search(&start).is_err() {
+        // El nodo de inicio no está en el grafo: solo se alcanza a sí mismo
+        nodes = vec![start];
+    }
+
+    let index: HashMap<usize, usize> = nodes.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+    let adjacency: Vec<Vec<usize>> = nodes
+        .iter()
+        .map(|node| {
+            graph
+                .get(node)
+                .map(|neighbors| neighbors.iter().map(|neighbor| index[neighbor]).collect())
+                .unwrap_or_default()
+        })
+        .collect();
+
+    parallel_bfs(&adjacency, index[&start], threads)
+        .into_iter()
+        .zip(nodes)
+        .filter_map(|(distance, node)| distance.map(|d| (node, d)))
+        .collect()
+}
+
+/// Expande una parte de la frontera: reclama los vecinos no visitados con la
+/// distancia `level` y los retorna como parte de la siguiente frontera.
+fn expand(
+    adjacency: &[Vec<usize>],
+    distance: &[AtomicUsize],
+    frontier: &[usize],
+    level: usize,
+) -> Vec<usize> {
+    let mut next = Vec::new();
+
+    for &node in frontier {
+        for &neighbor in &adjacency[node] {
+            // Una lectura barata evita el compare_exchange en nodos ya visitados
+            if distance[neighbor].load(Ordering::Relaxed) == UNVISITED
+                && distance[neighbor]
+                    .compare_exchange(UNVISITED, level, Ordering::Relaxed, Ordering::Relaxed)
+                    .is_ok()
+            {
+                next.push(neighbor);
+            }
+        }
+    }
+
+    next
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::graphs::basics::bfs::bfs_tree;
+    use crate::algorithms::graphs::basics::list_graph;
+    use crate::algorithms::sorting::property::Rng;
+
+    // Grafo dirigido aleatorio reproducible (generador congruencial lineal)
+    fn random_graph(n: usize, edges: usize, seed: u64) -> HashMap<usize, Vec<usize>> {
+        let mut rng = Rng::new(seed);
+        let mut next = || rng.below(n);
+
+        let mut graph: HashMap<usize, Vec<usize>> = HashMap::new();
+        for _ in 0..edges {
+            let (u, v) = (next(), next());
+            graph.entry(u).or_default().push(v);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_igual_al_secuencial() {
+        // Grafos de distintas densidades, incluidos algunos con nodos inalcanzables
+        for (seed, (n, edges)) in [(200, 150), (2_000, 6_000), (20_000, 100_000)]
+            .into_iter()
+            .enumerate()
+        {
+            let graph = random_graph(n, edges, seed as u64);
+            let start = *graph.keys().min().unwrap();
+            let expected = bfs_tree(&graph, start).distance;
+
+            for threads in [1, 2, 4, 8] {
+                assert_eq!(parallel_bfs_distances(&graph, start, threads), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_grafo_de_ejemplo() {
+        let graph = list_graph();
+
+        let distances = parallel_bfs_distances(&graph, 0, 4);
+        assert_eq!(
+            distances,
+            HashMap::from([(0, 0), (1, 1), (2, 1), (3, 2), (4, 3)])
+        );
+        assert_eq!(
+            parallel_bfs_distances(&graph, 9, 4),
+            HashMap::from([(9, 0)])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inicio_fuera_de_rango() {
+        parallel_bfs(&[vec![1], vec![0]], 2, 4);
+    }
+
+    #[test]
+    fn test_fronteras_grandes() {
+        // Una estrella con 5000 hojas y luego una cadena desde cada hoja:
+        // el segundo y tercer nivel superan el umbral y se reparten entre hilos
+        let leaves = 5_000;
+        let mut adjacency: Vec<Vec<usize>> = vec![(1..=leaves).collect()];
+        for leaf in 1..=leaves {
+            adjacency.push(vec![leaf + leaves]);
+        }
+        adjacency.extend((0..leaves).map(|_| Vec::new()));
+
+        let distances = parallel_bfs(&adjacency, 0, 4);
+        assert_eq!(distances[0], Some(0));
+        assert!(distances[1..=leaves].iter().all(|&d| d == Some(1)));
+        assert!(distances[leaves + 1..].iter().all(|&d| d == Some(2)));
+    }
+}
@@ -0,0 +1,106 @@
+use crate::algorithms::graphs::basics::bfs::bfs_tree;
+use crate::algorithms::graphs::nodes;
+use std::collections::HashMap;
+
+/// Calcula la centralidad de intermediación (betweenness) de cada nodo con el
+/// algoritmo de Brandes.
+///
+/// La intermediación de `v` suma, para cada par de nodos `(s, t)` distintos de
+/// `v`, la fracción de caminos más cortos de `s` a `t` que pasan por `v`.
+///
+/// # Características:
+/// - Un recorrido [`bfs_tree`] por nodo de origen: tiempo O(n · m) en grafos
+///   sin pesos
+/// - A partir del árbol de cada BFS se cuentan los caminos más cortos (σ) en
+///   orden de visita y luego se acumulan las dependencias en orden inverso
+/// - El grafo se trata como dirigido; en un grafo no dirigido guardado con
+///   ambas direcciones cada par se cuenta dos veces, por lo que los valores
+///   son el doble de la convención no dirigida
+/// - Con `normalized` se divide por `(n - 1)(n - 2)`, el número de pares
+///   ordenados que no incluyen al nodo
+pub fn betweenness_centrality(
+    graph: &HashMap<usize, Vec<usize>>,
+    normalized: bool,
+) -> HashMap<usize, f64> {
+    let nodes = nodes(graph);
+    let mut centrality: HashMap<usize, f64> = nodes.iter().map(|&node| (node, 0.0)).collect();
+
+    for &source in &nodes {
+        let tree = bfs_tree(graph, source);
+
+        // σ[v]: cantidad de caminos más cortos desde source hasta v. Los
+        // predecesores se visitan antes, así que basta con recorrer en orden
+        let mut paths: HashMap<usize, f64> = HashMap::new();
+        paths.insert(source, 1.0);
+        for &node in &tree.order[1..] {
+            let count = tree.predecessors[&node].iter().map(|p| paths[p]).sum();
+            paths.insert(node, count);
+        }
+
+        // δ[v]: dependencia de source en v, acumulada desde los nodos más lejanos
+        let mut dependency: HashMap<usize, f64> = HashMap::new();
+        for &node in tree.order.iter().rev() {
+            let delta = dependency.get(&node).copied().unwrap_or(0.0);
+            for predecessor in &tree.predecessors[&node] {
+                let share = paths[predecessor] / paths[&node] * (1.0 + delta);
+                *dependency.entry(*predecessor).or_default() += share;
+            }
+            if node != source {
+                *centrality.get_mut(&node).unwrap() += delta;
+            }
+        }
+    }
+
+    let n = nodes.len() as f64;
+    if normalized && n > 2.0 {
+        let scale = 1.0 / ((n - 1.0) * (n - 2.0));
+        for value in centrality.values_mut() {
+            *value *= scale;
+        }
+    }
+
+    centrality
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::graphs::basics::list_graph;
+    use crate::algorithms::graphs::fixtures::undirected;
+
+    #[test]
+    fn test_camino() {
+        // En el camino 0 - 1 - 2 solo el nodo central intermedia (pares 0→2 y 2→0)
+        let graph = undirected(&[(0, 1), (1, 2)]);
+
+        let centrality = betweenness_centrality(&graph, false);
+        assert_eq!(centrality[&0], 0.0);
+        assert_eq!(centrality[&1], 2.0);
+        assert_eq!(centrality[&2], 0.0);
+
+        let normalized = betweenness_centrality(&graph, true);
+        assert_eq!(normalized[&1], 1.0);
+    }
+
+    #[test]
+    fn test_caminos_repartidos() {
+        // Cuadrado 0 - 1 - 3 - 2 - 0: de 0 a 3 hay dos caminos, por 1 y por 2,
+        // así que cada uno recibe la mitad de ese par (y del par 3 → 0)
+        let graph = undirected(&[(0, 1), (0, 2), (1, 3), (2, 3)]);
+
+        let centrality = betweenness_centrality(&graph, false);
+        for node in 0..4 {
+            assert!((centrality[&node] - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_grafo_dirigido() {
+        // El grafo de list_graph: 3 es el único paso hacia 4
+        let graph = list_graph();
+
+        let centrality = betweenness_centrality(&graph, false);
+        let max = centrality.values().copied().fold(0.0, f64::max);
+        assert_eq!(centrality[&3], max);
+    }
+}
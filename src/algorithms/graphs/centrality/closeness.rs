@@ -0,0 +1,95 @@
+use crate::algorithms::graphs::basics::bfs::bfs_tree;
+use crate::algorithms::graphs::nodes;
+use std::collections::HashMap;
+
+/// Calcula la centralidad de cercanía (closeness) de cada nodo.
+///
+/// Para un nodo `u` que alcanza a `r` nodos además de sí mismo, con suma de
+/// distancias `D`, la cercanía es `r / D`: la inversa de la distancia media.
+/// Para no favorecer a los nodos que alcanzan a muy pocos, el valor se escala
+/// por `r / (n - 1)` (corrección de Wasserman y Faust).
+///
+/// # Características:
+/// - Un recorrido [`bfs_tree`] por nodo: tiempo O(n · m)
+/// - Usa las distancias de salida de cada nodo (aristas en su sentido)
+/// - Un nodo que no alcanza a ningún otro tiene cercanía 0
+pub fn closeness_centrality(graph: &HashMap<usize, Vec<usize>>) -> HashMap<usize, f64> {
+    let nodes = nodes(graph);
+    let n = nodes.len();
+
+    nodes
+        .iter()
+        .map(|&node| {
+            let tree = bfs_tree(graph, node);
+            let reached = (tree.order.len() - 1) as f64;
+            let total: usize = tree.distance.values().sum();
+
+            let closeness = if total == 0 {
+                0.0
+            } else {
+                (reached / total as f64) * (reached / (n - 1) as f64)
+            };
+            (node, closeness)
+        })
+        .collect()
+}
+
+/// Calcula la centralidad armónica de cada nodo: la suma de `1 / d(u, v)`
+/// sobre todos los demás nodos `v`.
+///
+/// A diferencia de la cercanía, los nodos inalcanzables simplemente aportan 0,
+/// por lo que se comporta bien en grafos no conexos sin ninguna corrección.
+pub fn harmonic_centrality(graph: &HashMap<usize, Vec<usize>>) -> HashMap<usize, f64> {
+    nodes(graph)
+        .into_iter()
+        .map(|node| {
+            let tree = bfs_tree(graph, node);
+            let harmonic = tree
+                .distance
+                .values()
+                .filter(|&&distance| distance > 0)
+                .map(|&distance| 1.0 / distance as f64)
+                .sum();
+            (node, harmonic)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::graphs::fixtures::undirected;
+
+    #[test]
+    fn test_cercania_en_camino() {
+        // Camino 0 - 1 - 2: el centro está a distancia 1 de todos
+        let graph = undirected(&[(0, 1), (1, 2)]);
+
+        let closeness = closeness_centrality(&graph);
+        assert_eq!(closeness[&1], 1.0);
+        assert!((closeness[&0] - 2.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cercania_no_conexo() {
+        // Dos componentes: 0 - 1 y el nodo 2 aislado (solo como vecino de sí mismo)
+        let mut graph = undirected(&[(0, 1)]);
+        graph.insert(2, vec![]);
+
+        let closeness = closeness_centrality(&graph);
+        // Alcanza a 1 de 2 nodos a distancia 1: (1 / 1) * (1 / 2)
+        assert_eq!(closeness[&0], 0.5);
+        assert_eq!(closeness[&2], 0.0);
+    }
+
+    #[test]
+    fn test_armonica() {
+        let mut graph = undirected(&[(0, 1), (1, 2)]);
+        graph.insert(3, vec![]);
+
+        let harmonic = harmonic_centrality(&graph);
+        assert_eq!(harmonic[&0], 1.5);
+        assert_eq!(harmonic[&1], 2.0);
+        assert_eq!(harmonic[&3], 0.0);
+    }
+}
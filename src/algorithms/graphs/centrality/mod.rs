@@ -0,0 +1,3 @@
+pub mod betweenness;
+pub mod closeness;
+pub mod pagerank;
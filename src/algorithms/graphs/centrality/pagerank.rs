@@ -0,0 +1,117 @@
+use crate::algorithms::graphs::nodes;
+use std::collections::HashMap;
+
+/// Calcula el PageRank de cada nodo de un grafo dirigido por iteración de potencias.
+///
+/// El PageRank modela a un navegante que en cada paso sigue una arista al azar
+/// con probabilidad `damping`, o salta a un nodo cualquiera con probabilidad
+/// `1 - damping`. El valor de cada nodo es la probabilidad de encontrarlo ahí
+/// a largo plazo.
+///
+/// # Características:
+/// - Parte de la distribución uniforme y repite el paso hasta que la suma de
+///   las diferencias absolutas entre dos iteraciones es menor que `tolerance`,
+///   o hasta agotar `max_iterations`
+/// - Los nodos sin aristas salientes reparten su valor entre todos los nodos,
+///   así la suma de los valores se mantiene en 1
+/// - Las aristas repetidas en una lista cuentan tantas veces como aparezcan
+/// - Un valor típico de `damping` es 0.85
+pub fn pagerank(
+    graph: &HashMap<usize, Vec<usize>>,
+    damping: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> HashMap<usize, f64> {
+    let nodes = nodes(graph);
+    let n = nodes.len();
+    if n == 0 {
+        return HashMap::new();
+    }
+
+    // Trabajamos con índices 0..n para usar vectores en lugar de mapas
+    let index: HashMap<usize, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, &node)| (node, i))
+        .collect();
+    let out_edges: Vec<Vec<usize>> = nodes
+        .iter()
+        .map(|node| {
+            graph
+                .get(node)
+                .map(|neighbors| neighbors.iter().map(|neighbor| index[neighbor]).collect())
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let uniform = 1.0 / n as f64;
+    let mut rank = vec![uniform; n];
+
+    for _ in 0..max_iterations {
+        // Valor de los nodos sin salida, que se reparte entre todos
+        let dangling: f64 = (0..n)
+            .filter(|&i| out_edges[i].is_empty())
+            .map(|i| rank[i])
+            .sum();
+        let base = (1.0 - damping) * uniform + damping * dangling * uniform;
+        let mut next = vec![base; n];
+
+        for (i, neighbors) in out_edges.iter().enumerate() {
+            let share = damping * rank[i] / neighbors.len() as f64;
+            for &j in neighbors {
+                next[j] += share;
+            }
+        }
+
+        let change: f64 = next.iter().zip(&rank).map(|(a, b)| (a - b).abs()).sum();
+        rank = next;
+        if change < tolerance {
+            break;
+        }
+    }
+
+    nodes.into_iter().zip(rank).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ciclo_uniforme() {
+        // En un ciclo dirigido todos los nodos son equivalentes
+        let mut graph: HashMap<usize, Vec<usize>> = HashMap::new();
+        for node in 0..4 {
+            graph.insert(node, vec![(node + 1) % 4]);
+        }
+
+        let rank = pagerank(&graph, 0.85, 1e-12, 100);
+        for node in 0..4 {
+            assert!((rank[&node] - 0.25).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_estrella() {
+        // Todas las hojas apuntan al centro y el centro no tiene salida
+        let mut graph: HashMap<usize, Vec<usize>> = HashMap::new();
+        for leaf in 1..5 {
+            graph.insert(leaf, vec![0]);
+        }
+
+        let rank = pagerank(&graph, 0.85, 1e-12, 1000);
+        let total: f64 = rank.values().sum();
+        assert!((total - 1.0).abs() < 1e-9);
+
+        // El centro acumula más valor que cualquier hoja, y las hojas son iguales entre sí
+        for leaf in 1..5 {
+            assert!(rank[&0] > rank[&leaf]);
+            assert!((rank[&leaf] - rank[&1]).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_grafo_vacio() {
+        assert!(pagerank(&HashMap::new(), 0.85, 1e-9, 100).is_empty());
+    }
+}
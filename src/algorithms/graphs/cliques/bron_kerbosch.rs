@@ -0,0 +1,248 @@
+use super::{undirected, UndirectedGraph};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Implementa el algoritmo de Bron–Kerbosch para enumerar los cliques maximales
+/// de un grafo no dirigido.
+///
+/// Un clique es un conjunto de nodos conectados todos entre sí, y es maximal si
+/// no se puede agregar ningún otro nodo sin romper esa propiedad.
+///
+/// # Características:
+/// - Trata el grafo como no dirigido (ver `undirected` en el módulo padre)
+/// - Usa pivoteo de Tomita: solo se ramifica sobre los candidatos que no son
+///   vecinos del pivote, lo que evita explorar cliques repetidos
+/// - Recorre el nivel exterior en orden de degeneración, acotando el tamaño de
+///   los conjuntos de candidatos por la degeneración del grafo
+/// - Es un iterador perezoso: cada clique se calcula al pedirlo, por lo que se
+///   puede cortar la enumeración en cualquier momento (`take`, `find`, ...)
+/// - Cada clique se entrega con sus nodos ordenados de menor a mayor
+pub fn maximal_cliques(graph: &HashMap<usize, Vec<usize>>) -> MaximalCliques {
+    MaximalCliques::new(undirected(graph))
+}
+
+/// Busca un clique de tamaño máximo en el grafo.
+///
+/// Recorre todos los cliques maximales y se queda con el más grande; si hay
+/// empate se devuelve el primero encontrado. Para un grafo vacío se retorna
+/// un vector vacío.
+pub fn maximum_clique(graph: &HashMap<usize, Vec<usize>>) -> Vec<usize> {
+    largest(maximal_cliques(graph))
+}
+
+// Se queda con el primer clique de mayor tamaño de la enumeración
+pub(super) fn largest(cliques: MaximalCliques) -> Vec<usize> {
+    cliques.fold(Vec::new(), |best, clique| {
+        if clique.len() > best.len() {
+            clique
+        } else {
+            best
+        }
+    })
+}
+
+// Estado de una llamada recursiva de Bron–Kerbosch, guardado en una pila explícita
+struct Frame {
+    clique: Vec<usize>,          // R: nodos del clique que se está construyendo
+    candidates: BTreeSet<usize>, // P: nodos que aún pueden extender el clique
+    excluded: BTreeSet<usize>,   // X: nodos ya explorados desde este estado
+    pending: Vec<usize>,         // Nodos de P sobre los que falta ramificar
+}
+
+/// Iterador sobre los cliques maximales de un grafo, creado por [`maximal_cliques`].
+///
+/// La recursión de Bron–Kerbosch se simula con una pila de estados para poder
+/// suspender la búsqueda cada vez que se encuentra un clique.
+pub struct MaximalCliques {
+    adjacency: UndirectedGraph,
+    stack: Vec<Frame>,
+}
+
+impl MaximalCliques {
+    pub(super) fn new(adjacency: UndirectedGraph) -> Self {
+        // El estado raíz ramifica sobre todos los nodos en orden de degeneración.
+        // Al recorrerlos en ese orden, cada nodo v_i tiene como candidatos a sus
+        // vecinos posteriores y como excluidos a sus vecinos anteriores.
+        let mut pending = degeneracy_order(&adjacency);
+        // Los nodos se extraen del final de `pending`, así que lo invertimos
+        pending.reverse();
+
+        let root = Frame {
+            clique: Vec::new(),
+            candidates: adjacency.keys().copied().collect(),
+            excluded: BTreeSet::new(),
+            pending,
+        };
+
+        Self {
+            adjacency,
+            stack: vec![root],
+        }
+    }
+}
+
+impl Iterator for MaximalCliques {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(frame) = self.stack.last_mut() {
+            // Si no quedan nodos sobre los que ramificar, este estado terminó
+            let Some(node) = frame.pending.pop() else {
+                self.stack.pop();
+                continue;
+            };
+
+            let neighbors = &self.adjacency[&node];
+
+            // R ∪ {v}, P ∩ N(v) y X ∩ N(v) para la llamada recursiva
+            let mut clique = frame.clique.clone();
+            clique.push(node);
+            let candidates: BTreeSet<usize> =
+                frame.candidates.intersection(neighbors).copied().collect();
+            let excluded: BTreeSet<usize> =
+                frame.excluded.intersection(neighbors).copied().collect();
+
+            // Al volver de la recursión, v pasa de P a X
+            frame.candidates.remove(&node);
+            frame.excluded.insert(node);
+
+            if candidates.is_empty() {
+                // Si X también está vacío, ningún nodo puede extender R: es maximal
+                if excluded.is_empty() {
+                    clique.sort_unstable();
+                    return Some(clique);
+                }
+                continue;
+            }
+
+            let pending = pivot_branches(&self.adjacency, &candidates, &excluded);
+            self.stack.push(Frame {
+                clique,
+                candidates,
+                excluded,
+                pending,
+            });
+        }
+
+        None
+    }
+}
+
+/// Elige como pivote el nodo de P ∪ X con más vecinos en P y retorna los
+/// candidatos que no son vecinos del pivote, que son los únicos sobre los
+/// que hace falta ramificar.
+fn pivot_branches(
+    adjacency: &UndirectedGraph,
+    candidates: &BTreeSet<usize>,
+    excluded: &BTreeSet<usize>,
+) -> Vec<usize> {
+    let pivot_neighbors = candidates
+        .iter()
+        .chain(excluded)
+        .map(|pivot| &adjacency[pivot])
+        .max_by_key(|neighbors| candidates.intersection(neighbors).count());
+
+    // Se invierte el orden para que los nodos se extraigan de menor a mayor
+    candidates
+        .iter()
+        .rev()
+        .filter(|node| pivot_neighbors.is_none_or(|neighbors| !neighbors.contains(node)))
+        .copied()
+        .collect()
+}
+
+/// Calcula un orden de degeneración: se retira repetidamente el nodo de menor
+/// grado en el subgrafo restante.
+fn degeneracy_order(adjacency: &UndirectedGraph) -> Vec<usize> {
+    let mut degree: BTreeMap<usize, usize> = adjacency
+        .iter()
+        .map(|(&node, neighbors)| (node, neighbors.len()))
+        .collect();
+    // Cola ordenada por (grado, nodo) para extraer siempre el de menor grado
+    let mut queue: BTreeSet<(usize, usize)> =
+        degree.iter().map(|(&node, &deg)| (deg, node)).collect();
+    let mut order = Vec::with_capacity(adjacency.len());
+
+    while let Some((_, node)) = queue.pop_first() {
+        order.push(node);
+        degree.remove(&node);
+
+        // Los vecinos que siguen en el subgrafo pierden una arista
+        for neighbor in &adjacency[&node] {
+            if let Some(deg) = degree.get_mut(neighbor) {
+                queue.remove(&(*deg, *neighbor));
+                *deg -= 1;
+                queue.insert((*deg, *neighbor));
+            }
+        }
+    }
+
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::graphs::fixtures::graph_from;
+
+    fn sorted(mut cliques: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+        cliques.sort();
+        cliques
+    }
+
+    #[test]
+    fn test_cliques_maximales() {
+        // Grafo de ejemplo clásico de Bron–Kerbosch
+        let graph = graph_from(&[(1, 2), (1, 5), (2, 3), (2, 5), (3, 4), (4, 5), (4, 6)]);
+
+        let cliques = sorted(maximal_cliques(&graph).collect());
+        assert_eq!(
+            cliques,
+            vec![
+                vec![1, 2, 5],
+                vec![2, 3],
+                vec![3, 4],
+                vec![4, 5],
+                vec![4, 6]
+            ]
+        );
+        assert_eq!(maximum_clique(&graph), vec![1, 2, 5]);
+    }
+
+    #[test]
+    fn test_grafo_dirigido_y_lazos() {
+        // Las aristas se toman en ambos sentidos y los lazos se ignoran
+        let graph = graph_from(&[(0, 1), (1, 2), (2, 0), (2, 2), (3, 3)]);
+
+        let cliques = sorted(maximal_cliques(&graph).collect());
+        assert_eq!(cliques, vec![vec![0, 1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn test_grafo_vacio() {
+        let graph = HashMap::new();
+        assert_eq!(maximal_cliques(&graph).next(), None);
+        assert!(maximum_clique(&graph).is_empty());
+    }
+
+    #[test]
+    fn test_corte_temprano() {
+        // El grafo de Moon–Moser con 30 nodos (10 triángulos independientes en
+        // su complemento) tiene 3^10 = 59049 cliques maximales; pedir solo unos
+        // pocos no debe obligar a enumerarlos todos
+        let mut edges = Vec::new();
+        for u in 0..30 {
+            for v in 0..30 {
+                if u / 3 != v / 3 {
+                    edges.push((u, v));
+                }
+            }
+        }
+        let graph = graph_from(&edges);
+
+        let first: Vec<Vec<usize>> = maximal_cliques(&graph).take(3).collect();
+        assert_eq!(first.len(), 3);
+        for clique in first {
+            assert_eq!(clique.len(), 10);
+        }
+    }
+}
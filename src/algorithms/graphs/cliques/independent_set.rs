@@ -0,0 +1,94 @@
+use super::bron_kerbosch::{largest, MaximalCliques};
+use super::{undirected, UndirectedGraph};
+use std::collections::HashMap;
+
+/// Construye el grafo complemento: dos nodos distintos son vecinos en el
+/// complemento si y solo si no lo son en el grafo original.
+///
+/// El grafo original se trata como no dirigido, por lo que el resultado es
+/// simétrico (cada arista aparece en la lista de sus dos extremos) y cada
+/// lista de vecinos viene ordenada.
+pub fn complement(graph: &HashMap<usize, Vec<usize>>) -> HashMap<usize, Vec<usize>> {
+    complement_of(&undirected(graph))
+        .into_iter()
+        .map(|(node, neighbors)| (node, neighbors.into_iter().collect()))
+        .collect()
+}
+
+/// Enumera los conjuntos independientes maximales del grafo.
+///
+/// Un conjunto independiente es un conjunto de nodos sin aristas entre sí,
+/// es decir, un clique del grafo complemento. Por eso la enumeración reutiliza
+/// el iterador de Bron–Kerbosch sobre el complemento, y al igual que él se
+/// puede cortar en cualquier momento.
+pub fn maximal_independent_sets(graph: &HashMap<usize, Vec<usize>>) -> MaximalCliques {
+    MaximalCliques::new(complement_of(&undirected(graph)))
+}
+
+/// Busca un conjunto independiente de tamaño máximo como el clique máximo
+/// del grafo complemento.
+pub fn maximum_independent_set(graph: &HashMap<usize, Vec<usize>>) -> Vec<usize> {
+    largest(maximal_independent_sets(graph))
+}
+
+fn complement_of(adjacency: &UndirectedGraph) -> UndirectedGraph {
+    adjacency
+        .iter()
+        .map(|(&node, neighbors)| {
+            let others = adjacency
+                .keys()
+                .copied()
+                .filter(|other| *other != node && !neighbors.contains(other))
+                .collect();
+            (node, others)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::graphs::fixtures::graph_from;
+
+    #[test]
+    fn test_complemento() {
+        // Camino 0 - 1 - 2: en el complemento solo queda la arista 0 - 2
+        let graph = graph_from(&[(0, 1), (1, 2)]);
+        let complement = complement(&graph);
+
+        assert_eq!(complement[&0], vec![2]);
+        assert_eq!(complement[&1], Vec::<usize>::new());
+        assert_eq!(complement[&2], vec![0]);
+    }
+
+    #[test]
+    fn test_conjunto_independiente_maximo() {
+        // Ciclo de 6 nodos: los pares y los impares son independientes
+        let graph = graph_from(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]);
+
+        let set = maximum_independent_set(&graph);
+        assert_eq!(set.len(), 3);
+        assert!(set == vec![0, 2, 4] || set == vec![1, 3, 5]);
+
+        // Además de los dos máximos hay tres maximales de tamaño 2 ({0,3}, {1,4}, {2,5})
+        let mut sets: Vec<Vec<usize>> = maximal_independent_sets(&graph).collect();
+        sets.sort();
+        assert_eq!(
+            sets,
+            vec![
+                vec![0, 2, 4],
+                vec![0, 3],
+                vec![1, 3, 5],
+                vec![1, 4],
+                vec![2, 5]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_estrella() {
+        // En una estrella el conjunto independiente máximo son las hojas
+        let graph = graph_from(&[(0, 1), (0, 2), (0, 3), (0, 4)]);
+        assert_eq!(maximum_independent_set(&graph), vec![1, 2, 3, 4]);
+    }
+}
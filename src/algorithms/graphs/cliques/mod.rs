@@ -0,0 +1,29 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+pub mod bron_kerbosch;
+pub mod independent_set;
+
+// Vista no dirigida de un grafo: cada nodo con el conjunto ordenado de sus vecinos
+type UndirectedGraph = BTreeMap<usize, BTreeSet<usize>>;
+
+/// Construye la vista no dirigida de un grafo en listas de adyacencia.
+///
+/// Existe una arista `u - v` si `v` aparece en la lista de `u` o `u` en la de `v`.
+/// Los nodos que solo aparecen como vecinos también se incluyen y los lazos
+/// (`u -> u`) se descartan, ya que no aportan nada a un clique.
+fn undirected(graph: &HashMap<usize, Vec<usize>>) -> UndirectedGraph {
+    let mut adjacency: UndirectedGraph = BTreeMap::new();
+
+    for (&node, neighbors) in graph {
+        adjacency.entry(node).or_default();
+
+        for &neighbor in neighbors {
+            if neighbor != node {
+                adjacency.entry(node).or_default().insert(neighbor);
+                adjacency.entry(neighbor).or_default().insert(node);
+            }
+        }
+    }
+
+    adjacency
+}
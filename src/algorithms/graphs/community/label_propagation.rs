@@ -0,0 +1,124 @@
+use super::{Communities, IndexedGraph};
+use crate::algorithms::graphs::WeightedGraph;
+use crate::algorithms::sorting::property::Rng;
+use std::collections::BTreeMap;
+
+/// Detecta comunidades por propagación de etiquetas.
+///
+/// Cada nodo empieza con su propia etiqueta y, en cada ronda, adopta la
+/// etiqueta con mayor peso total entre sus vecinos. Las etiquetas se propagan
+/// por las zonas densas del grafo hasta que cada nodo tiene la etiqueta
+/// dominante de su vecindario; los nodos con la misma etiqueta forman una
+/// comunidad.
+///
+/// # Características:
+/// - Cada ronda cuesta O(m); en la práctica converge en pocas rondas
+/// - Actualización asíncrona: los nodos se recorren en un orden aleatorio
+///   distinto en cada ronda y usan las etiquetas ya actualizadas
+/// - Los empates se rompen al azar, salvo que la etiqueta actual del nodo esté
+///   entre las empatadas, en cuyo caso se conserva (así el algoritmo termina)
+/// - Todo el azar sale de un generador con semilla `seed`: la misma semilla
+///   produce siempre la misma partición
+/// - Se detiene cuando una ronda no cambia ninguna etiqueta o tras
+///   `max_rounds` rondas
+pub fn label_propagation(graph: &WeightedGraph, seed: u64, max_rounds: usize) -> Communities {
+    let indexed = IndexedGraph::new(graph);
+    let n = indexed.nodes.len();
+    let mut rng = Rng::new(seed);
+    let mut labels: Vec<usize> = (0..n).collect();
+    let mut order: Vec<usize> = (0..n).collect();
+
+    for _ in 0..max_rounds {
+        rng.shuffle(&mut order);
+        let mut changed = false;
+
+        for &node in &order {
+            // Peso total de cada etiqueta en el vecindario (ordenado para ser determinista)
+            let mut weights: BTreeMap<usize, f64> = BTreeMap::new();
+            for &(neighbor, w) in &indexed.adjacency[node] {
+                if neighbor != node {
+                    *weights.entry(labels[neighbor]).or_default() += w;
+                }
+            }
+
+            let Some(best) = weights.values().copied().reduce(f64::max) else {
+                continue;
+            };
+            let tied: Vec<usize> = weights
+                .iter()
+                .filter(|(_, &w)| w == best)
+                .map(|(&label, _)| label)
+                .collect();
+
+            if !tied.contains(&labels[node]) {
+                labels[node] = tied[rng.below(tied.len())];
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    indexed.communities(graph, &labels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::graphs::fixtures::weighted_undirected;
+
+    // Dos cliques de 5 nodos unidos por una sola arista liviana
+    fn two_cliques() -> WeightedGraph {
+        let mut edges = Vec::new();
+        for offset in [0, 5] {
+            for u in 0..5 {
+                for v in (u + 1)..5 {
+                    edges.push((offset + u, offset + v, 1.0));
+                }
+            }
+        }
+        edges.push((4, 5, 0.5));
+        weighted_undirected(&edges)
+    }
+
+    #[test]
+    fn test_dos_cliques() {
+        let graph = two_cliques();
+
+        for seed in 0..10 {
+            let result = label_propagation(&graph, seed, 100);
+            assert_eq!(result.count, 2);
+            for node in 0..10 {
+                assert_eq!(result.membership[&node], node / 5);
+            }
+            assert!(result.modularity > 0.4);
+        }
+    }
+
+    #[test]
+    fn test_misma_semilla() {
+        // Un anillo sin estructura de comunidades: el resultado depende del azar,
+        // pero con la misma semilla debe repetirse exactamente
+        let edges: Vec<(usize, usize, f64)> = (0..20).map(|u| (u, (u + 1) % 20, 1.0)).collect();
+        let graph = weighted_undirected(&edges);
+
+        let first = label_propagation(&graph, 42, 100);
+        let second = label_propagation(&graph, 42, 100);
+        assert_eq!(first.membership, second.membership);
+        assert_eq!(first.modularity, second.modularity);
+    }
+
+    #[test]
+    fn test_nodos_aislados() {
+        // Un nodo sin vecinos conserva su propia comunidad
+        let mut graph = weighted_undirected(&[(0, 1, 1.0)]);
+        graph.insert(2, vec![]);
+
+        let result = label_propagation(&graph, 7, 10);
+        assert_eq!(result.count, 2);
+        assert_eq!(result.membership[&0], result.membership[&1]);
+        assert_eq!(result.membership[&2], 1);
+    }
+}
@@ -0,0 +1,207 @@
+use super::{Communities, IndexedGraph};
+use crate::algorithms::graphs::WeightedGraph;
+use std::collections::BTreeMap;
+
+/// Detecta comunidades con el método de Louvain, que busca maximizar la
+/// modularidad de forma voraz y por niveles.
+///
+/// # Pasos:
+/// 1. **Movimiento local**: cada nodo se mueve a la comunidad vecina que más
+///    aumenta la modularidad, repitiendo hasta que ningún movimiento mejore
+/// 2. **Agregación**: cada comunidad se convierte en un nodo de un nuevo grafo;
+///    las aristas internas pasan a ser un lazo y las externas se suman
+/// 3. Se repite sobre el grafo agregado mientras el movimiento local cambie algo
+///
+/// # Características:
+/// - Suele costar O(m log n) en grafos dispersos
+/// - El grafo debe ser no dirigido (cada arista en ambas listas) y con pesos
+///   no negativos
+/// - Los nodos se recorren siempre en orden creciente, por lo que el resultado
+///   es determinista
+pub fn louvain(graph: &WeightedGraph) -> Communities {
+    let indexed = IndexedGraph::new(graph);
+    // Comunidad de cada nodo original, que se va refinando nivel a nivel
+    let mut labels: Vec<usize> = (0..indexed.nodes.len()).collect();
+    let mut adjacency = indexed.adjacency.clone();
+
+    loop {
+        let (community, moved) = local_moving(&adjacency);
+        if !moved {
+            break;
+        }
+
+        let (renumbered, aggregated) = aggregate(&adjacency, &community);
+        for label in labels.iter_mut() {
+            *label = renumbered[*label];
+        }
+        adjacency = aggregated;
+    }
+
+    indexed.communities(graph, &labels)
+}
+
+/// Fase de movimiento local sobre un nivel. Retorna la comunidad de cada nodo
+/// y si algún nodo cambió de comunidad.
+fn local_moving(adjacency: &[Vec<(usize, f64)>]) -> (Vec<usize>, bool) {
+    let n = adjacency.len();
+    // Grado de cada nodo y suma de los grados de cada comunidad (tot_c)
+    let degree: Vec<f64> = adjacency
+        .iter()
+        .map(|neighbors| neighbors.iter().map(|&(_, w)| w).sum())
+        .collect();
+    let weight: f64 = degree.iter().sum();
+    let mut community: Vec<usize> = (0..n).collect();
+    let mut total = degree.clone();
+    let mut moved = false;
+
+    if weight == 0.0 {
+        return (community, false);
+    }
+
+    // Tolerancia para no mover nodos por ganancias que son solo redondeo
+    let epsilon = 1e-12;
+    let mut improved = true;
+    while improved {
+        improved = false;
+
+        for node in 0..n {
+            let current = community[node];
+
+            // Peso de las aristas del nodo hacia cada comunidad vecina
+            let mut links: BTreeMap<usize, f64> = BTreeMap::new();
+            for &(neighbor, w) in &adjacency[node] {
+                if neighbor != node {
+                    *links.entry(community[neighbor]).or_default() += w;
+                }
+            }
+
+            // Sacamos el nodo de su comunidad; la ganancia de insertarlo en c es
+            // proporcional a k_i,in(c) - tot_c · k_i / 2m
+            total[current] -= degree[node];
+            let gain = |c: usize, total: &[f64]| {
+                links.get(&c).copied().unwrap_or(0.0) - total[c] * degree[node] / weight
+            };
+
+            let mut best = current;
+            let mut best_gain = gain(current, &total);
+            for &candidate in links.keys() {
+                let candidate_gain = gain(candidate, &total);
+                if candidate_gain > best_gain + epsilon {
+                    best = candidate;
+                    best_gain = candidate_gain;
+                }
+            }
+
+            total[best] += degree[node];
+            if best != current {
+                community[node] = best;
+                improved = true;
+                moved = true;
+            }
+        }
+    }
+
+    (community, moved)
+}
+
+/// Fase de agregación: construye el grafo de comunidades. Retorna el nuevo
+/// número (0..k) de cada comunidad y las listas de adyacencia agregadas.
+fn aggregate(
+    adjacency: &[Vec<(usize, f64)>],
+    community: &[usize],
+) -> (Vec<usize>, Vec<Vec<(usize, f64)>>) {
+    // Renumeramos las comunidades no vacías de forma consecutiva
+    let mut renumber = vec![usize::MAX; adjacency.len()];
+    let mut count = 0;
+    for &c in community {
+        if renumber[c] == usize::MAX {
+            renumber[c] = count;
+            count += 1;
+        }
+    }
+
+    // Las aristas internas (incluidos los lazos previos) se acumulan en un lazo,
+    // de modo que la modularidad del grafo agregado es la misma
+    let mut weights: Vec<BTreeMap<usize, f64>> = vec![BTreeMap::new(); count];
+    for (node, neighbors) in adjacency.iter().enumerate() {
+        let from = renumber[community[node]];
+        for &(neighbor, w) in neighbors {
+            let to = renumber[community[neighbor]];
+            *weights[from].entry(to).or_default() += w;
+        }
+    }
+
+    let node_community = community.iter().map(|&c| renumber[c]).collect();
+    let aggregated = weights
+        .into_iter()
+        .map(|neighbors| neighbors.into_iter().collect())
+        .collect();
+    (node_community, aggregated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::graphs::community::label_propagation::label_propagation;
+    use crate::algorithms::graphs::fixtures::weighted_undirected;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_dos_triangulos() {
+        let graph = weighted_undirected(&[
+            (0, 1, 1.0),
+            (1, 2, 1.0),
+            (0, 2, 1.0),
+            (3, 4, 1.0),
+            (4, 5, 1.0),
+            (3, 5, 1.0),
+            (2, 3, 1.0),
+        ]);
+
+        let result = louvain(&graph);
+        assert_eq!(result.count, 2);
+        for node in 0..6 {
+            assert_eq!(result.membership[&node], node / 3);
+        }
+        assert!((result.modularity - 5.0 / 14.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_anillo_de_cliques() {
+        // Anillo de 6 cliques de 4 nodos, cada clique unido al siguiente por
+        // una arista: la partición óptima son los cliques
+        let mut edges = Vec::new();
+        for clique in 0..6 {
+            let base = clique * 4;
+            for u in 0..4 {
+                for v in (u + 1)..4 {
+                    edges.push((base + u, base + v, 1.0));
+                }
+            }
+            edges.push((base + 3, (base + 4) % 24, 1.0));
+        }
+        let graph = weighted_undirected(&edges);
+
+        let result = louvain(&graph);
+        assert_eq!(result.count, 6);
+        for node in 0..24 {
+            assert_eq!(result.membership[&node], node / 4);
+        }
+
+        // Louvain optimiza la modularidad directamente, así que no debería quedar
+        // por debajo de la propagación de etiquetas
+        let labels = label_propagation(&graph, 1, 100);
+        assert!(result.modularity >= labels.modularity - 1e-12);
+    }
+
+    #[test]
+    fn test_grafo_sin_aristas() {
+        let mut graph: WeightedGraph = HashMap::new();
+        graph.insert(0, vec![]);
+        graph.insert(1, vec![]);
+
+        let result = louvain(&graph);
+        assert_eq!(result.count, 2);
+        assert_eq!(result.modularity, 0.0);
+    }
+}
@@ -0,0 +1,143 @@
+use super::{weighted_nodes, WeightedGraph};
+use std::collections::HashMap;
+
+pub mod label_propagation;
+pub mod louvain;
+
+/// Partición de los nodos de un grafo en comunidades.
+#[derive(Debug, Clone)]
+pub struct Communities {
+    /// Comunidad asignada a cada nodo, numeradas de 0 a `count - 1` según el
+    /// menor nodo de cada una
+    pub membership: HashMap<usize, usize>,
+    /// Cantidad de comunidades distintas
+    pub count: usize,
+    /// Modularidad de la partición sobre el grafo original
+    pub modularity: f64,
+}
+
+/// Calcula la modularidad de una partición de un grafo no dirigido con pesos.
+///
+/// La modularidad compara el peso de las aristas internas de cada comunidad
+/// con el que tendrían si las aristas se repartieran al azar respetando los
+/// grados:
+///
+/// `Q = Σ_c [ in_c / 2m - (tot_c / 2m)² ]`
+///
+/// donde `in_c` es la suma de los pesos en las listas de adyacencia de los
+/// nodos de `c` que apuntan a nodos de `c`, `tot_c` la suma de los grados de
+/// sus nodos y `2m` la suma de todos los pesos de todas las listas. Los nodos
+/// sin comunidad en `membership` se ignoran.
+pub fn modularity(graph: &WeightedGraph, membership: &HashMap<usize, usize>) -> f64 {
+    let mut internal: HashMap<usize, f64> = HashMap::new();
+    let mut total: HashMap<usize, f64> = HashMap::new();
+    let mut weight = 0.0;
+
+    for (node, neighbors) in graph {
+        for &(neighbor, w) in neighbors {
+            weight += w;
+            let Some(&community) = membership.get(node) else {
+                continue;
+            };
+            *total.entry(community).or_default() += w;
+            if membership.get(&neighbor) == Some(&community) {
+                *internal.entry(community).or_default() += w;
+            }
+        }
+    }
+
+    if weight == 0.0 {
+        return 0.0;
+    }
+
+    total
+        .iter()
+        .map(|(community, tot)| {
+            let inside = internal.get(community).copied().unwrap_or(0.0);
+            inside / weight - (tot / weight).powi(2)
+        })
+        .sum()
+}
+
+// Versión del grafo con nodos numerados de 0 a n - 1, usada internamente
+struct IndexedGraph {
+    nodes: Vec<usize>,
+    adjacency: Vec<Vec<(usize, f64)>>,
+}
+
+impl IndexedGraph {
+    fn new(graph: &WeightedGraph) -> Self {
+        let nodes = weighted_nodes(graph);
+        let index: HashMap<usize, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &node)| (node, i))
+            .collect();
+        let adjacency = nodes
+            .iter()
+            .map(|node| {
+                graph
+                    .get(node)
+                    .map(|neighbors| {
+                        neighbors
+                            .iter()
+                            .map(|&(neighbor, w)| (index[&neighbor], w))
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        Self { nodes, adjacency }
+    }
+
+    // Arma el resultado a partir de la comunidad (en índices) de cada nodo
+    fn communities(&self, graph: &WeightedGraph, labels: &[usize]) -> Communities {
+        // Renumeramos las etiquetas en orden de aparición: como los nodos están
+        // ordenados, la comunidad 0 es la del menor nodo
+        let mut renumber: HashMap<usize, usize> = HashMap::new();
+        let membership: HashMap<usize, usize> = self
+            .nodes
+            .iter()
+            .zip(labels)
+            .map(|(&node, label)| {
+                let next = renumber.len();
+                (node, *renumber.entry(*label).or_insert(next))
+            })
+            .collect();
+
+        Communities {
+            modularity: modularity(graph, &membership),
+            count: renumber.len(),
+            membership,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::graphs::fixtures::weighted_undirected;
+
+    #[test]
+    fn test_modularidad_dos_triangulos() {
+        // Dos triángulos {0, 1, 2} y {3, 4, 5} unidos por la arista 2 - 3
+        let graph = weighted_undirected(&[
+            (0, 1, 1.0),
+            (1, 2, 1.0),
+            (0, 2, 1.0),
+            (3, 4, 1.0),
+            (4, 5, 1.0),
+            (3, 5, 1.0),
+            (2, 3, 1.0),
+        ]);
+
+        let membership: HashMap<usize, usize> = (0..6).map(|node| (node, node / 3)).collect();
+        // Cada triángulo: in = 6, tot = 7, 2m = 14 → Q = 2 · (6/14 - 1/4) = 5/14
+        assert!((modularity(&graph, &membership) - 5.0 / 14.0).abs() < 1e-12);
+
+        // Todos en una sola comunidad: Q = 14/14 - 1 = 0
+        let single: HashMap<usize, usize> = (0..6).map(|node| (node, 0)).collect();
+        assert!(modularity(&graph, &single).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,241 @@
+use crate::algorithms::graphs::basics::bfs::bfs_tree;
+use crate::algorithms::graphs::nodes;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Calcula una base de ciclos mínima de un grafo no dirigido con el
+/// algoritmo de Horton.
+///
+/// Todo ciclo del grafo se puede obtener como diferencia simétrica (XOR de
+/// aristas) de los ciclos de una base. La base tiene siempre `m - n + c`
+/// ciclos (`c` = componentes conexas) y es mínima si la suma de sus
+/// longitudes es la menor posible.
+///
+/// # Pasos:
+/// 1. Para cada nodo `v` se calcula un árbol BFS con [`bfs_tree`]
+/// 2. Para cada arista `(x, y)` se arma el candidato `v ~> x - y ~> v` con los
+///    caminos del árbol, descartándolo si los dos caminos se cruzan
+/// 3. Los candidatos se ordenan por longitud y se agregan de forma voraz los
+///    que son linealmente independientes (eliminación gaussiana sobre GF(2))
+///
+/// # Características:
+/// - El grafo se trata como no dirigido y sin pesos: la longitud de un ciclo
+///   es su cantidad de aristas; los lazos y aristas repetidas se ignoran
+/// - O(n · m) candidatos, pensado para grafos de tamaño moderado
+/// - Cada ciclo se entrega como la secuencia de sus nodos, sin repetir el
+///   primero al final
+/// - Es determinista: entre candidatos de igual longitud se prefiere el de
+///   la raíz menor y, con la misma raíz, el de la arista menor
+pub fn minimum_cycle_basis(graph: &HashMap<usize, Vec<usize>>) -> Vec<Vec<usize>> {
+    // Vista no dirigida y simple del grafo. Los mapas ordenados fijan el
+    // orden de las aristas, las raíces y los candidatos, así que la base
+    // elegida es siempre la misma
+    let mut adjacency: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
+    for node in nodes(graph) {
+        adjacency.entry(node).or_default();
+    }
+    for (&u, neighbors) in graph {
+        for &v in neighbors {
+            if u != v {
+                adjacency.entry(u).or_default().insert(v);
+                adjacency.entry(v).or_default().insert(u);
+            }
+        }
+    }
+    let undirected: HashMap<usize, Vec<usize>> = adjacency
+        .iter()
+        .map(|(&node, neighbors)| (node, neighbors.iter().copied().collect()))
+        .collect();
+
+    // Cada arista (u < v) recibe un número para representar ciclos como bits
+    let mut edge_index: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+    for (&u, neighbors) in &adjacency {
+        for &v in neighbors {
+            if u < v {
+                let next = edge_index.len();
+                edge_index.insert((u, v), next);
+            }
+        }
+    }
+    let dimension = edge_index.len() + components(&undirected) - adjacency.len();
+    if dimension == 0 {
+        return Vec::new();
+    }
+
+    let mut candidates: Vec<Vec<usize>> = Vec::new();
+    for &root in adjacency.keys() {
+        let tree = bfs_tree(&undirected, root);
+        // Camino del árbol desde un nodo hasta la raíz
+        let path_to_root = |mut node: usize| {
+            let mut path = vec![node];
+            while let Some(&parent) = tree.predecessors[&node].first() {
+                path.push(parent);
+                node = parent;
+            }
+            path
+        };
+
+        for &(x, y) in edge_index.keys() {
+            if !tree.distance.contains_key(&x) {
+                continue;
+            }
+            let from_x = path_to_root(x);
+            let from_y = path_to_root(y);
+
+            // Los caminos solo pueden compartir la raíz; si no, el candidato
+            // no es un ciclo simple (o la arista es del propio árbol)
+            let shared = from_x.iter().filter(|node| from_y.contains(node)).count();
+            if shared != 1 {
+                continue;
+            }
+
+            // raíz ~> x, luego x - y, luego y ~> raíz (sin repetir la raíz)
+            let mut cycle: Vec<usize> = from_x.into_iter().rev().collect();
+            cycle.extend(from_y.into_iter().take_while(|&node| node != root));
+            candidates.push(cycle);
+        }
+    }
+    candidates.sort_by_key(|cycle| cycle.len());
+
+    // Eliminación gaussiana incremental: cada vector guardado se identifica por
+    // su bit más bajo (pivote)
+    let words = edge_index.len().div_ceil(64);
+    let mut pivots: HashMap<usize, Vec<u64>> = HashMap::new();
+    let mut basis = Vec::with_capacity(dimension);
+
+    for cycle in candidates {
+        let mut vector = vec![0u64; words];
+        for i in 0..cycle.len() {
+            let (u, v) = (cycle[i], cycle[(i + 1) % cycle.len()]);
+            let edge = edge_index[&(u.min(v), u.max(v))];
+            vector[edge / 64] ^= 1 << (edge % 64);
+        }
+
+        // Reducimos el vector con los pivotes existentes; si queda en cero, el
+        // ciclo es combinación de los ya elegidos
+        while let Some(pivot) = lowest_bit(&vector) {
+            match pivots.get(&pivot) {
+                Some(reducer) => {
+                    for (word, bits) in vector.iter_mut().zip(reducer) {
+                        *word ^= bits;
+                    }
+                }
+                None => {
+                    pivots.insert(pivot, vector);
+                    basis.push(cycle);
+                    break;
+                }
+            }
+        }
+
+        if basis.len() == dimension {
+            break;
+        }
+    }
+
+    basis
+}
+
+// Posición del bit encendido más bajo, o None si el vector es cero
+fn lowest_bit(vector: &[u64]) -> Option<usize> {
+    vector
+        .iter()
+        .enumerate()
+        .find(|(_, &word)| word != 0)
+        .map(|(i, word)| i * 64 + word.trailing_zeros() as usize)
+}
+
+// Cantidad de componentes conexas de un grafo no dirigido
+fn components(graph: &HashMap<usize, Vec<usize>>) -> usize {
+    let mut seen: BTreeSet<usize> = BTreeSet::new();
+    let mut count = 0;
+    for &node in graph.keys() {
+        if seen.contains(&node) {
+            continue;
+        }
+        count += 1;
+        seen.extend(bfs_tree(graph, node).order);
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::graphs::basics::list_graph;
+    use crate::algorithms::graphs::fixtures::graph_from;
+
+    fn total_length(basis: &[Vec<usize>]) -> usize {
+        basis.iter().map(|cycle| cycle.len()).sum()
+    }
+
+    #[test]
+    fn test_cuadrado_con_diagonal() {
+        // La base mínima son los dos triángulos, no el ciclo de 4
+        let graph = graph_from(&[(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)]);
+
+        let basis = minimum_cycle_basis(&graph);
+        assert_eq!(basis.len(), 2);
+        assert_eq!(total_length(&basis), 6);
+        assert_eq!(basis, vec![vec![0, 1, 2], vec![0, 2, 3]]);
+    }
+
+    #[test]
+    fn test_cubo() {
+        // El cubo tiene 12 aristas y 8 nodos: 5 ciclos, todos caras de 4 nodos
+        let mut edges = Vec::new();
+        for u in 0..8usize {
+            for bit in 0..3 {
+                let v = u ^ (1 << bit);
+                if u < v {
+                    edges.push((u, v));
+                }
+            }
+        }
+        let graph = graph_from(&edges);
+
+        let basis = minimum_cycle_basis(&graph);
+        assert_eq!(basis.len(), 5);
+        assert!(basis.iter().all(|cycle| cycle.len() == 4));
+    }
+
+    #[test]
+    fn test_ciclos_validos() {
+        // Grafo de list_graph, tratado como no dirigido
+        let graph = list_graph();
+        let undirected: std::collections::HashSet<(usize, usize)> =
+            [(0, 1), (0, 2), (1, 3), (1, 2), (2, 3), (3, 4), (0, 4)]
+                .into_iter()
+                .collect();
+
+        // 7 aristas, 5 nodos, 1 componente: 3 ciclos
+        let basis = minimum_cycle_basis(&graph);
+        assert_eq!(basis.len(), 3);
+        assert_eq!(total_length(&basis), 10);
+
+        // Cada par de nodos consecutivos de cada ciclo debe ser una arista
+        for cycle in &basis {
+            for i in 0..cycle.len() {
+                let (u, v) = (cycle[i], cycle[(i + 1) % cycle.len()]);
+                assert!(undirected.contains(&(u.min(v), u.max(v))));
+            }
+        }
+    }
+
+    #[test]
+    fn test_determinista() {
+        // Cada HashMap nuevo recorre sus claves en otro orden; la base no
+        // debe depender de eso
+        let expected = minimum_cycle_basis(&list_graph());
+        for _ in 0..20 {
+            assert_eq!(minimum_cycle_basis(&list_graph()), expected);
+        }
+    }
+
+    #[test]
+    fn test_bosque() {
+        // Un árbol y un nodo aislado no tienen ciclos
+        let mut graph = graph_from(&[(0, 1), (1, 2), (1, 3)]);
+        graph.insert(9, vec![9]);
+        assert!(minimum_cycle_basis(&graph).is_empty());
+    }
+}
@@ -0,0 +1,305 @@
+use crate::algorithms::graphs::nodes;
+use std::collections::{HashMap, HashSet};
+
+/// Enumera todos los ciclos elementales de un grafo dirigido con el
+/// algoritmo de Johnson.
+///
+/// Un ciclo es elemental si no repite nodos (salvo el inicial al cerrarse).
+/// Para cada nodo `s`, en orden creciente, se buscan los ciclos cuyo menor
+/// nodo es `s`, dentro de la componente fuertemente conexa de `s` en el
+/// subgrafo de los nodos mayores o iguales a `s`.
+///
+/// # Características:
+/// - Tiempo O((n + m)(c + 1)) para `c` ciclos: el bloqueo de nodos evita
+///   recorrer caminos que ya se sabe que no vuelven a `s`
+/// - Es un iterador perezoso: se puede cortar la enumeración en cualquier
+///   momento, lo que importa porque un grafo puede tener una cantidad
+///   exponencial de ciclos
+/// - Cada ciclo se entrega empezando por su menor nodo y sin repetirlo al
+///   final: `[0, 2, 1]` representa `0 -> 2 -> 1 -> 0`
+/// - Los lazos (`u -> u`) son ciclos de un solo nodo; las aristas repetidas
+///   se cuentan una vez
+pub fn elementary_cycles(graph: &HashMap<usize, Vec<usize>>) -> ElementaryCycles {
+    let nodes = nodes(graph);
+    let index: HashMap<usize, usize> = nodes.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+    let adjacency = nodes
+        .iter()
+        .map(|node| {
+            let mut neighbors: Vec<usize> = graph
+                .get(node)
+                .into_iter()
+                .flatten()
+                .map(|neighbor| index[neighbor])
+                .collect();
+            neighbors.sort_unstable();
+            neighbors.dedup();
+            neighbors
+        })
+        .collect();
+
+    ElementaryCycles {
+        nodes,
+        adjacency,
+        start: 0,
+        component: HashSet::new(),
+        blocked: Vec::new(),
+        blocked_by: Vec::new(),
+        path: Vec::new(),
+        frames: Vec::new(),
+    }
+}
+
+// Estado de una llamada a `circuit(v)` del algoritmo, en una pila explícita
+struct Frame {
+    node: usize,
+    next: usize, // Índice del próximo vecino a revisar
+    found: bool, // Si desde este nodo ya se cerró algún ciclo
+}
+
+/// Iterador sobre los ciclos elementales, creado por [`elementary_cycles`].
+pub struct ElementaryCycles {
+    nodes: Vec<usize>,
+    adjacency: Vec<Vec<usize>>,
+    // Nodo (como índice) cuyos ciclos se están buscando
+    start: usize,
+    // Componente fuertemente conexa de `start` entre los nodos >= start
+    component: HashSet<usize>,
+    blocked: Vec<bool>,
+    // B(w): nodos a desbloquear cuando se desbloquee w
+    blocked_by: Vec<HashSet<usize>>,
+    path: Vec<usize>,
+    frames: Vec<Frame>,
+}
+
+impl Iterator for ElementaryCycles {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        loop {
+            // Si no hay búsqueda en curso, preparamos la del siguiente nodo inicial
+            if self.frames.is_empty() && !self.begin_next_start() {
+                return None;
+            }
+
+            let frame = self.frames.last_mut().unwrap();
+            let node = frame.node;
+
+            if let Some(&neighbor) = self.adjacency[node].get(frame.next) {
+                frame.next += 1;
+                if !self.component.contains(&neighbor) {
+                    continue;
+                }
+
+                if neighbor == self.start {
+                    // El camino actual vuelve al inicio: es un ciclo
+                    frame.found = true;
+                    return Some(self.path.iter().map(|&i| self.nodes[i]).collect());
+                }
+                if !self.blocked[neighbor] {
+                    self.enter(neighbor);
+                }
+                continue;
+            }
+
+            // Se revisaron todos los vecinos: termina circuit(node)
+            let found = frame.found;
+            if found {
+                self.unblock(node);
+            } else {
+                // Mientras ningún vecino se desbloquee, este nodo no lleva al inicio
+                for &neighbor in &self.adjacency[node] {
+                    if self.component.contains(&neighbor) {
+                        self.blocked_by[neighbor].insert(node);
+                    }
+                }
+            }
+            self.path.pop();
+            self.frames.pop();
+            if let Some(parent) = self.frames.last_mut() {
+                parent.found |= found;
+            }
+            if self.frames.is_empty() {
+                self.start += 1;
+            }
+        }
+    }
+}
+
+impl ElementaryCycles {
+    // Busca el próximo nodo inicial con ciclos y deja lista su búsqueda.
+    // Retorna false cuando ya no quedan nodos
+    fn begin_next_start(&mut self) -> bool {
+        let n = self.nodes.len();
+        while self.start < n {
+            self.component = self.strong_component(self.start);
+            let has_cycle = self.component.len() > 1
+                || self.adjacency[self.start]
+                    .binary_search(&self.start)
+                    .is_ok();
+
+            if has_cycle {
+                self.blocked = vec![false; n];
+                self.blocked_by = vec![HashSet::new(); n];
+                self.enter(self.start);
+                return true;
+            }
+            self.start += 1;
+        }
+        false
+    }
+
+    // Comienza circuit(node): lo agrega al camino y lo bloquea
+    fn enter(&mut self, node: usize) {
+        self.blocked[node] = true;
+        self.path.push(node);
+        self.frames.push(Frame {
+            node,
+            next: 0,
+            found: false,
+        });
+    }
+
+    // Desbloquea un nodo y, en cascada, los que esperaban por él
+    fn unblock(&mut self, node: usize) {
+        let mut pending = vec![node];
+        while let Some(node) = pending.pop() {
+            if self.blocked[node] {
+                self.blocked[node] = false;
+                pending.extend(self.blocked_by[node].drain());
+            }
+        }
+    }
+
+    // Nodos >= start que alcanzan a start y son alcanzables desde él
+    fn strong_component(&self, start: usize) -> HashSet<usize> {
+        let forward = self.reach(start, |node| self.adjacency[node].clone());
+
+        // Para el recorrido inverso solo hace falta mirar los nodos ya alcanzados
+        let mut reverse: HashMap<usize, Vec<usize>> = HashMap::new();
+        for &node in &forward {
+            for &neighbor in &self.adjacency[node] {
+                if forward.contains(&neighbor) {
+                    reverse.entry(neighbor).or_default().push(node);
+                }
+            }
+        }
+        self.reach(start, |node| {
+            reverse.get(&node).cloned().unwrap_or_default()
+        })
+    }
+
+    // Recorrido en profundidad limitado a los nodos >= start
+    fn reach(&self, start: usize, neighbors: impl Fn(usize) -> Vec<usize>) -> HashSet<usize> {
+        let mut seen = HashSet::from([start]);
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            for neighbor in neighbors(node) {
+                if neighbor >= start && seen.insert(neighbor) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+        seen
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::graphs::basics::list_graph;
+    use crate::algorithms::graphs::fixtures::graph_from;
+    use crate::algorithms::sorting::property::Rng;
+
+    // Ciclos por fuerza bruta: caminos simples que vuelven a su menor nodo
+    fn brute_force(graph: &HashMap<usize, Vec<usize>>) -> Vec<Vec<usize>> {
+        fn walk(
+            graph: &HashMap<usize, Vec<usize>>,
+            path: &mut Vec<usize>,
+            out: &mut Vec<Vec<usize>>,
+        ) {
+            let node = *path.last().unwrap();
+            let mut neighbors = graph.get(&node).cloned().unwrap_or_default();
+            neighbors.sort_unstable();
+            neighbors.dedup();
+            for neighbor in neighbors {
+                if neighbor == path[0] {
+                    out.push(path.clone());
+                } else if neighbor > path[0] && !path.contains(&neighbor) {
+                    path.push(neighbor);
+                    walk(graph, path, out);
+                    path.pop();
+                }
+            }
+        }
+
+        let mut out = Vec::new();
+        for start in nodes(graph) {
+            walk(graph, &mut vec![start], &mut out);
+        }
+        out.sort();
+        out
+    }
+
+    /// Test de regresión sobre el grafo de fill_list_graph(): los ciclos que
+    /// enumeran sus comentarios deben existir realmente. Se usa list_graph()
+    /// para no tocar LIST_GRAPH, que otras pruebas escriben en paralelo.
+    #[test]
+    fn test_ciclos_de_fill_list_graph() {
+        let graph = list_graph();
+
+        let cycles: Vec<Vec<usize>> = elementary_cycles(&graph).collect();
+
+        // Cada ciclo de los comentarios, rotado para empezar por su menor nodo
+        assert!(cycles.contains(&vec![0, 1])); // 0 -> 1 -> 0
+        assert!(cycles.contains(&vec![0, 2, 1])); // 0 -> 2 -> 1 -> 0
+        assert!(cycles.contains(&vec![1, 3, 2])); // 1 -> 3 -> 2 -> 1
+        assert!(cycles.contains(&vec![0, 1, 3, 4])); // 3 -> 4 -> 0 -> 1 -> 3
+
+        // Los comentarios no son exhaustivos: el grafo tiene más ciclos
+        let mut sorted = cycles.clone();
+        sorted.sort();
+        assert_eq!(sorted, brute_force(&graph));
+        assert!(cycles.len() > 4);
+    }
+
+    #[test]
+    fn test_contra_fuerza_bruta() {
+        for seed in 0..30u64 {
+            let mut rng = Rng::new(seed);
+            let mut next = || rng.below(8);
+            let edges: Vec<(usize, usize)> = (0..16).map(|_| (next(), next())).collect();
+            let graph = graph_from(&edges);
+
+            let mut cycles: Vec<Vec<usize>> = elementary_cycles(&graph).collect();
+            cycles.sort();
+            assert_eq!(cycles, brute_force(&graph));
+        }
+    }
+
+    #[test]
+    fn test_lazos_y_aciclicos() {
+        // Un DAG no tiene ciclos
+        let dag = graph_from(&[(0, 1), (0, 2), (1, 3), (2, 3)]);
+        assert_eq!(elementary_cycles(&dag).next(), None);
+
+        // Un lazo es un ciclo de un nodo, y las aristas repetidas no duplican ciclos
+        let graph = graph_from(&[(5, 5), (1, 2), (2, 1), (2, 1)]);
+        let cycles: Vec<Vec<usize>> = elementary_cycles(&graph).collect();
+        assert_eq!(cycles, vec![vec![1, 2], vec![5]]);
+    }
+
+    #[test]
+    fn test_corte_temprano() {
+        // Grafo completo de 12 nodos: más de 10^8 ciclos, pero pedir 5 es inmediato
+        let mut edges = Vec::new();
+        for u in 0..12 {
+            for v in 0..12 {
+                if u != v {
+                    edges.push((u, v));
+                }
+            }
+        }
+        let graph = graph_from(&edges);
+        assert_eq!(elementary_cycles(&graph).take(5).count(), 5);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod cycle_basis;
+pub mod johnson;
@@ -0,0 +1,319 @@
+use std::collections::{BTreeSet, HashMap};
+
+/// Relación de dominancia de un grafo de flujo dirigido con raíz.
+///
+/// Un nodo `a` domina a `b` si todo camino desde la raíz hasta `b` pasa por
+/// `a`. El dominador inmediato de `b` es su dominador estricto más cercano, y
+/// la frontera de dominancia de `a` son los nodos donde la dominancia de `a`
+/// "termina": nodos que `a` no domina estrictamente pero que tienen un
+/// predecesor dominado por `a`. Estas fronteras indican dónde insertar las
+/// funciones φ al construir la forma SSA.
+///
+/// Solo se consideran los nodos alcanzables desde la raíz.
+#[derive(Debug, Clone)]
+pub struct Dominators {
+    /// Nodo de entrada del grafo de flujo
+    pub root: usize,
+    /// Dominador inmediato de cada nodo alcanzable, salvo la raíz
+    pub idom: HashMap<usize, usize>,
+    /// Árbol de dominadores: hijos de cada nodo alcanzable, ordenados
+    pub tree: HashMap<usize, Vec<usize>>,
+    /// Frontera de dominancia de cada nodo alcanzable, ordenada
+    pub frontiers: HashMap<usize, Vec<usize>>,
+}
+
+impl Dominators {
+    /// Retorna el dominador inmediato de `node`, o `None` para la raíz y los
+    /// nodos inalcanzables.
+    pub fn immediate_dominator(&self, node: usize) -> Option<usize> {
+        self.idom.get(&node).copied()
+    }
+
+    /// Indica si `a` domina a `b` (todo nodo alcanzable se domina a sí mismo).
+    pub fn dominates(&self, a: usize, b: usize) -> bool {
+        if !self.tree.contains_key(&b) {
+            return false;
+        }
+
+        // Subimos por el árbol de dominadores desde b hasta la raíz
+        let mut node = b;
+        loop {
+            if node == a {
+                return true;
+            }
+            match self.idom.get(&node) {
+                Some(&parent) => node = parent,
+                None => return false,
+            }
+        }
+    }
+}
+
+/// Calcula dominadores inmediatos, árbol de dominadores y fronteras de
+/// dominancia con el algoritmo de Cooper, Harvey y Kennedy.
+///
+/// # Características:
+/// - Numera los nodos en postorden inverso con un DFS desde la raíz y repite
+///   una pasada de flujo de datos hasta que los dominadores no cambian
+/// - En cada pasada, el dominador de un nodo es la intersección (el ancestro
+///   común más cercano en el árbol actual) de los dominadores de sus
+///   predecesores ya procesados
+/// - En los grafos de flujo habituales converge en dos o tres pasadas; en la
+///   práctica es tan rápido como Lengauer–Tarjan con una fracción del código
+/// - Las fronteras se calculan subiendo desde cada predecesor de los nodos
+///   con dos o más predecesores hasta el dominador inmediato del nodo. La
+///   raíz se trata como si tuviera además una entrada virtual: cualquier
+///   arista que vuelva a ella la pone en la frontera de todo el camino,
+///   incluida la propia raíz
+pub fn dominators(graph: &HashMap<usize, Vec<usize>>, root: usize) -> Dominators {
+    let order = reverse_postorder(graph, root);
+    let number: HashMap<usize, usize> = order.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+    let n = order.len();
+
+    // Predecesores de cada nodo alcanzable, como índices del postorden inverso
+    let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for (i, node) in order.iter().enumerate() {
+        for neighbor in graph.get(node).into_iter().flatten() {
+            if let Some(&j) = number.get(neighbor) {
+                predecessors[j].push(i);
+            }
+        }
+    }
+
+    // idom[i] para cada índice; la raíz (índice 0) es su propio dominador
+    let mut idom: Vec<Option<usize>> = vec![None; n];
+    if n > 0 {
+        idom[0] = Some(0);
+    }
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+
+        for b in 1..n {
+            // Intersección de los predecesores que ya tienen dominador
+            let new_idom = predecessors[b]
+                .iter()
+                .copied()
+                .filter(|&p| idom[p].is_some())
+                .reduce(|a, p| intersect(&idom, a, p));
+
+            if new_idom.is_some() && idom[b] != new_idom {
+                idom[b] = new_idom;
+                changed = true;
+            }
+        }
+    }
+
+    // Fronteras de dominancia
+    let mut frontiers: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); n];
+    for b in 0..n {
+        // La entrada virtual cuenta como un predecesor más de la raíz
+        if b > 0 && predecessors[b].len() < 2 {
+            continue;
+        }
+        for &p in &predecessors[b] {
+            let mut runner = p;
+            // Para la raíz, la subida termina después de pasar por ella,
+            // porque su dominador inmediato sería la entrada virtual
+            while b == 0 || runner != idom[b].unwrap() {
+                frontiers[runner].insert(order[b]);
+                if runner == 0 {
+                    break;
+                }
+                runner = idom[runner].unwrap();
+            }
+        }
+    }
+
+    let mut result = Dominators {
+        root,
+        idom: HashMap::new(),
+        tree: order.iter().map(|&node| (node, Vec::new())).collect(),
+        frontiers: HashMap::new(),
+    };
+    for (i, &node) in order.iter().enumerate() {
+        if i > 0 {
+            let parent = order[idom[i].unwrap()];
+            result.idom.insert(node, parent);
+            result.tree.get_mut(&parent).unwrap().push(node);
+        }
+        result.frontiers.insert(
+            node,
+            std::mem::take(&mut frontiers[i]).into_iter().collect(),
+        );
+    }
+    for children in result.tree.values_mut() {
+        children.sort_unstable();
+    }
+
+    result
+}
+
+// Sube desde dos nodos por el árbol de dominadores parcial hasta encontrarse.
+// Los índices son del postorden inverso, así que el ancestro tiene índice menor
+fn intersect(idom: &[Option<usize>], mut a: usize, mut b: usize) -> usize {
+    while a != b {
+        while a > b {
+            a = idom[a].unwrap();
+        }
+        while b > a {
+            b = idom[b].unwrap();
+        }
+    }
+    a
+}
+
+/// Nodos alcanzables desde `root` en postorden inverso, con un DFS iterativo.
+fn reverse_postorder(graph: &HashMap<usize, Vec<usize>>, root: usize) -> Vec<usize> {
+    let empty = Vec::new();
+    let mut visited: BTreeSet<usize> = BTreeSet::new();
+    let mut postorder = Vec::new();
+    // Pila de (nodo, índice del próximo vecino a revisar)
+    let mut stack: Vec<(usize, usize)> = vec![(root, 0)];
+    visited.insert(root);
+
+    while let Some((node, next)) = stack.last_mut() {
+        let neighbors = graph.get(node).unwrap_or(&empty);
+        if let Some(&neighbor) = neighbors.get(*next) {
+            *next += 1;
+            if visited.insert(neighbor) {
+                stack.push((neighbor, 0));
+            }
+        } else {
+            postorder.push(*node);
+            stack.pop();
+        }
+    }
+
+    postorder.reverse();
+    postorder
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::graphs::fixtures::graph_from;
+    use crate::algorithms::sorting::property::Rng;
+    use std::collections::HashSet;
+
+    // Nodos alcanzables desde root sin pasar por `removed`
+    fn reachable(
+        graph: &HashMap<usize, Vec<usize>>,
+        root: usize,
+        removed: usize,
+    ) -> HashSet<usize> {
+        let mut seen = HashSet::new();
+        let mut stack = vec![root];
+        while let Some(node) = stack.pop() {
+            if node == removed || !seen.insert(node) {
+                continue;
+            }
+            stack.extend(graph.get(&node).into_iter().flatten());
+        }
+        seen
+    }
+
+    #[test]
+    fn test_bucle() {
+        // 0 -> 1 -> {2, 3} -> 4 -> {1, 5}: un if/else dentro de un bucle
+        let graph = graph_from(&[(0, 1), (1, 2), (1, 3), (2, 4), (3, 4), (4, 1), (4, 5)]);
+        let dom = dominators(&graph, 0);
+
+        assert_eq!(dom.immediate_dominator(0), None);
+        assert_eq!(dom.immediate_dominator(1), Some(0));
+        assert_eq!(dom.immediate_dominator(2), Some(1));
+        assert_eq!(dom.immediate_dominator(4), Some(1));
+        assert_eq!(dom.immediate_dominator(5), Some(4));
+
+        assert_eq!(dom.tree[&1], vec![2, 3, 4]);
+        assert_eq!(dom.tree[&5], Vec::<usize>::new());
+
+        // Las ramas se juntan en 4 y el bucle vuelve a 1
+        assert_eq!(dom.frontiers[&2], vec![4]);
+        assert_eq!(dom.frontiers[&3], vec![4]);
+        assert_eq!(dom.frontiers[&4], vec![1]);
+        assert_eq!(dom.frontiers[&1], vec![1]);
+        assert_eq!(dom.frontiers[&0], Vec::<usize>::new());
+
+        assert!(dom.dominates(1, 5));
+        assert!(dom.dominates(5, 5));
+        assert!(!dom.dominates(2, 4));
+    }
+
+    #[test]
+    fn test_bucle_a_la_raiz() {
+        // 0 -> 1 -> 0: el bucle vuelve al bloque de entrada, que necesita su φ
+        let dom = dominators(&graph_from(&[(0, 1), (1, 0)]), 0);
+        assert_eq!(dom.frontiers[&1], vec![0]);
+        assert_eq!(dom.frontiers[&0], vec![0]);
+
+        // Con dos aristas de vuelta la raíz aparece en ambas fronteras
+        let dom = dominators(&graph_from(&[(0, 1), (0, 2), (1, 0), (2, 0)]), 0);
+        assert_eq!(dom.frontiers[&1], vec![0]);
+        assert_eq!(dom.frontiers[&2], vec![0]);
+        assert_eq!(dom.frontiers[&0], vec![0]);
+    }
+
+    #[test]
+    fn test_grafo_irreducible() {
+        // Ejemplo del artículo de Cooper, Harvey y Kennedy: el ciclo 1 - 2 - 3
+        // tiene varias entradas, así que ninguno domina a los otros
+        let graph = graph_from(&[
+            (6, 5),
+            (6, 4),
+            (5, 1),
+            (4, 2),
+            (4, 3),
+            (1, 2),
+            (2, 1),
+            (2, 3),
+            (3, 2),
+        ]);
+        let dom = dominators(&graph, 6);
+
+        for node in 1..=5 {
+            assert_eq!(dom.immediate_dominator(node), Some(6));
+        }
+        assert_eq!(dom.tree[&6], vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_contra_definicion() {
+        // a domina a b si al quitar a, b deja de ser alcanzable
+        for seed in 0..30u64 {
+            let mut rng = Rng::new(seed);
+            let mut next = || rng.below(12);
+            let edges: Vec<(usize, usize)> = (0..24).map(|_| (next(), next())).collect();
+            let graph = graph_from(&edges);
+            let dom = dominators(&graph, 0);
+            let all = reachable(&graph, 0, usize::MAX);
+
+            for &a in &all {
+                let without_a = reachable(&graph, 0, a);
+                for &b in &all {
+                    let expected = a == b || !without_a.contains(&b);
+                    assert_eq!(dom.dominates(a, b), expected);
+                }
+            }
+            // b está en la frontera de a si a domina a un predecesor de b
+            // pero no domina estrictamente a b
+            for &a in &all {
+                let expected: Vec<usize> = (0..12)
+                    .filter(|&b| all.contains(&b))
+                    .filter(|&b| {
+                        let dominated_predecessor = edges
+                            .iter()
+                            .any(|&(p, to)| to == b && all.contains(&p) && dom.dominates(a, p));
+                        dominated_predecessor && (a == b || !dom.dominates(a, b))
+                    })
+                    .collect();
+                assert_eq!(dom.frontiers[&a], expected);
+            }
+            // Los nodos inalcanzables no aparecen
+            for node in 0..12 {
+                assert_eq!(dom.tree.contains_key(&node), all.contains(&node));
+            }
+        }
+    }
+}
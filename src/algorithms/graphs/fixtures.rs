@@ -0,0 +1,64 @@
+//! Grafos y datos de prueba compartidos por las pruebas del módulo.
+
+use super::WeightedGraph;
+use crate::algorithms::sorting::property::Rng;
+use std::collections::HashMap;
+
+/// Grafo dirigido con las aristas dadas. Los nodos que solo son destino de
+/// alguna arista no aparecen como clave.
+pub(crate) fn graph_from(edges: &[(usize, usize)]) -> HashMap<usize, Vec<usize>> {
+    let mut graph: HashMap<usize, Vec<usize>> = HashMap::new();
+    for &(u, v) in edges {
+        graph.entry(u).or_default().push(v);
+    }
+    graph
+}
+
+/// Grafo no dirigido: cada arista queda en las listas de sus dos extremos.
+pub(crate) fn undirected(edges: &[(usize, usize)]) -> HashMap<usize, Vec<usize>> {
+    let mut graph: HashMap<usize, Vec<usize>> = HashMap::new();
+    for &(u, v) in edges {
+        graph.entry(u).or_default().push(v);
+        graph.entry(v).or_default().push(u);
+    }
+    graph
+}
+
+/// Igual que [`graph_from`], con un peso por arista.
+pub(crate) fn directed(edges: &[(usize, usize, f64)]) -> WeightedGraph {
+    let mut graph: WeightedGraph = HashMap::new();
+    for &(u, v, w) in edges {
+        graph.entry(u).or_default().push((v, w));
+    }
+    graph
+}
+
+/// Igual que [`undirected`], con un peso por arista.
+pub(crate) fn weighted_undirected(edges: &[(usize, usize, f64)]) -> WeightedGraph {
+    let mut graph: WeightedGraph = HashMap::new();
+    for &(u, v, w) in edges {
+        graph.entry(u).or_default().push((v, w));
+        graph.entry(v).or_default().push((u, w));
+    }
+    graph
+}
+
+/// Matriz de distancias euclidianas entre puntos del plano.
+pub(crate) fn euclidean(points: &[(f64, f64)]) -> Vec<Vec<f64>> {
+    points
+        .iter()
+        .map(|&(x1, y1)| {
+            points
+                .iter()
+                .map(|&(x2, y2)| ((x1 - x2).powi(2) + (y1 - y2).powi(2)).sqrt())
+                .collect()
+        })
+        .collect()
+}
+
+/// `n` puntos pseudoaleatorios reproducibles en el cuadrado [0, 100)².
+pub(crate) fn random_points(n: usize, seed: u64) -> Vec<(f64, f64)> {
+    let mut rng = Rng::new(seed);
+    let mut next = || rng.below(10_000) as f64 / 100.0;
+    (0..n).map(|_| (next(), next())).collect()
+}
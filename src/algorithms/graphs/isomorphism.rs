@@ -0,0 +1,567 @@
+use super::nodes;
+use std::collections::HashMap;
+
+/// Enumera los isomorfismos entre dos grafos dirigidos con el algoritmo VF2.
+///
+/// Un isomorfismo es una biyección entre los nodos de `first` y `second` que
+/// conserva las aristas en ambos sentidos: `u -> v` existe en `first` si y
+/// solo si `f(u) -> f(v)` existe en `second`.
+///
+/// # Características:
+/// - Construye la correspondencia de a un par de nodos, y solo extiende
+///   estados consistentes con las aristas entre nodos ya emparejados
+/// - Poda con la regla de anticipación de VF2: cuenta cuántos vecinos de cada
+///   nodo están en la frontera de entrada, en la de salida o todavía fuera,
+///   y esas cantidades deben coincidir
+/// - Es un iterador perezoso; cada elemento es un mapa de nodos de `first` a
+///   nodos de `second`
+/// - Un grafo no dirigido se representa guardando cada arista en ambos
+///   sentidos; las aristas repetidas se cuentan una vez
+pub fn isomorphisms(
+    first: &HashMap<usize, Vec<usize>>,
+    second: &HashMap<usize, Vec<usize>>,
+) -> Mappings<'static> {
+    Mappings::new(first, second, Mode::Isomorphism)
+}
+
+/// Enumera las formas de encontrar `pattern` como subgrafo inducido de
+/// `target`, con el algoritmo VF2.
+///
+/// Cada resultado es un mapa inyectivo de los nodos de `pattern` a nodos de
+/// `target` tal que, entre los nodos elegidos, `target` tiene exactamente las
+/// aristas de `pattern`: ni una menos ni una más. Por ejemplo, un camino de
+/// tres nodos no aparece dentro de un triángulo, porque el triángulo tiene
+/// una arista extra entre los extremos.
+///
+/// La poda es la misma que en [`isomorphisms`], pero exigiendo que `target`
+/// tenga al menos tantos vecinos de cada tipo como `pattern`.
+pub fn subgraph_isomorphisms(
+    pattern: &HashMap<usize, Vec<usize>>,
+    target: &HashMap<usize, Vec<usize>>,
+) -> Mappings<'static> {
+    Mappings::new(pattern, target, Mode::Subgraph)
+}
+
+/// Busca un isomorfismo entre dos grafos. Retorna `None` si no son isomorfos.
+pub fn find_isomorphism(
+    first: &HashMap<usize, Vec<usize>>,
+    second: &HashMap<usize, Vec<usize>>,
+) -> Option<HashMap<usize, usize>> {
+    isomorphisms(first, second).next()
+}
+
+/// Indica si dos grafos son isomorfos.
+pub fn is_isomorphic(
+    first: &HashMap<usize, Vec<usize>>,
+    second: &HashMap<usize, Vec<usize>>,
+) -> bool {
+    find_isomorphism(first, second).is_some()
+}
+
+/// Busca una aparición de `pattern` como subgrafo inducido de `target`.
+pub fn find_subgraph_isomorphism(
+    pattern: &HashMap<usize, Vec<usize>>,
+    target: &HashMap<usize, Vec<usize>>,
+) -> Option<HashMap<usize, usize>> {
+    subgraph_isomorphisms(pattern, target).next()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    Isomorphism,
+    Subgraph,
+}
+
+// Grafo con nodos renumerados 0..n y listas de sucesores y predecesores
+// ordenadas, para consultar aristas con búsqueda binaria
+struct Indexed {
+    nodes: Vec<usize>,
+    successors: Vec<Vec<usize>>,
+    predecessors: Vec<Vec<usize>>,
+}
+
+impl Indexed {
+    fn new(graph: &HashMap<usize, Vec<usize>>) -> Self {
+        let nodes = nodes(graph);
+        let index: HashMap<usize, usize> = nodes.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+        let mut successors = vec![Vec::new(); nodes.len()];
+        let mut predecessors = vec![Vec::new(); nodes.len()];
+        for (node, neighbors) in graph {
+            for neighbor in neighbors {
+                successors[index[node]].push(index[neighbor]);
+                predecessors[index[neighbor]].push(index[node]);
+            }
+        }
+        for list in successors.iter_mut().chain(predecessors.iter_mut()) {
+            list.sort_unstable();
+            list.dedup();
+        }
+
+        Indexed {
+            nodes,
+            successors,
+            predecessors,
+        }
+    }
+
+    fn has_edge(&self, from: usize, to: usize) -> bool {
+        self.successors[from].binary_search(&to).is_ok()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.successors.iter().map(Vec::len).sum()
+    }
+}
+
+// Estado parcial de la correspondencia sobre uno de los dos grafos
+struct Side {
+    graph: Indexed,
+    // Nodo del otro grafo emparejado con cada nodo
+    core: Vec<Option<usize>>,
+    // Profundidad a la que cada nodo entró en la frontera de entrada
+    // (predecesores de nodos emparejados) o de salida (sucesores); 0 si no entró
+    incoming: Vec<usize>,
+    outgoing: Vec<usize>,
+}
+
+impl Side {
+    fn new(graph: &HashMap<usize, Vec<usize>>) -> Self {
+        let graph = Indexed::new(graph);
+        let n = graph.nodes.len();
+        Side {
+            graph,
+            core: vec![None; n],
+            incoming: vec![0; n],
+            outgoing: vec![0; n],
+        }
+    }
+
+    fn in_incoming(&self, node: usize) -> bool {
+        self.core[node].is_none() && self.incoming[node] != 0
+    }
+
+    fn in_outgoing(&self, node: usize) -> bool {
+        self.core[node].is_none() && self.outgoing[node] != 0
+    }
+
+    // Empareja `node` con `other` y agranda las fronteras con sus vecinos
+    fn push(&mut self, node: usize, other: usize, depth: usize) {
+        self.core[node] = Some(other);
+        for (set, neighbors) in [
+            (&mut self.incoming, &self.graph.predecessors[node]),
+            (&mut self.outgoing, &self.graph.successors[node]),
+        ] {
+            for &x in neighbors.iter().chain([&node]) {
+                if set[x] == 0 {
+                    set[x] = depth;
+                }
+            }
+        }
+    }
+
+    // Deshace el emparejamiento hecho a la profundidad `depth`
+    fn pop(&mut self, node: usize, depth: usize) {
+        self.core[node] = None;
+        for set in [&mut self.incoming, &mut self.outgoing] {
+            for entry in set.iter_mut().filter(|entry| **entry == depth) {
+                *entry = 0;
+            }
+        }
+    }
+
+    // Vecinos no emparejados de `node`, clasificados por frontera:
+    // [pred. en entrada, pred. en salida, pred. nuevos, lo mismo para sucesores]
+    fn lookahead(&self, node: usize) -> [usize; 6] {
+        let mut counts = [0; 6];
+        for (offset, neighbors) in [
+            (0, &self.graph.predecessors[node]),
+            (3, &self.graph.successors[node]),
+        ] {
+            for &x in neighbors.iter().filter(|&&x| self.core[x].is_none()) {
+                if self.in_incoming(x) {
+                    counts[offset] += 1;
+                }
+                if self.in_outgoing(x) {
+                    counts[offset + 1] += 1;
+                }
+                if !self.in_incoming(x) && !self.in_outgoing(x) {
+                    counts[offset + 2] += 1;
+                }
+            }
+        }
+        counts
+    }
+}
+
+// Estado de un nivel de la búsqueda: los pares candidatos a probar
+struct Frame {
+    candidates: Vec<(usize, usize)>,
+    next: usize,
+}
+
+/// Iterador sobre las correspondencias de nodos, creado por [`isomorphisms`]
+/// o [`subgraph_isomorphisms`].
+///
+/// La recursión de VF2 se simula con una pila de niveles, así que la búsqueda
+/// queda suspendida entre un resultado y el siguiente.
+pub struct Mappings<'a> {
+    mode: Mode,
+    // `pattern` es el primer grafo; `target`, el segundo
+    pattern: Side,
+    target: Side,
+    matched: Vec<(usize, usize)>,
+    frames: Vec<Frame>,
+    node_match: Option<Box<dyn Fn(usize, usize) -> bool + 'a>>,
+}
+
+impl<'a> Mappings<'a> {
+    fn new(
+        pattern: &HashMap<usize, Vec<usize>>,
+        target: &HashMap<usize, Vec<usize>>,
+        mode: Mode,
+    ) -> Self {
+        let mut mappings = Mappings {
+            mode,
+            pattern: Side::new(pattern),
+            target: Side::new(target),
+            matched: Vec::new(),
+            frames: Vec::new(),
+            node_match: None,
+        };
+
+        // Descarte rápido por cantidad de nodos y aristas
+        let (p, t) = (&mappings.pattern.graph, &mappings.target.graph);
+        let possible = match mode {
+            Mode::Isomorphism => p.nodes.len() == t.nodes.len() && p.edge_count() == t.edge_count(),
+            Mode::Subgraph => p.nodes.len() <= t.nodes.len() && p.edge_count() <= t.edge_count(),
+        };
+        if possible {
+            let candidates = mappings.candidates();
+            mappings.frames.push(Frame {
+                candidates,
+                next: 0,
+            });
+        }
+        mappings
+    }
+
+    /// Agrega una condición sobre los nodos: solo se emparejan `u` del primer
+    /// grafo y `v` del segundo si `node_match(u, v)` es verdadero. Sirve para
+    /// grafos con etiquetas, comparando la etiqueta de cada nodo.
+    pub fn with_node_match<'b>(
+        self,
+        node_match: impl Fn(usize, usize) -> bool + 'b,
+    ) -> Mappings<'b> {
+        Mappings {
+            mode: self.mode,
+            pattern: self.pattern,
+            target: self.target,
+            matched: self.matched,
+            frames: self.frames,
+            node_match: Some(Box::new(node_match)),
+        }
+    }
+
+    // Pares a probar en el estado actual. Se fija un solo nodo de `pattern`
+    // (el menor de la frontera) y se prueban todos los de `target`, porque
+    // cualquier correspondencia completa tiene que emparejarlo con alguno
+    fn candidates(&self) -> Vec<(usize, usize)> {
+        let pattern_nodes = 0..self.pattern.core.len();
+        let target_nodes = 0..self.target.core.len();
+
+        type Filter = fn(&Side, usize) -> bool;
+        let filters: [Filter; 3] = [Side::in_outgoing, Side::in_incoming, |side, node| {
+            side.core[node].is_none()
+        }];
+        for filter in filters {
+            let node = pattern_nodes.clone().find(|&n| filter(&self.pattern, n));
+            let others: Vec<usize> = target_nodes
+                .clone()
+                .filter(|&m| filter(&self.target, m))
+                .collect();
+            if let Some(node) = node {
+                if !others.is_empty() {
+                    return others.into_iter().map(|m| (node, m)).collect();
+                }
+            }
+        }
+        Vec::new()
+    }
+
+    fn feasible(&self, node: usize, other: usize) -> bool {
+        let (p, t) = (&self.pattern.graph, &self.target.graph);
+
+        if let Some(node_match) = &self.node_match {
+            if !node_match(p.nodes[node], t.nodes[other]) {
+                return false;
+            }
+        }
+        if p.has_edge(node, node) != t.has_edge(other, other) {
+            return false;
+        }
+
+        // Las aristas hacia nodos ya emparejados deben existir en los dos grafos
+        let consistent = |a: &Side, b: &Side, x: usize, y: usize| {
+            a.graph.predecessors[x]
+                .iter()
+                .filter_map(|&pred| a.core[pred])
+                .all(|image| b.graph.has_edge(image, y))
+                && a.graph.successors[x]
+                    .iter()
+                    .filter_map(|&succ| a.core[succ])
+                    .all(|image| b.graph.has_edge(y, image))
+        };
+        if !consistent(&self.pattern, &self.target, node, other)
+            || !consistent(&self.target, &self.pattern, other, node)
+        {
+            return false;
+        }
+
+        let counts = self.pattern.lookahead(node);
+        let available = self.target.lookahead(other);
+        match self.mode {
+            Mode::Isomorphism => counts == available,
+            Mode::Subgraph => counts.iter().zip(&available).all(|(a, b)| a <= b),
+        }
+    }
+
+    fn push(&mut self, node: usize, other: usize) {
+        self.matched.push((node, other));
+        let depth = self.matched.len();
+        self.pattern.push(node, other, depth);
+        self.target.push(other, node, depth);
+    }
+
+    fn pop(&mut self) {
+        let depth = self.matched.len();
+        let (node, other) = self.matched.pop().unwrap();
+        self.pattern.pop(node, depth);
+        self.target.pop(other, depth);
+    }
+
+    fn mapping(&self) -> HashMap<usize, usize> {
+        self.matched
+            .iter()
+            .map(|&(n, m)| (self.pattern.graph.nodes[n], self.target.graph.nodes[m]))
+            .collect()
+    }
+}
+
+impl Iterator for Mappings<'_> {
+    type Item = HashMap<usize, usize>;
+
+    fn next(&mut self) -> Option<HashMap<usize, usize>> {
+        let total = self.pattern.core.len();
+        loop {
+            let frame = self.frames.last_mut()?;
+
+            if total == 0 {
+                // Un patrón vacío tiene una única correspondencia: la vacía
+                self.frames.clear();
+                return Some(HashMap::new());
+            }
+
+            let Some(&(node, other)) = frame.candidates.get(frame.next) else {
+                // Nivel agotado: se deshace el par que llevó hasta él
+                self.frames.pop();
+                if !self.frames.is_empty() {
+                    self.pop();
+                }
+                continue;
+            };
+            frame.next += 1;
+
+            if !self.feasible(node, other) {
+                continue;
+            }
+            self.push(node, other);
+            if self.matched.len() == total {
+                let mapping = self.mapping();
+                self.pop();
+                return Some(mapping);
+            }
+            let candidates = self.candidates();
+            self.frames.push(Frame {
+                candidates,
+                next: 0,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::graphs::fixtures::{graph_from, undirected};
+    use crate::algorithms::sorting::property::Rng;
+
+    // Grafo dirigido aleatorio con nodos 0..n (todos presentes como clave)
+    fn random_graph(n: usize, edges: usize, seed: u64) -> HashMap<usize, Vec<usize>> {
+        let mut rng = Rng::new(seed);
+        let mut next = || rng.below(n);
+        let mut graph: HashMap<usize, Vec<usize>> = (0..n).map(|node| (node, Vec::new())).collect();
+        for _ in 0..edges {
+            let (u, v) = (next(), next());
+            graph.get_mut(&u).unwrap().push(v);
+        }
+        graph
+    }
+
+    // Todas las permutaciones de 0..n
+    fn permutations(n: usize) -> Vec<Vec<usize>> {
+        if n == 0 {
+            return vec![Vec::new()];
+        }
+        let mut result = Vec::new();
+        for smaller in permutations(n - 1) {
+            for position in 0..n {
+                let mut permutation = smaller.clone();
+                permutation.insert(position, n - 1);
+                result.push(permutation);
+            }
+        }
+        result
+    }
+
+    fn edge_set(graph: &HashMap<usize, Vec<usize>>) -> Vec<(usize, usize)> {
+        let mut edges: Vec<(usize, usize)> = graph
+            .iter()
+            .flat_map(|(&u, neighbors)| neighbors.iter().map(move |&v| (u, v)))
+            .collect();
+        edges.sort_unstable();
+        edges.dedup();
+        edges
+    }
+
+    fn relabel(
+        graph: &HashMap<usize, Vec<usize>>,
+        permutation: &[usize],
+    ) -> HashMap<usize, Vec<usize>> {
+        let mut relabeled: HashMap<usize, Vec<usize>> =
+            permutation.iter().map(|&node| (node, Vec::new())).collect();
+        for (u, v) in edge_set(graph) {
+            relabeled
+                .get_mut(&permutation[u])
+                .unwrap()
+                .push(permutation[v]);
+        }
+        relabeled
+    }
+
+    #[test]
+    fn test_grafos_permutados() {
+        for seed in 0..20 {
+            let graph = random_graph(9, 20, seed);
+            let permutation: Vec<usize> = (0..9).map(|i| (i * 4 + seed as usize) % 9).collect();
+            let copy = relabel(&graph, &permutation);
+
+            // La correspondencia encontrada transforma un grafo en el otro
+            let mapping = find_isomorphism(&graph, &copy).unwrap();
+            let image: Vec<usize> = (0..9).map(|node| mapping[&node]).collect();
+            assert_eq!(edge_set(&relabel(&graph, &image)), edge_set(&copy));
+        }
+    }
+
+    #[test]
+    fn test_no_isomorfos() {
+        // Un hexágono y dos triángulos: 6 nodos de grado 2 en ambos casos
+        let hexagon = undirected(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]);
+        let triangles = undirected(&[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+        assert!(!is_isomorphic(&hexagon, &triangles));
+
+        // La dirección de las aristas importa
+        let path = graph_from(&[(0, 1), (1, 2)]);
+        let fork = graph_from(&[(0, 1), (0, 2)]);
+        assert!(!is_isomorphic(&path, &fork));
+        assert!(is_isomorphic(&path, &graph_from(&[(7, 3), (5, 7)])));
+    }
+
+    #[test]
+    fn test_contra_fuerza_bruta() {
+        // La cantidad de isomorfismos debe coincidir con las permutaciones válidas
+        for seed in 0..30 {
+            let first = random_graph(5, 7, seed);
+            let second = random_graph(5, 7, seed % 3);
+            let expected = permutations(5)
+                .iter()
+                .filter(|permutation| edge_set(&relabel(&first, permutation)) == edge_set(&second))
+                .count();
+            assert_eq!(isomorphisms(&first, &second).count(), expected);
+        }
+
+        // El cuadrado tiene 8 automorfismos (rotaciones y reflexiones)
+        let square = undirected(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+        assert_eq!(isomorphisms(&square, &square).count(), 8);
+    }
+
+    #[test]
+    fn test_deduplicar_grafos() {
+        // Se agrupan grafos aleatorios de 4 nodos por isomorfismo, y se compara
+        // con una forma canónica por fuerza bruta: el menor conjunto de aristas
+        // entre todas las renumeraciones
+        let mut representatives: Vec<HashMap<usize, Vec<usize>>> = Vec::new();
+        let mut canonical_forms: Vec<Vec<(usize, usize)>> = Vec::new();
+        for seed in 0..200 {
+            let graph = random_graph(4, 4, seed);
+            if !representatives
+                .iter()
+                .any(|other| is_isomorphic(other, &graph))
+            {
+                representatives.push(graph.clone());
+            }
+            let canonical = permutations(4)
+                .iter()
+                .map(|permutation| edge_set(&relabel(&graph, permutation)))
+                .min()
+                .unwrap();
+            canonical_forms.push(canonical);
+        }
+        canonical_forms.sort();
+        canonical_forms.dedup();
+        assert_eq!(representatives.len(), canonical_forms.len());
+    }
+
+    #[test]
+    fn test_subgrafo_inducido() {
+        let k4 = undirected(&[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+        let triangle = undirected(&[(0, 1), (1, 2), (2, 0)]);
+        let path = undirected(&[(0, 1), (1, 2)]);
+        let pentagon = undirected(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+
+        // 4 triángulos en K4, con 6 formas de emparejar cada uno
+        assert_eq!(subgraph_isomorphisms(&triangle, &k4).count(), 24);
+        // En K4 todo par de nodos está unido: no hay caminos inducidos
+        assert_eq!(find_subgraph_isomorphism(&path, &k4), None);
+        // 5 caminos de 3 nodos en el pentágono, recorridos en 2 sentidos
+        assert_eq!(subgraph_isomorphisms(&path, &pentagon).count(), 10);
+
+        let mapping = find_subgraph_isomorphism(&path, &pentagon).unwrap();
+        assert!(pentagon[&mapping[&0]].contains(&mapping[&1]));
+        assert!(!pentagon[&mapping[&0]].contains(&mapping[&2]));
+    }
+
+    #[test]
+    fn test_etiquetas() {
+        // Patrón C-O-H sobre una molécula con etiquetas por nodo
+        let labels = HashMap::from([(0, 'C'), (1, 'O'), (2, 'H'), (3, 'C'), (4, 'O'), (5, 'C')]);
+        let molecule = undirected(&[(0, 1), (1, 2), (3, 4), (4, 5), (0, 3)]);
+        let pattern_labels = HashMap::from([(0, 'C'), (1, 'O'), (2, 'H')]);
+        let pattern = undirected(&[(0, 1), (1, 2)]);
+
+        let found: Vec<HashMap<usize, usize>> = subgraph_isomorphisms(&pattern, &molecule)
+            .with_node_match(|p, t| pattern_labels[&p] == labels[&t])
+            .collect();
+        assert_eq!(found, vec![HashMap::from([(0, 0), (1, 1), (2, 2)])]);
+    }
+
+    #[test]
+    fn test_grafos_vacios() {
+        let empty = HashMap::new();
+        assert_eq!(isomorphisms(&empty, &empty).count(), 1);
+        assert_eq!(
+            subgraph_isomorphisms(&empty, &graph_from(&[(0, 1)])).count(),
+            1
+        );
+        assert!(!is_isomorphic(&empty, &graph_from(&[(0, 1)])));
+    }
+}
@@ -1 +1,34 @@
+use std::collections::{BTreeSet, HashMap};
+
 pub mod basics;
+pub mod centrality;
+pub mod cliques;
+pub mod community;
+pub mod cycles;
+pub mod dominators;
+#[cfg(test)]
+mod fixtures;
+pub mod isomorphism;
+pub mod shortest_paths;
+pub mod transitive;
+pub mod tsp;
+
+/// Grafo con pesos en listas de adyacencia: cada nodo guarda pares
+/// `(vecino, peso)`. Un grafo no dirigido guarda cada arista en las listas de
+/// sus dos extremos con el mismo peso.
+pub type WeightedGraph = HashMap<usize, Vec<(usize, f64)>>;
+
+/// Retorna todos los nodos de un grafo en listas de adyacencia, incluidos los
+/// que solo aparecen como vecinos de otro nodo, ordenados de menor a mayor.
+pub fn nodes(graph: &HashMap<usize, Vec<usize>>) -> Vec<usize> {
+    let mut nodes: BTreeSet<usize> = graph.keys().copied().collect();
+    nodes.extend(graph.values().flatten().copied());
+    nodes.into_iter().collect()
+}
+
+/// Igual que [`nodes`], pero para un [`WeightedGraph`].
+pub fn weighted_nodes(graph: &WeightedGraph) -> Vec<usize> {
+    let mut nodes: BTreeSet<usize> = graph.keys().copied().collect();
+    nodes.extend(graph.values().flatten().map(|&(neighbor, _)| neighbor));
+    nodes.into_iter().collect()
+}
@@ -0,0 +1,206 @@
+use super::Path;
+use crate::algorithms::graphs::WeightedGraph;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+/// Distancias mínimas desde un nodo de origen y el predecesor de cada nodo en
+/// su camino más corto.
+#[derive(Debug, Default)]
+pub struct ShortestPaths {
+    /// Nodo de origen de la búsqueda
+    pub start: usize,
+    /// Costo del camino más corto hasta cada nodo alcanzable
+    pub distance: HashMap<usize, f64>,
+    /// Nodo anterior en el camino más corto (el origen no tiene)
+    pub previous: HashMap<usize, usize>,
+}
+
+impl ShortestPaths {
+    /// Reconstruye el camino más corto hasta `target` siguiendo los predecesores.
+    /// Retorna `None` si `target` no es alcanzable.
+    pub fn path_to(&self, target: usize) -> Option<Path> {
+        let cost = *self.distance.get(&target)?;
+        let mut nodes = vec![target];
+        let mut node = target;
+        while let Some(&previous) = self.previous.get(&node) {
+            nodes.push(previous);
+            node = previous;
+        }
+        nodes.reverse();
+
+        Some(Path { nodes, cost })
+    }
+}
+
+/// Implementa el algoritmo de Dijkstra sobre un grafo dirigido con pesos.
+///
+/// Dijkstra extiende la idea de BFS a aristas con peso: en lugar de una cola
+/// FIFO usa una cola de prioridad, y siempre procesa el nodo pendiente con
+/// menor distancia acumulada. Cuando un nodo sale de la cola su distancia ya
+/// es definitiva.
+///
+/// # Características:
+/// - Tiempo O((n + m) log n) con un montículo binario
+/// - Los pesos deben ser no negativos
+/// - Un grafo no dirigido se representa guardando cada arista en ambos sentidos
+pub fn dijkstra(graph: &WeightedGraph, start: usize) -> ShortestPaths {
+    search(graph, start, None, &HashSet::new(), &HashSet::new())
+}
+
+/// Busca el camino más corto entre `start` y `target`. La búsqueda se detiene
+/// apenas se fija la distancia de `target`.
+pub fn shortest_path(graph: &WeightedGraph, start: usize, target: usize) -> Option<Path> {
+    search(graph, start, Some(target), &HashSet::new(), &HashSet::new()).path_to(target)
+}
+
+/// Igual que [`shortest_path`], pero ignorando los nodos y aristas indicados.
+/// Es la operación que repite el algoritmo de Yen para buscar desvíos.
+pub(super) fn restricted_shortest_path(
+    graph: &WeightedGraph,
+    start: usize,
+    target: usize,
+    removed_nodes: &HashSet<usize>,
+    removed_edges: &HashSet<(usize, usize)>,
+) -> Option<Path> {
+    search(graph, start, Some(target), removed_nodes, removed_edges).path_to(target)
+}
+
+fn search(
+    graph: &WeightedGraph,
+    start: usize,
+    target: Option<usize>,
+    removed_nodes: &HashSet<usize>,
+    removed_edges: &HashSet<(usize, usize)>,
+) -> ShortestPaths {
+    let mut paths = ShortestPaths {
+        start,
+        ..Default::default()
+    };
+    let mut settled: HashSet<usize> = HashSet::new();
+    let mut queue: BinaryHeap<State> = BinaryHeap::new();
+
+    paths.distance.insert(start, 0.0);
+    queue.push(State {
+        cost: 0.0,
+        node: start,
+    });
+
+    while let Some(State { cost, node }) = queue.pop() {
+        // Una misma entrada puede quedar repetida en la cola con un costo viejo
+        if !settled.insert(node) {
+            continue;
+        }
+        if target == Some(node) {
+            break;
+        }
+
+        let Some(neighbors) = graph.get(&node) else {
+            continue;
+        };
+        for &(neighbor, weight) in neighbors {
+            if removed_nodes.contains(&neighbor) || removed_edges.contains(&(node, neighbor)) {
+                continue;
+            }
+
+            // Relajación: si pasar por `node` mejora la distancia, la actualizamos
+            let candidate = cost + weight;
+            let improves = paths
+                .distance
+                .get(&neighbor)
+                .is_none_or(|&current| candidate < current);
+            if improves {
+                paths.distance.insert(neighbor, candidate);
+                paths.previous.insert(neighbor, node);
+                queue.push(State {
+                    cost: candidate,
+                    node: neighbor,
+                });
+            }
+        }
+    }
+
+    paths
+}
+
+// Entrada de la cola de prioridad. `BinaryHeap` es un montículo de máximos,
+// así que el orden se invierte para extraer primero el menor costo
+#[derive(Debug, PartialEq)]
+struct State {
+    cost: f64,
+    node: usize,
+}
+
+impl Eq for State {}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::graphs::fixtures::directed;
+
+    #[test]
+    fn test_distancias() {
+        // El camino directo 0 -> 2 es más caro que pasar por 1
+        let graph = directed(&[
+            (0, 1, 1.0),
+            (0, 2, 5.0),
+            (1, 2, 2.0),
+            (2, 3, 1.0),
+            (4, 0, 1.0),
+        ]);
+
+        let paths = dijkstra(&graph, 0);
+        assert_eq!(paths.distance[&2], 3.0);
+        assert_eq!(paths.distance[&3], 4.0);
+        // El nodo 4 solo tiene aristas de salida: no es alcanzable desde 0
+        assert!(!paths.distance.contains_key(&4));
+
+        let path = paths.path_to(3).unwrap();
+        assert_eq!(path.nodes, vec![0, 1, 2, 3]);
+        assert_eq!(path.cost, 4.0);
+        assert_eq!(paths.path_to(4), None);
+    }
+
+    #[test]
+    fn test_camino_mas_corto() {
+        let graph = directed(&[(0, 1, 1.0), (0, 2, 5.0), (1, 2, 2.0), (2, 3, 1.0)]);
+
+        assert_eq!(shortest_path(&graph, 0, 3).unwrap().nodes, vec![0, 1, 2, 3]);
+        assert_eq!(shortest_path(&graph, 3, 0), None);
+        // El camino de un nodo a sí mismo es el propio nodo, con costo 0
+        assert_eq!(
+            shortest_path(&graph, 2, 2),
+            Some(Path {
+                nodes: vec![2],
+                cost: 0.0
+            })
+        );
+    }
+
+    #[test]
+    fn test_restricciones() {
+        let graph = directed(&[(0, 1, 1.0), (0, 2, 5.0), (1, 2, 2.0), (2, 3, 1.0)]);
+
+        let without_node =
+            restricted_shortest_path(&graph, 0, 3, &HashSet::from([1]), &HashSet::new());
+        assert_eq!(without_node.unwrap().nodes, vec![0, 2, 3]);
+
+        let without_edge =
+            restricted_shortest_path(&graph, 0, 3, &HashSet::new(), &HashSet::from([(2, 3)]));
+        assert_eq!(without_edge, None);
+    }
+}
@@ -0,0 +1,11 @@
+pub mod dijkstra;
+pub mod yen;
+
+/// Camino entre dos nodos junto con su costo total.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Path {
+    /// Nodos del camino en orden, incluidos el origen y el destino
+    pub nodes: Vec<usize>,
+    /// Suma de los pesos de las aristas recorridas
+    pub cost: f64,
+}
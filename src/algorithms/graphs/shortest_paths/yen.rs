@@ -0,0 +1,256 @@
+use super::dijkstra::restricted_shortest_path;
+use super::Path;
+use crate::algorithms::graphs::WeightedGraph;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet};
+
+/// Implementa el algoritmo de Yen para obtener los caminos simples (sin nodos
+/// repetidos) más cortos entre `source` y `target`, de menor a mayor costo.
+///
+/// Cada camino nuevo se construye como desvío de uno ya encontrado: para cada
+/// nodo del último camino (nodo de desvío) se toma el tramo anterior como raíz
+/// y se busca con Dijkstra el mejor camino hasta `target` que no reutilice la
+/// raíz ni las aristas que ya usaron los caminos encontrados con esa misma
+/// raíz. Los desvíos se guardan como candidatos y el más barato pasa a ser el
+/// siguiente camino.
+///
+/// # Características:
+/// - Es un iterador perezoso: usar `.take(k)` para los `k` mejores caminos;
+///   cada camino cuesta O(n) búsquedas de Dijkstra
+/// - Los caminos salen en orden no decreciente de costo. A igual costo el
+///   orden es determinista pero no lexicográfico: un camino puede aparecer
+///   como desvío recién después de entregar otro del mismo costo
+/// - Los pesos deben ser no negativos
+pub fn k_shortest_paths(graph: &WeightedGraph, source: usize, target: usize) -> KShortestPaths<'_> {
+    KShortestPaths {
+        graph,
+        source,
+        target,
+        found: Vec::new(),
+        candidates: BinaryHeap::new(),
+        seen: HashSet::new(),
+        started: false,
+    }
+}
+
+/// Iterador sobre los caminos simples más cortos, creado por [`k_shortest_paths`].
+pub struct KShortestPaths<'a> {
+    graph: &'a WeightedGraph,
+    source: usize,
+    target: usize,
+    // Caminos ya entregados (la lista A del algoritmo)
+    found: Vec<Path>,
+    // Desvíos pendientes ordenados por costo (la lista B del algoritmo)
+    candidates: BinaryHeap<Candidate>,
+    // Caminos que ya pasaron por la lista de candidatos, para no repetirlos
+    seen: HashSet<Vec<usize>>,
+    started: bool,
+}
+
+impl Iterator for KShortestPaths<'_> {
+    type Item = Path;
+
+    fn next(&mut self) -> Option<Path> {
+        if !self.started {
+            // El primer camino es simplemente el más corto
+            self.started = true;
+            let first = restricted_shortest_path(
+                self.graph,
+                self.source,
+                self.target,
+                &HashSet::new(),
+                &HashSet::new(),
+            )?;
+            self.seen.insert(first.nodes.clone());
+            self.found.push(first.clone());
+            return Some(first);
+        }
+
+        self.push_deviations();
+        let Candidate(path) = self.candidates.pop()?;
+        self.found.push(path.clone());
+        Some(path)
+    }
+}
+
+impl KShortestPaths<'_> {
+    // Genera los desvíos del último camino encontrado y los agrega a los candidatos
+    fn push_deviations(&mut self) {
+        let Some(last) = self.found.last() else {
+            return;
+        };
+        let last = last.nodes.clone();
+
+        let mut root_cost = 0.0;
+        for i in 0..last.len() - 1 {
+            let spur = last[i];
+            let root = &last[..=i];
+
+            // Aristas que salen del nodo de desvío en los caminos que comparten la raíz
+            let removed_edges: HashSet<(usize, usize)> = self
+                .found
+                .iter()
+                .filter(|path| path.nodes.len() > i + 1 && &path.nodes[..=i] == root)
+                .map(|path| (path.nodes[i], path.nodes[i + 1]))
+                .collect();
+            // Los nodos de la raíz (salvo el de desvío) no pueden repetirse
+            let removed_nodes: HashSet<usize> = root[..i].iter().copied().collect();
+
+            if let Some(spur_path) = restricted_shortest_path(
+                self.graph,
+                spur,
+                self.target,
+                &removed_nodes,
+                &removed_edges,
+            ) {
+                let mut nodes = root[..i].to_vec();
+                nodes.extend(spur_path.nodes);
+                if self.seen.insert(nodes.clone()) {
+                    self.candidates.push(Candidate(Path {
+                        nodes,
+                        cost: root_cost + spur_path.cost,
+                    }));
+                }
+            }
+
+            root_cost += edge_weight(self.graph, last[i], last[i + 1]);
+        }
+    }
+}
+
+// Peso de la arista más barata de `from` a `to`
+fn edge_weight(graph: &WeightedGraph, from: usize, to: usize) -> f64 {
+    graph[&from]
+        .iter()
+        .filter(|&&(neighbor, _)| neighbor == to)
+        .map(|&(_, weight)| weight)
+        .fold(f64::INFINITY, f64::min)
+}
+
+// Candidato de la cola de prioridad, con el orden invertido para que
+// `BinaryHeap` extraiga primero el de menor costo
+struct Candidate(Path);
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .0
+            .cost
+            .total_cmp(&self.0.cost)
+            .then_with(|| other.0.nodes.cmp(&self.0.nodes))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::graphs::fixtures::directed;
+    use crate::algorithms::sorting::property::Rng;
+    use std::collections::HashMap;
+
+    // Todos los caminos simples por DFS, para comparar contra fuerza bruta
+    fn all_simple_paths(graph: &WeightedGraph, source: usize, target: usize) -> Vec<Path> {
+        fn walk(
+            graph: &WeightedGraph,
+            target: usize,
+            path: &mut Vec<usize>,
+            cost: f64,
+            out: &mut Vec<Path>,
+        ) {
+            let node = *path.last().unwrap();
+            if node == target {
+                out.push(Path {
+                    nodes: path.clone(),
+                    cost,
+                });
+                return;
+            }
+            for &(neighbor, weight) in graph.get(&node).into_iter().flatten() {
+                if !path.contains(&neighbor) {
+                    path.push(neighbor);
+                    walk(graph, target, path, cost + weight, out);
+                    path.pop();
+                }
+            }
+        }
+
+        let mut out = Vec::new();
+        walk(graph, target, &mut vec![source], 0.0, &mut out);
+        out
+    }
+
+    #[test]
+    fn test_ejemplo_clasico() {
+        // Ejemplo habitual de Yen con los nodos C, D, E, F, G, H como 0..=5
+        let graph = directed(&[
+            (0, 1, 3.0),
+            (0, 2, 2.0),
+            (1, 3, 4.0),
+            (2, 1, 1.0),
+            (2, 3, 2.0),
+            (2, 4, 3.0),
+            (3, 4, 2.0),
+            (3, 5, 1.0),
+            (4, 5, 2.0),
+        ]);
+
+        let paths: Vec<Path> = k_shortest_paths(&graph, 0, 5).take(3).collect();
+        assert_eq!(paths[0].nodes, vec![0, 2, 3, 5]); // C-E-F-H, costo 5
+        assert_eq!(paths[1].nodes, vec![0, 2, 4, 5]); // C-E-G-H, costo 7
+        assert_eq!(paths[2].nodes, vec![0, 1, 3, 5]); // C-D-F-H, costo 8
+        assert_eq!(
+            paths.iter().map(|p| p.cost).collect::<Vec<f64>>(),
+            vec![5.0, 7.0, 8.0]
+        );
+    }
+
+    #[test]
+    fn test_contra_fuerza_bruta() {
+        // Grafos pequeños generados con un congruencial lineal
+        for seed in 0..20u64 {
+            let mut rng = Rng::new(seed);
+            let mut next = || rng.next_u32();
+            let mut edges = Vec::new();
+            for _ in 0..20 {
+                let (u, v) = ((next() % 7) as usize, (next() % 7) as usize);
+                if u != v {
+                    edges.push((u, v, (next() % 10 + 1) as f64));
+                }
+            }
+            let graph = directed(&edges);
+
+            // Con aristas paralelas varios caminos comparten nodos; Yen los trata
+            // como uno solo con el costo de las aristas más baratas
+            let mut cheapest: HashMap<Vec<usize>, f64> = HashMap::new();
+            for path in all_simple_paths(&graph, 0, 6) {
+                let cost = cheapest.entry(path.nodes).or_insert(f64::INFINITY);
+                *cost = cost.min(path.cost);
+            }
+            let mut expected: Vec<f64> = cheapest.into_values().collect();
+            expected.sort_by(f64::total_cmp);
+
+            let costs: Vec<f64> = k_shortest_paths(&graph, 0, 6).map(|p| p.cost).collect();
+            assert_eq!(costs, expected);
+        }
+    }
+
+    #[test]
+    fn test_sin_camino() {
+        let graph = directed(&[(0, 1, 1.0)]);
+        assert_eq!(k_shortest_paths(&graph, 1, 0).next(), None);
+    }
+}
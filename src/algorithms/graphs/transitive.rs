@@ -0,0 +1,250 @@
+use super::nodes;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+/// Ordena topológicamente un grafo dirigido con el algoritmo de Kahn.
+///
+/// Repetidamente se toma un nodo sin aristas de entrada pendientes y se
+/// "quitan" sus aristas de salida. Entre los nodos disponibles se elige
+/// siempre el menor (con un montículo de mínimos), así que el orden es
+/// determinista: el menor en orden lexicográfico entre los posibles.
+///
+/// Retorna `None` si el grafo tiene algún ciclo (no es un DAG).
+pub fn topological_sort(graph: &HashMap<usize, Vec<usize>>) -> Option<Vec<usize>> {
+    let nodes = nodes(graph);
+    let mut in_degree: HashMap<usize, usize> = nodes.iter().map(|&node| (node, 0)).collect();
+    for neighbors in graph.values() {
+        for neighbor in neighbors {
+            *in_degree.get_mut(neighbor).unwrap() += 1;
+        }
+    }
+
+    let mut ready: BinaryHeap<Reverse<usize>> = nodes
+        .iter()
+        .copied()
+        .filter(|n| in_degree[n] == 0)
+        .map(Reverse)
+        .collect();
+    let mut order = Vec::with_capacity(nodes.len());
+
+    while let Some(Reverse(node)) = ready.pop() {
+        order.push(node);
+        for neighbor in graph.get(&node).into_iter().flatten() {
+            let degree = in_degree.get_mut(neighbor).unwrap();
+            *degree -= 1;
+            if *degree == 0 {
+                ready.push(Reverse(*neighbor));
+            }
+        }
+    }
+
+    // Si quedaron nodos sin procesar, forman parte de un ciclo
+    (order.len() == nodes.len()).then_some(order)
+}
+
+/// Clausura transitiva de un DAG: para cada nodo, el conjunto de nodos que
+/// alcanza, guardado como una fila de bits.
+///
+/// Con `n` nodos la matriz ocupa `n² / 8` bytes, así que un DAG de 10 000
+/// nodos necesita unos 12 MB.
+#[derive(Debug, Clone)]
+pub struct Reachability {
+    nodes: Vec<usize>,
+    index: HashMap<usize, usize>,
+    // rows[i] tiene encendido el bit j si el nodo i alcanza al nodo j con al
+    // menos una arista
+    rows: Vec<Vec<u64>>,
+}
+
+impl Reachability {
+    /// Indica si existe un camino de `from` a `to`. Todo nodo del grafo se
+    /// alcanza a sí mismo con el camino vacío.
+    pub fn reachable(&self, from: usize, to: usize) -> bool {
+        match (self.index.get(&from), self.index.get(&to)) {
+            (Some(&i), Some(&j)) => i == j || self.rows[i][j / 64] & (1 << (j % 64)) != 0,
+            _ => false,
+        }
+    }
+
+    /// Todos los nodos alcanzables desde `from` (sin incluirlo), en orden creciente.
+    pub fn descendants(&self, from: usize) -> Vec<usize> {
+        let Some(&i) = self.index.get(&from) else {
+            return Vec::new();
+        };
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| self.rows[i][j / 64] & (1 << (j % 64)) != 0)
+            .map(|(_, &node)| node)
+            .collect()
+    }
+}
+
+/// Calcula la clausura transitiva de un DAG.
+///
+/// # Características:
+/// - Recorre los nodos en orden topológico inverso: cuando se procesa un nodo,
+///   las filas de todos sus sucesores ya están completas y basta con unirlas
+///   (un OR de palabras de 64 bits)
+/// - Tiempo O(n · m / 64) y memoria O(n² / 64)
+/// - Retorna `None` si el grafo tiene algún ciclo
+pub fn transitive_closure(graph: &HashMap<usize, Vec<usize>>) -> Option<Reachability> {
+    let order = topological_sort(graph)?;
+    let nodes = nodes(graph);
+    let index: HashMap<usize, usize> = nodes.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+    let words = nodes.len().div_ceil(64);
+    let mut rows = vec![vec![0u64; words]; nodes.len()];
+
+    for node in order.iter().rev() {
+        let i = index[node];
+        let mut row = vec![0u64; words];
+        for neighbor in graph.get(node).into_iter().flatten() {
+            let j = index[neighbor];
+            row[j / 64] |= 1 << (j % 64);
+            for (word, bits) in row.iter_mut().zip(&rows[j]) {
+                *word |= bits;
+            }
+        }
+        rows[i] = row;
+    }
+
+    Some(Reachability { nodes, index, rows })
+}
+
+/// Calcula la reducción transitiva de un DAG: el grafo con menos aristas que
+/// tiene la misma clausura transitiva.
+///
+/// En un DAG la reducción es única: una arista `u -> v` sobra si `v` también
+/// se alcanza desde otro sucesor de `u`. Es el grafo que conviene dibujar en
+/// un diagrama de dependencias, porque elimina las dependencias implícitas.
+///
+/// Las listas de vecinos del resultado vienen ordenadas y sin repetidos; todos
+/// los nodos del grafo aparecen como clave. Retorna `None` si el grafo tiene
+/// algún ciclo.
+pub fn transitive_reduction(
+    graph: &HashMap<usize, Vec<usize>>,
+) -> Option<HashMap<usize, Vec<usize>>> {
+    let closure = transitive_closure(graph)?;
+    let words = closure.nodes.len().div_ceil(64);
+
+    let reduction = closure
+        .nodes
+        .iter()
+        .map(|node| {
+            let mut successors: Vec<usize> = graph.get(node).cloned().unwrap_or_default();
+            successors.sort_unstable();
+            successors.dedup();
+
+            // Unión de lo que alcanzan los sucesores (sin contarse a sí mismos)
+            let mut covered = vec![0u64; words];
+            for successor in &successors {
+                for (word, bits) in covered
+                    .iter_mut()
+                    .zip(&closure.rows[closure.index[successor]])
+                {
+                    *word |= bits;
+                }
+            }
+
+            // Una arista sobra si su destino ya se alcanza por otro sucesor
+            successors.retain(|successor| {
+                let j = closure.index[successor];
+                covered[j / 64] & (1 << (j % 64)) == 0
+            });
+            (*node, successors)
+        })
+        .collect();
+
+    Some(reduction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::graphs::fixtures::graph_from;
+    use crate::algorithms::sorting::property::Rng;
+
+    // DAG aleatorio: solo aristas de un nodo menor a uno mayor
+    fn random_dag(n: usize, edges: usize, seed: u64) -> HashMap<usize, Vec<usize>> {
+        let mut rng = Rng::new(seed);
+        let mut next = || rng.below(n);
+        let mut graph: HashMap<usize, Vec<usize>> = (0..n).map(|node| (node, Vec::new())).collect();
+        for _ in 0..edges {
+            let (u, v) = (next(), next());
+            if u < v {
+                graph.get_mut(&u).unwrap().push(v);
+            }
+        }
+        graph
+    }
+
+    #[test]
+    fn test_orden_topologico() {
+        let graph = graph_from(&[(3, 1), (1, 0), (3, 2), (2, 0)]);
+        assert_eq!(topological_sort(&graph), Some(vec![3, 1, 2, 0]));
+
+        // Entre los disponibles va primero el menor, aunque se haya liberado
+        // después: 2 sale antes que 3
+        let graph = graph_from(&[(0, 3), (1, 2)]);
+        assert_eq!(topological_sort(&graph), Some(vec![0, 1, 2, 3]));
+
+        // Con un ciclo no hay orden topológico ni clausura de DAG
+        let cyclic = graph_from(&[(0, 1), (1, 0)]);
+        assert_eq!(topological_sort(&cyclic), None);
+        assert!(transitive_closure(&cyclic).is_none());
+        assert!(transitive_reduction(&cyclic).is_none());
+    }
+
+    #[test]
+    fn test_clausura() {
+        // 0 -> 1 -> 2 y 0 -> 2, más la componente separada 3 -> 4
+        let graph = graph_from(&[(0, 1), (1, 2), (0, 2), (3, 4)]);
+        let closure = transitive_closure(&graph).unwrap();
+
+        assert!(closure.reachable(0, 2));
+        assert!(closure.reachable(1, 2));
+        assert!(!closure.reachable(2, 0));
+        assert!(!closure.reachable(0, 4));
+        assert!(closure.reachable(2, 2));
+        assert!(!closure.reachable(0, 99));
+        assert_eq!(closure.descendants(0), vec![1, 2]);
+        assert_eq!(closure.descendants(2), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_reduccion() {
+        // La arista 0 -> 2 es implícita por 0 -> 1 -> 2, y 0 -> 3 por 0 -> 1 -> 2 -> 3
+        let graph = graph_from(&[(0, 1), (1, 2), (0, 2), (2, 3), (0, 3), (0, 1)]);
+        let reduction = transitive_reduction(&graph).unwrap();
+
+        assert_eq!(reduction[&0], vec![1]);
+        assert_eq!(reduction[&1], vec![2]);
+        assert_eq!(reduction[&2], vec![3]);
+        assert_eq!(reduction[&3], Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_reduccion_minima() {
+        for seed in 0..10 {
+            // Más de 64 nodos para cubrir filas de varias palabras
+            let graph = random_dag(90, 600, seed);
+            let closure = transitive_closure(&graph).unwrap();
+            let reduction = transitive_reduction(&graph).unwrap();
+            let reduced = transitive_closure(&reduction).unwrap();
+
+            // La reducción conserva exactamente la misma alcanzabilidad
+            for u in 0..90 {
+                assert_eq!(closure.descendants(u), reduced.descendants(u));
+            }
+
+            // Y no sobra ninguna arista: quitar cualquiera cambia la clausura
+            for (&u, successors) in &reduction {
+                for &v in successors {
+                    let mut smaller = reduction.clone();
+                    smaller.get_mut(&u).unwrap().retain(|&w| w != v);
+                    assert!(!transitive_closure(&smaller).unwrap().reachable(u, v));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use super::{tour_cost, Tour};
+
+/// Construye un ciclo aproximado al estilo de Christofides.
+///
+/// # Pasos:
+/// 1. Se calcula un árbol de expansión mínima (Prim)
+/// 2. Se toman los nodos de grado impar del árbol, que siempre son una cantidad par
+/// 3. Se emparejan esos nodos con aristas baratas para que todos los grados sean pares
+/// 4. El árbol más el emparejamiento forman un multigrafo euleriano, del que se
+///    obtiene un circuito de Euler (Hierholzer)
+/// 5. Se recorre el circuito saltando los nodos ya visitados ("atajos")
+///
+/// # Características:
+/// - Tiempo O(n² log n), dominado por ordenar los pares de nodos impares
+/// - Supone una matriz simétrica que cumple la desigualdad triangular, que es
+///   lo que hace que los atajos nunca aumenten el costo
+/// - El emparejamiento es voraz y no el de costo mínimo exacto, por lo que la
+///   cota de 1.5 veces el óptimo de Christofides no está garantizada, aunque
+///   en la práctica el resultado queda cerca; aplicar [`two_opt`] después
+///   suele mejorarlo
+/// - El ciclo empieza en el nodo 0 y retorna `None` si la matriz está vacía
+///
+/// [`two_opt`]: super::heuristics::two_opt
+pub fn christofides(dist: &[Vec<f64>]) -> Option<Tour> {
+    let n = dist.len();
+    if n == 0 {
+        return None;
+    }
+
+    // Aristas del multigrafo: árbol de expansión mínima más el emparejamiento
+    let mut edges = minimum_spanning_tree(dist);
+    let mut degree = vec![0usize; n];
+    for &(u, v) in &edges {
+        degree[u] += 1;
+        degree[v] += 1;
+    }
+    let odd: Vec<usize> = (0..n).filter(|&node| degree[node] % 2 == 1).collect();
+    edges.extend(greedy_matching(dist, &odd));
+
+    // Atajos: nos quedamos con la primera aparición de cada nodo en el circuito
+    let mut visited = vec![false; n];
+    let order: Vec<usize> = euler_circuit(n, &edges)
+        .into_iter()
+        .filter(|&node| !std::mem::replace(&mut visited[node], true))
+        .collect();
+
+    let cost = tour_cost(dist, &order);
+    Some(Tour { order, cost })
+}
+
+/// Árbol de expansión mínima con el algoritmo de Prim en su versión O(n²),
+/// la más adecuada para grafos completos dados como matriz.
+fn minimum_spanning_tree(dist: &[Vec<f64>]) -> Vec<(usize, usize)> {
+    let n = dist.len();
+    let mut in_tree = vec![false; n];
+    // Arista más barata que conecta cada nodo con el árbol: (costo, nodo del árbol)
+    let mut best = vec![(f64::INFINITY, 0); n];
+    let mut edges = Vec::with_capacity(n.saturating_sub(1));
+
+    best[0] = (0.0, 0);
+    for _ in 0..n {
+        let node = (0..n)
+            .filter(|&node| !in_tree[node])
+            .min_by(|&a, &b| best[a].0.total_cmp(&best[b].0))
+            .expect("quedan nodos fuera del árbol");
+
+        in_tree[node] = true;
+        if node != 0 {
+            edges.push((best[node].1, node));
+        }
+
+        for other in 0..n {
+            if !in_tree[other] && dist[node][other] < best[other].0 {
+                best[other] = (dist[node][other], node);
+            }
+        }
+    }
+
+    edges
+}
+
+/// Empareja los nodos recibidos tomando siempre el par libre más cercano.
+fn greedy_matching(dist: &[Vec<f64>], nodes: &[usize]) -> Vec<(usize, usize)> {
+    let mut pairs: Vec<(usize, usize)> = Vec::new();
+    for (i, &u) in nodes.iter().enumerate() {
+        for &v in &nodes[i + 1..] {
+            pairs.push((u, v));
+        }
+    }
+    pairs.sort_by(|a, b| dist[a.0][a.1].total_cmp(&dist[b.0][b.1]));
+
+    let mut matched = vec![false; dist.len()];
+    let mut matching = Vec::with_capacity(nodes.len() / 2);
+    for (u, v) in pairs {
+        if !matched[u] && !matched[v] {
+            matched[u] = true;
+            matched[v] = true;
+            matching.push((u, v));
+        }
+    }
+
+    matching
+}
+
+/// Circuito de Euler con el algoritmo de Hierholzer, empezando en el nodo 0.
+///
+/// Las aristas pueden repetirse (multigrafo) y todos los nodos deben tener
+/// grado par.
+fn euler_circuit(n: usize, edges: &[(usize, usize)]) -> Vec<usize> {
+    // Lista de adyacencia con el identificador de cada arista para marcarla como usada
+    let mut adjacency: Vec<Vec<(usize, usize)>> = vec![Vec::new(); n];
+    for (id, &(u, v)) in edges.iter().enumerate() {
+        adjacency[u].push((v, id));
+        adjacency[v].push((u, id));
+    }
+    let mut used = vec![false; edges.len()];
+
+    let mut circuit = Vec::with_capacity(edges.len() + 1);
+    let mut stack = vec![0];
+    while let Some(&node) = stack.last() {
+        // Descartamos las aristas ya recorridas desde el otro extremo
+        while adjacency[node].last().is_some_and(|&(_, id)| used[id]) {
+            adjacency[node].pop();
+        }
+
+        match adjacency[node].pop() {
+            Some((next, id)) => {
+                used[id] = true;
+                stack.push(next);
+            }
+            // Sin aristas libres: el nodo se cierra y pasa al circuito
+            None => circuit.push(stack.pop().unwrap()),
+        }
+    }
+
+    circuit.reverse();
+    circuit
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::graphs::fixtures::{euclidean, random_points};
+    use crate::algorithms::graphs::tsp::held_karp::held_karp_tour;
+    use crate::algorithms::graphs::tsp::heuristics::two_opt;
+
+    #[test]
+    fn test_recorre_todos_los_nodos() {
+        let dist = euclidean(&random_points(40, 7));
+        let tour = christofides(&dist).unwrap();
+
+        let mut nodes = tour.order.clone();
+        nodes.sort_unstable();
+        assert_eq!(nodes, (0..40).collect::<Vec<usize>>());
+        assert_eq!(tour.order[0], 0);
+        assert_eq!(tour.cost, tour_cost(&dist, &tour.order));
+    }
+
+    #[test]
+    fn test_cerca_del_optimo() {
+        for seed in 0..5 {
+            let dist = euclidean(&random_points(11, seed));
+            let optimal = held_karp_tour(&dist).unwrap().cost;
+
+            let tour = christofides(&dist).unwrap();
+            assert!(tour.cost >= optimal - 1e-9);
+            // Con emparejamiento voraz la cota es más holgada que 1.5
+            assert!(tour.cost <= optimal * 2.0);
+            assert!(two_opt(&dist, tour.clone()).cost <= tour.cost + 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_casos_triviales() {
+        assert_eq!(christofides(&[]), None);
+
+        let tour = christofides(&[vec![0.0]]).unwrap();
+        assert_eq!((tour.order, tour.cost), (vec![0], 0.0));
+
+        let tour = christofides(&[vec![0.0, 2.0], vec![2.0, 0.0]]).unwrap();
+        assert_eq!((tour.order, tour.cost), (vec![0, 1], 4.0));
+    }
+}
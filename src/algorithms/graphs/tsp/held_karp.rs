@@ -0,0 +1,255 @@
+use super::Tour;
+
+/// Cantidad máxima de nodos que aceptan las funciones de este módulo.
+///
+/// La tabla de programación dinámica tiene `2^n * n` entradas, así que con 20
+/// nodos ya ocupa unos 190 MB; por encima de eso conviene usar las heurísticas.
+pub const MAX_NODES: usize = 20;
+
+/// Resuelve de forma exacta el problema del viajante (TSP) con el algoritmo
+/// de Held-Karp.
+///
+/// Held-Karp es una programación dinámica sobre subconjuntos: `costo[S][j]` es
+/// el menor costo de salir del nodo 0, visitar exactamente los nodos de `S` y
+/// terminar en `j`. Cada subconjunto se representa con una máscara de bits.
+///
+/// # Características:
+/// - Tiempo O(2^n · n²) y memoria O(2^n · n)
+/// - El ciclo comienza siempre en el nodo 0
+/// - La matriz puede ser asimétrica; una distancia `f64::INFINITY` indica que
+///   no hay arista entre ese par de nodos
+/// - Retorna `None` si la matriz está vacía o no existe ningún ciclo
+///
+/// # Panics
+/// Si la matriz tiene más de [`MAX_NODES`] nodos.
+pub fn held_karp_tour(dist: &[Vec<f64>]) -> Option<Tour> {
+    let n = dist.len();
+    if n == 0 {
+        return None;
+    }
+    let table = Table::build(dist, &[0]);
+    let full = (1 << n) - 1;
+
+    // Se cierra el ciclo desde el último nodo de vuelta al nodo 0
+    let (last, cost) = (0..n)
+        .map(|j| {
+            (
+                j,
+                table.cost(full, j) + if n > 1 { dist[j][0] } else { 0.0 },
+            )
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))?;
+
+    table.tour(full, last, cost)
+}
+
+/// Busca el camino hamiltoniano de menor costo con la misma programación
+/// dinámica que [`held_karp_tour`].
+///
+/// A diferencia del ciclo, el camino puede empezar y terminar en cualquier
+/// nodo, por lo que la tabla se inicializa con todos los nodos como posibles
+/// puntos de partida. Retorna `None` si no existe un camino que pase por
+/// todos los nodos usando solo distancias finitas.
+///
+/// # Panics
+/// Si la matriz tiene más de [`MAX_NODES`] nodos.
+pub fn held_karp_path(dist: &[Vec<f64>]) -> Option<Tour> {
+    let n = dist.len();
+    let starts: Vec<usize> = (0..n).collect();
+    let table = Table::build(dist, &starts);
+    let full = (1 << n) - 1;
+
+    let (last, cost) = (0..n)
+        .map(|j| (j, table.cost(full, j)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))?;
+
+    table.tour(full, last, cost)
+}
+
+// Tabla de la programación dinámica junto con el predecesor de cada estado
+struct Table {
+    n: usize,
+    cost: Vec<f64>,
+    parent: Vec<u8>,
+}
+
+// Marca de "sin predecesor" en la tabla de padres
+const NO_PARENT: u8 = u8::MAX;
+
+impl Table {
+    fn build(dist: &[Vec<f64>], starts: &[usize]) -> Self {
+        let n = dist.len();
+        assert!(
+            n <= MAX_NODES,
+            "Held-Karp admite como máximo {} nodos, se recibieron {}",
+            MAX_NODES,
+            n
+        );
+
+        let mut table = Self {
+            n,
+            cost: vec![f64::INFINITY; (1 << n) * n],
+            parent: vec![NO_PARENT; (1 << n) * n],
+        };
+
+        // Caso base: el camino formado solo por el nodo de partida cuesta 0
+        for &start in starts {
+            table.cost[(1 << start) * n + start] = 0.0;
+        }
+
+        // Las máscaras se recorren en orden creciente, así que todo subconjunto
+        // se procesa antes que sus superconjuntos
+        for mask in 1..(1usize << n) {
+            for (last, row) in dist.iter().enumerate() {
+                let current = table.cost[mask * n + last];
+                if mask & (1 << last) == 0 || current == f64::INFINITY {
+                    continue;
+                }
+
+                // Extendemos el camino hacia cada nodo que todavía no se visitó
+                for (next, &step) in row.iter().enumerate() {
+                    if mask & (1 << next) != 0 {
+                        continue;
+                    }
+                    let extended = mask | (1 << next);
+                    let candidate = current + step;
+                    if candidate < table.cost[extended * n + next] {
+                        table.cost[extended * n + next] = candidate;
+                        table.parent[extended * n + next] = last as u8;
+                    }
+                }
+            }
+        }
+
+        table
+    }
+
+    fn cost(&self, mask: usize, last: usize) -> f64 {
+        self.cost[mask * self.n + last]
+    }
+
+    // Reconstruye el orden de visita siguiendo los predecesores desde el final
+    fn tour(&self, mut mask: usize, mut last: usize, cost: f64) -> Option<Tour> {
+        if cost == f64::INFINITY {
+            return None;
+        }
+
+        let mut order = Vec::with_capacity(self.n);
+        loop {
+            order.push(last);
+            let parent = self.parent[mask * self.n + last];
+            if parent == NO_PARENT {
+                break;
+            }
+            mask &= !(1 << last);
+            last = parent as usize;
+        }
+        order.reverse();
+
+        Some(Tour { order, cost })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::graphs::tsp::{path_cost, tour_cost};
+    use crate::algorithms::sorting::property::Rng;
+
+    // Generador congruencial lineal para construir matrices reproducibles
+    fn random_matrix(n: usize, seed: u64) -> Vec<Vec<f64>> {
+        let mut rng = Rng::new(seed);
+        let mut next = || rng.below(100) as f64 + 1.0;
+        (0..n)
+            .map(|i| (0..n).map(|j| if i == j { 0.0 } else { next() }).collect())
+            .collect()
+    }
+
+    // Recorre todas las permutaciones para obtener el óptimo por fuerza bruta
+    fn brute_force(dist: &[Vec<f64>], closed: bool) -> f64 {
+        fn permute(
+            items: &mut Vec<usize>,
+            k: usize,
+            best: &mut f64,
+            cost: &dyn Fn(&[usize]) -> f64,
+        ) {
+            if k == items.len() {
+                *best = best.min(cost(items));
+                return;
+            }
+            for i in k..items.len() {
+                items.swap(k, i);
+                permute(items, k + 1, best, cost);
+                items.swap(k, i);
+            }
+        }
+
+        let mut items: Vec<usize> = (0..dist.len()).collect();
+        let mut best = f64::INFINITY;
+        if closed {
+            permute(&mut items, 1, &mut best, &|order| tour_cost(dist, order));
+        } else {
+            permute(&mut items, 0, &mut best, &|order| path_cost(dist, order));
+        }
+        best
+    }
+
+    #[test]
+    fn test_ciclo_conocido() {
+        // Cuatro ciudades en las esquinas de un cuadrado de lado 1
+        let d = std::f64::consts::SQRT_2;
+        let dist = vec![
+            vec![0.0, 1.0, d, 1.0],
+            vec![1.0, 0.0, 1.0, d],
+            vec![d, 1.0, 0.0, 1.0],
+            vec![1.0, d, 1.0, 0.0],
+        ];
+
+        let tour = held_karp_tour(&dist).unwrap();
+        assert_eq!(tour.cost, 4.0);
+        assert_eq!(tour.order[0], 0);
+        assert_eq!(tour_cost(&dist, &tour.order), tour.cost);
+    }
+
+    #[test]
+    fn test_contra_fuerza_bruta() {
+        for seed in 0..10 {
+            // Matrices asimétricas de 7 nodos
+            let dist = random_matrix(7, seed);
+
+            let tour = held_karp_tour(&dist).unwrap();
+            assert_eq!(tour.cost, brute_force(&dist, true));
+            assert_eq!(tour_cost(&dist, &tour.order), tour.cost);
+
+            let path = held_karp_path(&dist).unwrap();
+            assert_eq!(path.cost, brute_force(&dist, false));
+            assert_eq!(path_cost(&dist, &path.order), path.cost);
+        }
+    }
+
+    #[test]
+    fn test_sin_camino() {
+        // El nodo 2 solo se conecta con el 1: hay camino pero no ciclo
+        let inf = f64::INFINITY;
+        let dist = vec![
+            vec![0.0, 1.0, inf],
+            vec![1.0, 0.0, 2.0],
+            vec![inf, 2.0, 0.0],
+        ];
+
+        assert_eq!(held_karp_tour(&dist), None);
+        let path = held_karp_path(&dist).unwrap();
+        assert_eq!(path.cost, 3.0);
+        assert!(path.order == vec![0, 1, 2] || path.order == vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn test_casos_triviales() {
+        assert_eq!(held_karp_tour(&[]), None);
+        assert_eq!(held_karp_path(&[]), None);
+
+        let single = vec![vec![0.0]];
+        let tour = held_karp_tour(&single).unwrap();
+        assert_eq!((tour.order, tour.cost), (vec![0], 0.0));
+    }
+}
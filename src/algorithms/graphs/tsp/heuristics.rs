@@ -0,0 +1,129 @@
+use super::{tour_cost, Tour};
+
+/// Construye un ciclo con la heurística del vecino más cercano.
+///
+/// Partiendo de `start`, en cada paso se viaja al nodo no visitado más cercano
+/// al nodo actual; al visitar todos se vuelve al inicio.
+///
+/// # Características:
+/// - Tiempo O(n²), válido para instancias de cualquier tamaño
+/// - No garantiza el óptimo; suele quedar a un 25% del óptimo en instancias
+///   euclidianas y sirve como punto de partida para [`two_opt`]
+/// - Retorna `None` si la matriz está vacía o si `start` no es un nodo
+pub fn nearest_neighbour(dist: &[Vec<f64>], start: usize) -> Option<Tour> {
+    let n = dist.len();
+    if start >= n {
+        return None;
+    }
+
+    let mut visited = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    let mut current = start;
+    visited[current] = true;
+    order.push(current);
+
+    while order.len() < n {
+        // Elegimos el nodo sin visitar a menor distancia del actual
+        let next = (0..n)
+            .filter(|&node| !visited[node])
+            .min_by(|&a, &b| dist[current][a].total_cmp(&dist[current][b]))?;
+
+        visited[next] = true;
+        order.push(next);
+        current = next;
+    }
+
+    let cost = tour_cost(dist, &order);
+    Some(Tour { order, cost })
+}
+
+/// Mejora un ciclo con búsqueda local 2-opt.
+///
+/// Un movimiento 2-opt quita dos aristas `(a, b)` y `(c, d)` del ciclo y las
+/// reemplaza por `(a, c)` y `(b, d)`, lo que equivale a invertir el tramo entre
+/// `b` y `c`. Se aplican movimientos mientras alguno reduzca el costo, hasta
+/// llegar a un óptimo local.
+///
+/// # Características:
+/// - Cada pasada cuesta O(n²); el número de pasadas depende de la entrada
+/// - Supone una matriz simétrica, ya que invertir un tramo no cambia su costo
+/// - El ciclo resultante nunca es más caro que el recibido
+pub fn two_opt(dist: &[Vec<f64>], tour: Tour) -> Tour {
+    let mut order = tour.order;
+    let n = order.len();
+    // Tolerancia para no ciclar por errores de redondeo
+    let epsilon = 1e-10;
+    let mut improved = true;
+
+    while improved {
+        improved = false;
+
+        for i in 0..n.saturating_sub(1) {
+            for j in (i + 2)..n {
+                let (a, b) = (order[i], order[i + 1]);
+                let (c, d) = (order[j], order[(j + 1) % n]);
+                // Si d es a, las dos aristas son adyacentes y el movimiento no cambia nada
+                if d == a {
+                    continue;
+                }
+
+                let delta = dist[a][c] + dist[b][d] - dist[a][b] - dist[c][d];
+                if delta < -epsilon {
+                    order[i + 1..=j].reverse();
+                    improved = true;
+                }
+            }
+        }
+    }
+
+    let cost = tour_cost(dist, &order);
+    Tour { order, cost }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::graphs::fixtures::{euclidean, random_points};
+    use crate::algorithms::graphs::tsp::held_karp::held_karp_tour;
+
+    #[test]
+    fn test_vecino_mas_cercano() {
+        // Puntos sobre una recta: el vecino más cercano los recorre en orden
+        let dist = euclidean(&[(0.0, 0.0), (3.0, 0.0), (1.0, 0.0), (2.0, 0.0)]);
+
+        let tour = nearest_neighbour(&dist, 0).unwrap();
+        assert_eq!(tour.order, vec![0, 2, 3, 1]);
+        assert_eq!(tour.cost, 6.0);
+        assert_eq!(nearest_neighbour(&[], 0), None);
+        assert_eq!(nearest_neighbour(&dist, dist.len()), None);
+    }
+
+    #[test]
+    fn test_two_opt_deshace_cruces() {
+        // El ciclo 0 -> 2 -> 1 -> 3 cruza las diagonales del cuadrado
+        let dist = euclidean(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+        let crossed = Tour {
+            order: vec![0, 2, 1, 3],
+            cost: tour_cost(&dist, &[0, 2, 1, 3]),
+        };
+
+        let tour = two_opt(&dist, crossed);
+        assert_eq!(tour.cost, 4.0);
+    }
+
+    #[test]
+    fn test_cercano_al_optimo() {
+        for seed in 0..5 {
+            let dist = euclidean(&random_points(10, seed));
+            let optimal = held_karp_tour(&dist).unwrap().cost;
+
+            let greedy = nearest_neighbour(&dist, 0).unwrap();
+            let improved = two_opt(&dist, greedy.clone());
+
+            assert!(improved.cost <= greedy.cost + 1e-9);
+            assert!(improved.cost >= optimal - 1e-9);
+            // 2-opt sobre instancias euclidianas pequeñas queda muy cerca del óptimo
+            assert!(improved.cost <= optimal * 1.2);
+        }
+    }
+}
@@ -0,0 +1,30 @@
+pub mod christofides;
+pub mod held_karp;
+pub mod heuristics;
+
+/// Recorrido sobre una matriz de distancias junto con su costo total.
+///
+/// `order` contiene cada nodo una sola vez en el orden de visita. Para un
+/// ciclo (TSP) el regreso al primer nodo está implícito y su costo ya está
+/// incluido en `cost`; para un camino hamiltoniano `cost` es la suma de las
+/// aristas entre nodos consecutivos.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tour {
+    pub order: Vec<usize>,
+    pub cost: f64,
+}
+
+/// Costo de recorrer `order` como un ciclo, volviendo al primer nodo al final.
+pub fn tour_cost(dist: &[Vec<f64>], order: &[usize]) -> f64 {
+    match (order.first(), order.last()) {
+        (Some(&first), Some(&last)) if order.len() > 1 => {
+            path_cost(dist, order) + dist[last][first]
+        }
+        _ => 0.0,
+    }
+}
+
+/// Costo de recorrer `order` como un camino abierto.
+pub fn path_cost(dist: &[Vec<f64>], order: &[usize]) -> f64 {
+    order.windows(2).map(|pair| dist[pair[0]][pair[1]]).sum()
+}
@@ -0,0 +1,124 @@
+use super::pdq_sort::pdq_sort_by;
+use super::radix_sort::radix_sort;
+
+/// # Bucket Sort
+///
+/// Ordenamiento para flotantes distribuidos de manera uniforme: el rango se
+/// divide en tantos intervalos iguales (cubetas) como elementos, así que en
+/// promedio a cada cubeta le toca uno.
+///
+/// ## Pasos
+///
+/// 1. **Rango**: Se buscan el mínimo y el máximo.
+/// 2. **Reparto**: Cada valor va a la cubeta `(x - min) / (max - min) · n`.
+///    Las cubetas se arman como en Counting Sort: se cuenta cuántos
+///    elementos tiene cada una y se copian a un buffer, cada una en su
+///    tramo.
+/// 3. **Cubetas**: Cada tramo se ordena por separado con
+///    [`pdq_sort`](super::pdq_sort::pdq_sort), que en tramos de uno o dos
+///    elementos es casi gratis.
+///
+/// ## Características
+///
+/// - Tiempo O(n) en promedio si la distribución es uniforme. Si no lo es,
+///   las cubetas se desbalancean, pero el peor caso sigue siendo O(n log n)
+/// - Memoria extra O(n)
+/// - No es estable
+/// - Si hay infinitos o NaN, el rango no se puede dividir y se ordena con
+///   [`radix_sort`](super::radix_sort::radix_sort), en el orden de
+///   `total_cmp`
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::bucket_sort::bucket_sort;
+///
+/// let mut values = vec![0.42, 0.32, 0.23, 0.52, 0.25, 0.47, 0.51];
+/// bucket_sort(&mut values);
+/// assert_eq!(values, vec![0.23, 0.25, 0.32, 0.42, 0.47, 0.51, 0.52]);
+/// ```
+pub fn bucket_sort(arr: &mut [f64]) {
+    let len = arr.len();
+    if len < 2 {
+        return;
+    }
+
+    let (min, max) = arr
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &x| {
+            (min.min(x), max.max(x))
+        });
+    let width = max - min;
+    if !width.is_finite() || arr.iter().any(|x| x.is_nan()) {
+        radix_sort(arr);
+        return;
+    }
+
+    // `as usize` satura: con todos iguales (ancho 0) el índice es 0, y el
+    // máximo cae justo en `len`, que se manda a la última cubeta
+    let bucket = |x: f64| (((x - min) / width * len as f64) as usize).min(len - 1);
+
+    let mut starts = vec![0usize; len + 1];
+    for &x in arr.iter() {
+        starts[bucket(x) + 1] += 1;
+    }
+    for i in 1..=len {
+        starts[i] += starts[i - 1];
+    }
+
+    let mut buffer = vec![0.0; len];
+    let mut next = starts.clone();
+    for &x in arr.iter() {
+        let b = bucket(x);
+        buffer[next[b]] = x;
+        next[b] += 1;
+    }
+
+    for b in 0..len {
+        pdq_sort_by(&mut buffer[starts[b]..starts[b + 1]], f64::total_cmp);
+    }
+    arr.copy_from_slice(&buffer);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::property::Rng;
+
+    // Uniformes en [0, 1)
+    fn random_floats(len: usize, seed: u64) -> Vec<f64> {
+        let mut rng = Rng::new(seed);
+        (0..len).map(|_| rng.next_f64()).collect()
+    }
+
+    fn check(mut values: Vec<f64>) {
+        let mut expected = values.clone();
+        expected.sort_by(f64::total_cmp);
+        bucket_sort(&mut values);
+        let bits = |v: &[f64]| v.iter().map(|x| x.to_bits()).collect::<Vec<_>>();
+        assert_eq!(bits(&values), bits(&expected));
+    }
+
+    #[test]
+    fn test_uniformes() {
+        for len in [0, 1, 2, 3, 100, 10_000] {
+            check(random_floats(len, len as u64));
+            check(
+                random_floats(len, 1)
+                    .iter()
+                    .map(|x| x * 2e6 - 1e6)
+                    .collect(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_casos_borde() {
+        // Todos iguales, distribución muy sesgada, y valores no finitos
+        check(vec![0.5; 100]);
+        check(vec![-0.0, 0.0, -0.0, 0.0]);
+        check(random_floats(1_000, 2).iter().map(|x| x.powi(20)).collect());
+        check(vec![1.0, f64::NAN, -f64::INFINITY, 0.5]);
+        check(vec![f64::MAX, f64::MIN, 0.0]);
+    }
+}
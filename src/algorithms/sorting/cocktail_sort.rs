@@ -0,0 +1,109 @@
+use super::probe::{swap, NoProbe, Probe};
+use std::cmp::Ordering;
+
+/// # Cocktail Shaker Sort
+///
+/// Bubble Sort en las dos direcciones: una pasada hacia la derecha lleva el
+/// mayor al final, y otra hacia la izquierda lleva el menor al principio.
+///
+/// ## Pasos
+///
+/// 1. **Ida**: Se recorre la parte sin ordenar de izquierda a derecha,
+///    intercambiando cada par de vecinos fuera de orden.
+/// 2. **Vuelta**: Se recorre de derecha a izquierda, igual.
+/// 3. **Achique**: Después del último intercambio de cada pasada todo está
+///    en su lugar, así que los bordes de la parte sin ordenar saltan hasta
+///    ahí. Si una pasada no intercambia nada, terminó.
+///
+/// ## Características
+///
+/// - Tiempo O(n²) en el peor caso y O(n) con la entrada ordenada
+/// - A diferencia de Bubble Sort, los elementos chicos que están al final
+///   (las "tortugas") llegan a su lugar en una sola vuelta
+/// - Sin memoria auxiliar ni `Clone`: solo intercambios
+/// - Es estable: solo se intercambian vecinos estrictamente fuera de orden
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::cocktail_sort::cocktail_sort;
+///
+/// let mut numbers = vec![2, 3, 4, 5, 1];
+/// cocktail_sort(&mut numbers);
+/// assert_eq!(numbers, vec![1, 2, 3, 4, 5]);
+/// ```
+pub fn cocktail_sort<T: Ord>(arr: &mut [T]) {
+    cocktail_sort_by(arr, T::cmp);
+}
+
+/// Igual que [`cocktail_sort`], pero con una función de comparación propia.
+pub fn cocktail_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    cocktail_sort_probed(arr, compare, &NoProbe);
+}
+
+/// Igual que [`cocktail_sort_by`], avisando cada operación a `probe`.
+pub fn cocktail_sort_probed<T, F, P>(arr: &mut [T], mut compare: F, probe: &P)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    let mut out_of_order = |arr: &[T], i: usize| {
+        probe.compare(&arr[i], &arr[i - 1]);
+        compare(&arr[i], &arr[i - 1]) == Ordering::Less
+    };
+
+    // La parte sin ordenar es arr[start..end]
+    let (mut start, mut end) = (0, arr.len());
+    while start + 1 < end {
+        let mut last = start;
+        for i in start + 1..end {
+            if out_of_order(arr, i) {
+                swap(arr, i - 1, i, probe);
+                last = i;
+            }
+        }
+        end = last;
+
+        let mut first = end;
+        for i in (start + 1..end).rev() {
+            if out_of_order(arr, i) {
+                swap(arr, i - 1, i, probe);
+                first = i;
+            }
+        }
+        start = first;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::instrument::{Counters, Distribution};
+
+    #[test]
+    fn test_cocktail_sort() {
+        for distribution in Distribution::ALL {
+            for len in [0, 1, 2, 3, 10, 200] {
+                // Pares (clave, posición original) para comprobar la estabilidad
+                let mut values: Vec<(u32, usize)> = distribution
+                    .generate(len, 9)
+                    .into_iter()
+                    .map(|x| x % 10)
+                    .zip(0..)
+                    .collect();
+                let mut expected = values.clone();
+                expected.sort_by_key(|&(key, _)| key);
+                cocktail_sort_by(&mut values, |a, b| a.0.cmp(&b.0));
+                assert_eq!(values, expected, "{distribution} con {len} elementos");
+            }
+        }
+
+        let mut sorted: Vec<u32> = (0..100).collect();
+        let counters = Counters::new();
+        cocktail_sort_probed(&mut sorted, u32::cmp, &counters);
+        assert_eq!(counters.counts().comparisons, 99);
+    }
+}
@@ -0,0 +1,92 @@
+use super::probe::{swap, NoProbe, Probe};
+use std::cmp::Ordering;
+
+/// # Comb Sort
+///
+/// Bubble Sort con saltos (Dobosiewicz, 1980; Lacey y Box, 1991): compara
+/// elementos a distancia `gap`, y el salto se achica en cada pasada hasta
+/// llegar a 1.
+///
+/// ## Pasos
+///
+/// 1. **Salto**: Empieza en el largo del slice y en cada pasada se divide
+///    por 1,3. Los saltos 9 y 10 se reemplazan por 11, que deja menos
+///    elementos fuera de lugar para el final.
+/// 2. **Pasada**: Se recorre el slice intercambiando cada par a distancia
+///    `gap` que esté fuera de orden.
+/// 3. **Final**: Con salto 1 es Bubble Sort, y se repite hasta una pasada
+///    sin intercambios.
+///
+/// ## Características
+///
+/// - Tiempo O(n²) en el peor caso, pero en la práctica cerca de O(n log n):
+///   los saltos grandes eliminan las "tortugas" que hacen lento a Bubble
+///   Sort
+/// - Sin memoria auxiliar ni `Clone`: solo intercambios
+/// - No es estable: los saltos pasan elementos por encima de otros iguales
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::comb_sort::comb_sort;
+///
+/// let mut numbers = vec![8, 4, 1, 56, 3, -44, 23, -6, 28, 0];
+/// comb_sort(&mut numbers);
+/// assert_eq!(numbers, vec![-44, -6, 0, 1, 3, 4, 8, 23, 28, 56]);
+/// ```
+pub fn comb_sort<T: Ord>(arr: &mut [T]) {
+    comb_sort_by(arr, T::cmp);
+}
+
+/// Igual que [`comb_sort`], pero con una función de comparación propia.
+pub fn comb_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    comb_sort_probed(arr, compare, &NoProbe);
+}
+
+/// Igual que [`comb_sort_by`], avisando cada operación a `probe`.
+pub fn comb_sort_probed<T, F, P>(arr: &mut [T], mut compare: F, probe: &P)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    let mut gap = arr.len();
+    let mut swapped = true;
+    while gap > 1 || swapped {
+        gap = match gap * 10 / 13 {
+            9 | 10 => 11,
+            0 => 1,
+            gap => gap,
+        };
+
+        swapped = false;
+        for i in gap..arr.len() {
+            probe.compare(&arr[i], &arr[i - gap]);
+            if compare(&arr[i], &arr[i - gap]) == Ordering::Less {
+                swap(arr, i - gap, i, probe);
+                swapped = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::instrument::Distribution;
+
+    #[test]
+    fn test_comb_sort() {
+        for distribution in Distribution::ALL {
+            for len in [0, 1, 2, 3, 10, 11, 12, 100, 1_000] {
+                let mut values = distribution.generate(len, 13);
+                let mut expected = values.clone();
+                expected.sort();
+                comb_sort(&mut values);
+                assert_eq!(values, expected, "{distribution} con {len} elementos");
+            }
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use super::probe::{to_vec, write, NoProbe, Probe};
+use super::radix_sort::{radix_sort, RadixKey};
+use std::mem;
+
+// Rangos de hasta `RANGE_FACTOR` veces el largo del slice (o `MIN_RANGE`
+// en slices cortos) se ordenan con contadores; los más grandes, con Radix Sort
+const RANGE_FACTOR: u64 = 8;
+const MIN_RANGE: u64 = 256;
+
+/// # Counting Sort
+///
+/// Ordenamiento sin comparaciones para enteros en un rango chico: en lugar
+/// de comparar, cuenta cuántas veces aparece cada valor.
+///
+/// ## Pasos
+///
+/// 1. **Rango**: Se buscan el mínimo y el máximo, y se reserva un contador
+///    por cada valor entre ambos.
+/// 2. **Conteo**: Se cuenta cuántos elementos tiene cada valor.
+/// 3. **Posiciones**: La suma acumulada de los contadores dice dónde empieza
+///    cada valor en el resultado.
+/// 4. **Distribución**: Cada elemento se copia a la posición que le toca, en
+///    el orden en que aparece.
+///
+/// ## Características
+///
+/// - Tiempo O(n + k) y memoria extra O(n + k), con `k` el tamaño del rango:
+///   solo conviene cuando `k` no es mucho mayor que `n`. Si el rango pasa de
+///   8 veces el largo del slice (y de 256), [`counting_sort`] usa
+///   [`radix_sort`] en su lugar, así que una clave grande como un `u64`
+///   no reserva un contador por cada valor posible
+/// - Estable
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::counting_sort::counting_sort;
+///
+/// let mut grades = vec![7, -2, 10, 4, 7, 0, 10, 4];
+/// counting_sort(&mut grades);
+/// assert_eq!(grades, vec![-2, 0, 4, 4, 7, 7, 10, 10]);
+/// ```
+pub fn counting_sort<T: RadixKey>(arr: &mut [T]) {
+    let (Some(min), Some(max)) = (
+        arr.iter().map(|x| x.radix_key()).min(),
+        arr.iter().map(|x| x.radix_key()).max(),
+    ) else {
+        return;
+    };
+    let limit = (arr.len() as u64)
+        .saturating_mul(RANGE_FACTOR)
+        .max(MIN_RANGE);
+    if max - min >= limit {
+        radix_sort(arr);
+        return;
+    }
+    counting_sort_by_key(arr, |x| (x.radix_key() - min) as usize);
+}
+
+/// Igual que [`counting_sort`], ordenando por la clave que devuelve `key`.
+/// El rango es `0..=max`, con `max` la mayor de las claves, y se reserva
+/// entero: acá no hay vuelta atrás a Radix Sort.
+pub fn counting_sort_by_key<T, F>(arr: &mut [T], key: F)
+where
+    T: Clone,
+    F: FnMut(&T) -> usize,
+{
+    counting_sort_probed(arr, key, &NoProbe);
+}
+
+/// Igual que [`counting_sort_by_key`], avisando cada operación a `probe`.
+pub fn counting_sort_probed<T, F, P>(arr: &mut [T], mut key: F, probe: &P)
+where
+    T: Clone,
+    F: FnMut(&T) -> usize,
+    P: Probe<T> + ?Sized,
+{
+    let Some(max) = arr.iter().map(&mut key).max() else {
+        return;
+    };
+    let range = max.checked_add(1).expect("rango demasiado grande");
+
+    probe.alloc(range * mem::size_of::<usize>());
+    let mut counts = vec![0usize; range];
+    for item in arr.iter() {
+        counts[key(item)] += 1;
+    }
+
+    // Cada contador pasa a ser la posición donde empieza su valor
+    let mut sum = 0;
+    for count in counts.iter_mut() {
+        let start = sum;
+        sum += *count;
+        *count = start;
+    }
+
+    let sorted = to_vec(arr, probe);
+    for item in sorted {
+        let position = &mut counts[key(&item)];
+        write(arr, *position, item, probe);
+        *position += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::random_values;
+
+    #[test]
+    fn test_counting_sort() {
+        let values: Vec<i32> = random_values(2_000, 300, 3)
+            .into_iter()
+            .map(|x| x as i32 - 150)
+            .collect();
+        for len in [0, 1, 2, 50, 2_000] {
+            let mut sorted = values[..len].to_vec();
+            let mut expected = sorted.clone();
+            expected.sort();
+            counting_sort(&mut sorted);
+            assert_eq!(sorted, expected);
+        }
+
+        let mut bytes = vec![u8::MAX, 0, 7, u8::MAX, 1];
+        counting_sort(&mut bytes);
+        assert_eq!(bytes, vec![0, 1, 7, u8::MAX, u8::MAX]);
+    }
+
+    #[test]
+    fn test_rango_grande() {
+        // Con un contador por valor harían falta 2^64 contadores
+        let mut wide = vec![u64::MAX, 0, 1 << 40, 7, u64::MAX - 1];
+        counting_sort(&mut wide);
+        assert_eq!(wide, vec![0, 7, 1 << 40, u64::MAX - 1, u64::MAX]);
+
+        let mut signed = vec![i64::MAX, -3, i64::MIN, 0];
+        counting_sort(&mut signed);
+        assert_eq!(signed, vec![i64::MIN, -3, 0, i64::MAX]);
+
+        let mut floats = vec![2.5f64, -1.0, f64::MAX, 0.0];
+        counting_sort(&mut floats);
+        assert_eq!(floats, vec![-1.0, 0.0, 2.5, f64::MAX]);
+    }
+
+    #[test]
+    fn test_estabilidad() {
+        let mut people = vec![
+            ("ana", 30),
+            ("luis", 25),
+            ("eva", 30),
+            ("juan", 25),
+            ("sol", 4),
+        ];
+        counting_sort_by_key(&mut people, |&(_, age)| age);
+        assert_eq!(
+            people,
+            vec![
+                ("sol", 4),
+                ("luis", 25),
+                ("juan", 25),
+                ("ana", 30),
+                ("eva", 30)
+            ]
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use super::probe::{replace, NoProbe, Probe};
+use std::cmp::Ordering;
+
+/// # Cycle Sort
+///
+/// Ordenamiento que escribe cada elemento directamente en su posición final
+/// (Haddon, 1990): hace el mínimo de escrituras posible.
+///
+/// ## Pasos
+///
+/// 1. **Posición**: Se toma el elemento de la posición `start` y se cuenta
+///    cuántos de los que están después son menores: eso dice su posición
+///    final. Si ya está ahí, se sigue con la siguiente.
+/// 2. **Ciclo**: El elemento se escribe en su posición, después de los
+///    iguales que ya estén ubicados, y el que estaba ahí pasa a la mano. Se
+///    repite con ese hasta que a alguno le corresponda `start`, lo que
+///    cierra el ciclo de la permutación.
+///
+/// ## Características
+///
+/// - Tiempo O(n²) siempre, por el conteo de cada posición
+/// - Cada elemento fuera de lugar se escribe una sola vez, y los que ya
+///   están en su lugar no se tocan: útil cuando escribir es caro, como en
+///   memorias flash que se gastan con cada escritura
+/// - Memoria extra O(1): solo el elemento en la mano, que se obtiene con
+///   `Clone` al empezar cada ciclo
+/// - No es estable
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::cycle_sort::cycle_sort;
+///
+/// let mut numbers = vec![3, 1, 2, 4, 1];
+/// cycle_sort(&mut numbers);
+/// assert_eq!(numbers, vec![1, 1, 2, 3, 4]);
+/// ```
+pub fn cycle_sort<T: Ord + Clone>(arr: &mut [T]) {
+    cycle_sort_by(arr, T::cmp);
+}
+
+/// Igual que [`cycle_sort`], pero con una función de comparación propia.
+pub fn cycle_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    cycle_sort_probed(arr, compare, &NoProbe);
+}
+
+/// Igual que [`cycle_sort_by`], avisando cada operación a `probe`.
+pub fn cycle_sort_probed<T, F, P>(arr: &mut [T], mut compare: F, probe: &P)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    let mut compare = |a: &T, b: &T| {
+        probe.compare(a, b);
+        compare(a, b)
+    };
+
+    for start in 0..arr.len().saturating_sub(1) {
+        let mut item = arr[start].clone();
+        let mut pos = position(arr, start, &item, &mut compare);
+        if pos == start {
+            continue;
+        }
+
+        // Hasta cerrar el ciclo, `arr[start]` guarda una copia vieja; el
+        // elemento al que le toca `start` nunca es igual a ella, así que no se
+        // la saltea como repetida
+        loop {
+            while compare(&item, &arr[pos]) == Ordering::Equal {
+                pos += 1;
+            }
+            item = replace(arr, pos, item, probe);
+            if pos == start {
+                break;
+            }
+            pos = position(arr, start, &item, &mut compare);
+        }
+    }
+}
+
+// Posición final de `item`: `start` más la cantidad de menores después de
+// `start`
+fn position<T, F>(arr: &[T], start: usize, item: &T, compare: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let smaller = arr[start + 1..]
+        .iter()
+        .filter(|x| compare(x, item) == Ordering::Less)
+        .count();
+    start + smaller
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::instrument::{Counters, Distribution};
+
+    #[test]
+    fn test_cycle_sort() {
+        for distribution in Distribution::ALL {
+            for len in [0, 1, 2, 3, 10, 300] {
+                let mut values = distribution.generate(len, 19);
+                let mut expected = values.clone();
+                expected.sort();
+                cycle_sort(&mut values);
+                assert_eq!(values, expected, "{distribution} con {len} elementos");
+            }
+        }
+    }
+
+    #[test]
+    fn test_escrituras_minimas() {
+        for distribution in Distribution::ALL {
+            let mut values = distribution.generate(500, 23);
+            let mut expected = values.clone();
+            expected.sort();
+            let misplaced = values.iter().zip(&expected).filter(|(a, b)| a != b).count();
+
+            let counters = Counters::new();
+            cycle_sort_probed(&mut values, u32::cmp, &counters);
+            assert_eq!(values, expected);
+            assert_eq!(counters.counts().writes, misplaced, "{distribution}");
+            assert_eq!(counters.counts().swaps, 0);
+        }
+    }
+}
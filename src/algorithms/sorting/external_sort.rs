@@ -0,0 +1,484 @@
+use super::merge_sort::merge_sort_by;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{self, AtomicUsize};
+
+/// Formato de los registros del archivo a ordenar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordFormat {
+    /// Un registro por línea, terminado en `\n`. El salto de línea no forma
+    /// parte de la comparación, y la salida termina cada línea con `\n`
+    /// aunque la última de la entrada no lo tuviera.
+    Lines,
+    /// Registros binarios de exactamente esta cantidad de bytes, que no
+    /// puede ser cero.
+    Fixed(usize),
+}
+
+/// Opciones de [`external_sort`].
+#[derive(Debug, Clone)]
+pub struct ExternalOptions {
+    /// Memoria aproximada, en bytes, que se permite ocupar con registros
+    /// mientras se arma cada tramo
+    pub memory_budget: usize,
+    /// Formato de los registros
+    pub format: RecordFormat,
+    /// Directorio donde se guardan los tramos ordenados intermedios
+    pub temp_dir: PathBuf,
+    /// Máximo de tramos que se fusionan a la vez, y por lo tanto de archivos
+    /// abiertos. Con más tramos la fusión se hace en varias pasadas. Los
+    /// valores menores que 2 cuentan como 2.
+    pub max_fan_in: usize,
+}
+
+impl Default for ExternalOptions {
+    /// 64 MiB de memoria, registros por línea, el directorio temporal del
+    /// sistema y hasta 64 tramos por fusión.
+    fn default() -> Self {
+        ExternalOptions {
+            memory_budget: 64 * 1024 * 1024,
+            format: RecordFormat::Lines,
+            temp_dir: std::env::temp_dir(),
+            max_fan_in: 64,
+        }
+    }
+}
+
+/// # External Merge Sort
+///
+/// Ordena un archivo que no entra en memoria, comparando los registros byte
+/// a byte.
+///
+/// ## Pasos
+///
+/// 1. **Tramos**: Se leen registros hasta llenar `memory_budget`, se ordenan
+///    referencias a ellos con [`merge_sort_by`] y se escriben en ese orden en
+///    un archivo temporal. Se repite hasta terminar la entrada.
+/// 2. **Pasadas intermedias**: Mientras haya más de `max_fan_in` tramos, se
+///    fusionan de a grupos consecutivos de `max_fan_in` en tramos nuevos.
+/// 3. **Fusión de k vías**: Se abre un lector por tramo y se pone el primer
+///    registro de cada uno en un montículo. Se extrae el menor, se escribe en
+///    la salida y se reemplaza por el siguiente registro de su tramo.
+///
+/// ## Características
+///
+/// - Lee y escribe cada registro dos veces si los tramos no pasan de
+///   `max_fan_in`, y una vez más por cada pasada intermedia
+/// - El merge sort copia solo las referencias, nunca los bytes: su buffer
+///   auxiliar se cuenta dentro del presupuesto, a dos referencias por registro
+/// - Es estable: dentro de un tramo lo garantiza el merge sort, y entre
+///   tramos se desempata por el número de tramo, que sigue el orden de la
+///   entrada
+/// - Los archivos temporales se borran al terminar, también si hay un error
+/// - Retorna la cantidad de tramos que se generaron
+///
+/// # Errores
+///
+/// Además de los errores de lectura y escritura, retorna uno de tipo
+/// [`ErrorKind::InvalidInput`] si el formato es `RecordFormat::Fixed(0)`.
+pub fn external_sort(
+    input: impl AsRef<Path>,
+    output: impl AsRef<Path>,
+    options: &ExternalOptions,
+) -> io::Result<usize> {
+    external_sort_by(input, output, options, |a, b| a.cmp(b))
+}
+
+/// Igual que [`external_sort`], pero con una función de comparación propia
+/// sobre los bytes de cada registro (sin el salto de línea). Sirve, por
+/// ejemplo, para ordenar un log por la fecha que aparece en cada línea.
+pub fn external_sort_by<F>(
+    input: impl AsRef<Path>,
+    output: impl AsRef<Path>,
+    options: &ExternalOptions,
+    compare: F,
+) -> io::Result<usize>
+where
+    F: Fn(&[u8], &[u8]) -> Ordering,
+{
+    // Con registros de cero bytes la lectura nunca llegaría al final
+    if options.format == RecordFormat::Fixed(0) {
+        return Err(io::Error::new(
+            ErrorKind::InvalidInput,
+            "los registros fijos no pueden tener cero bytes",
+        ));
+    }
+    let mut reader = BufReader::new(File::open(input)?);
+    let mut runs = TempRuns::default();
+
+    // Fase 1: tramos ordenados
+    let mut chunk: Vec<Vec<u8>> = Vec::new();
+    let mut used = 0;
+    while let Some(record) = read_record(&mut reader, options.format)? {
+        // Además de los bytes, cada registro cuesta la cabecera de su Vec y
+        // las dos referencias que usa el merge sort (el slice y su buffer)
+        used += record.len() + std::mem::size_of::<Vec<u8>>() + 2 * std::mem::size_of::<&[u8]>();
+        chunk.push(record);
+        if used >= options.memory_budget {
+            write_run(&mut chunk, &mut runs, options, &compare)?;
+            used = 0;
+        }
+    }
+    if !chunk.is_empty() || runs.paths.is_empty() {
+        write_run(&mut chunk, &mut runs, options, &compare)?;
+    }
+
+    let run_count = runs.paths.len();
+
+    // Fase 2: pasadas intermedias. Los grupos son de tramos consecutivos, así
+    // que el número de tramo sigue desempatando en el orden de la entrada
+    let fan_in = options.max_fan_in.max(2);
+    let mut pending = runs.paths.clone();
+    while pending.len() > fan_in {
+        let mut merged = Vec::new();
+        for group in pending.chunks(fan_in) {
+            let path = runs.create(&options.temp_dir);
+            merge_runs(group, &path, options, &compare)?;
+            for run in group {
+                fs::remove_file(run)?;
+            }
+            merged.push(path);
+        }
+        pending = merged;
+    }
+
+    // Fase 3: fusión de k vías hacia la salida
+    merge_runs(&pending, output.as_ref(), options, &compare)?;
+    Ok(run_count)
+}
+
+// Fusiona los tramos de `paths` en el archivo `output`. El buffer de cada
+// lector sale del mismo presupuesto de memoria
+fn merge_runs<F>(
+    paths: &[PathBuf],
+    output: &Path,
+    options: &ExternalOptions,
+    compare: &F,
+) -> io::Result<()>
+where
+    F: Fn(&[u8], &[u8]) -> Ordering,
+{
+    let buffer_size = (options.memory_budget / (paths.len() + 1)).max(4096);
+    let mut readers = paths
+        .iter()
+        .map(|path| Ok(BufReader::with_capacity(buffer_size, File::open(path)?)))
+        .collect::<io::Result<Vec<_>>>()?;
+    let mut writer = BufWriter::new(File::create(output)?);
+
+    let mut heap = BinaryHeap::with_capacity(readers.len());
+    for (run, reader) in readers.iter_mut().enumerate() {
+        if let Some(record) = read_record(reader, options.format)? {
+            heap.push(HeapEntry {
+                record,
+                run,
+                compare,
+            });
+        }
+    }
+    while let Some(entry) = heap.pop() {
+        write_record(&mut writer, &entry.record, options.format)?;
+        if let Some(record) = read_record(&mut readers[entry.run], options.format)? {
+            heap.push(HeapEntry { record, ..entry });
+        }
+    }
+    writer.flush()
+}
+
+// Ordena el trozo en memoria y lo guarda como un tramo nuevo, dejándolo vacío
+fn write_run<F>(
+    chunk: &mut Vec<Vec<u8>>,
+    runs: &mut TempRuns,
+    options: &ExternalOptions,
+    compare: &F,
+) -> io::Result<()>
+where
+    F: Fn(&[u8], &[u8]) -> Ordering,
+{
+    // Se ordenan referencias: clonarlas no copia los registros
+    let mut sorted: Vec<&[u8]> = chunk.iter().map(Vec::as_slice).collect();
+    merge_sort_by(&mut sorted, |a, b| compare(a, b));
+
+    let path = runs.create(&options.temp_dir);
+    let mut writer = BufWriter::new(File::create(&path)?);
+    for record in sorted {
+        write_record(&mut writer, record, options.format)?;
+    }
+    writer.flush()?;
+    chunk.clear();
+    Ok(())
+}
+
+// Lee el próximo registro, o None al final del archivo
+fn read_record(reader: &mut impl BufRead, format: RecordFormat) -> io::Result<Option<Vec<u8>>> {
+    match format {
+        RecordFormat::Lines => {
+            let mut record = Vec::new();
+            if reader.read_until(b'\n', &mut record)? == 0 {
+                return Ok(None);
+            }
+            if record.last() == Some(&b'\n') {
+                record.pop();
+            }
+            Ok(Some(record))
+        }
+        RecordFormat::Fixed(size) => {
+            let mut record = vec![0; size];
+            let mut filled = 0;
+            while filled < size {
+                match reader.read(&mut record[filled..])? {
+                    0 if filled == 0 => return Ok(None),
+                    0 => {
+                        return Err(io::Error::new(
+                            ErrorKind::InvalidData,
+                            format!("registro incompleto: {filled} de {size} bytes"),
+                        ))
+                    }
+                    read => filled += read,
+                }
+            }
+            Ok(Some(record))
+        }
+    }
+}
+
+fn write_record(writer: &mut impl Write, record: &[u8], format: RecordFormat) -> io::Result<()> {
+    writer.write_all(record)?;
+    if format == RecordFormat::Lines {
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+// Archivos de los tramos; se borran al salir de alcance
+#[derive(Default)]
+struct TempRuns {
+    paths: Vec<PathBuf>,
+}
+
+impl TempRuns {
+    fn create(&mut self, dir: &Path) -> PathBuf {
+        // Contador global para que dos ordenamientos simultáneos no choquen
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let id = NEXT.fetch_add(1, atomic::Ordering::Relaxed);
+        let path = dir.join(format!("external-sort-{}-{id}.run", std::process::id()));
+        self.paths.push(path.clone());
+        path
+    }
+}
+
+impl Drop for TempRuns {
+    fn drop(&mut self) {
+        for path in &self.paths {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+// Entrada del montículo de la fusión. `BinaryHeap` es un montículo de
+// máximos, así que el orden se invierte; a igual registro sale primero el
+// del tramo anterior, para que la fusión sea estable
+struct HeapEntry<'a, F> {
+    record: Vec<u8>,
+    run: usize,
+    compare: &'a F,
+}
+
+impl<F: Fn(&[u8], &[u8]) -> Ordering> Ord for HeapEntry<'_, F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.compare)(&other.record, &self.record).then_with(|| other.run.cmp(&self.run))
+    }
+}
+
+impl<F: Fn(&[u8], &[u8]) -> Ordering> PartialOrd for HeapEntry<'_, F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F: Fn(&[u8], &[u8]) -> Ordering> PartialEq for HeapEntry<'_, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<F: Fn(&[u8], &[u8]) -> Ordering> Eq for HeapEntry<'_, F> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::random_values;
+
+    // Directorio temporal propio de cada test, borrado al terminar
+    struct TestDir(PathBuf);
+
+    impl TestDir {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir()
+                .join(format!("external-sort-test-{}-{name}", std::process::id()));
+            fs::create_dir_all(&path).unwrap();
+            TestDir(path)
+        }
+
+        fn options(&self, memory_budget: usize, format: RecordFormat) -> ExternalOptions {
+            ExternalOptions {
+                memory_budget,
+                format,
+                temp_dir: self.0.join("runs"),
+                ..ExternalOptions::default()
+            }
+        }
+    }
+
+    impl Drop for TestDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_lineas() {
+        let dir = TestDir::new("lineas");
+        let options = dir.options(2048, RecordFormat::Lines);
+        fs::create_dir_all(&options.temp_dir).unwrap();
+
+        let mut lines: Vec<String> = random_values(5_000, 1000, 1)
+            .into_iter()
+            .map(|value| format!("registro {value}"))
+            .collect();
+        // La última línea sin salto de línea también cuenta
+        fs::write(dir.0.join("input"), lines.join("\n")).unwrap();
+
+        let runs = external_sort(dir.0.join("input"), dir.0.join("output"), &options).unwrap();
+        assert!(runs > 10, "se esperaban varios tramos, hubo {runs}");
+
+        lines.sort();
+        let output = fs::read_to_string(dir.0.join("output")).unwrap();
+        assert_eq!(output, lines.join("\n") + "\n");
+        // Los tramos temporales se borraron
+        assert_eq!(fs::read_dir(&options.temp_dir).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_registros_fijos() {
+        let dir = TestDir::new("fijos");
+        let options = dir.options(4096, RecordFormat::Fixed(8));
+        fs::create_dir_all(&options.temp_dir).unwrap();
+
+        // Enteros big-endian: el orden de los bytes es el orden numérico
+        let mut values: Vec<u64> = random_values(3_000, u32::MAX, 2)
+            .into_iter()
+            .map(u64::from)
+            .collect();
+        let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_be_bytes()).collect();
+        fs::write(dir.0.join("input"), bytes).unwrap();
+
+        external_sort(dir.0.join("input"), dir.0.join("output"), &options).unwrap();
+
+        values.sort();
+        let output: Vec<u64> = fs::read(dir.0.join("output"))
+            .unwrap()
+            .chunks(8)
+            .map(|chunk| u64::from_be_bytes(chunk.try_into().unwrap()))
+            .collect();
+        assert_eq!(output, values);
+
+        // Un registro incompleto al final es un error, y no deja temporales
+        fs::write(dir.0.join("broken"), [0u8; 13]).unwrap();
+        let error = external_sort(dir.0.join("broken"), dir.0.join("output"), &options);
+        assert_eq!(error.unwrap_err().kind(), ErrorKind::InvalidData);
+        assert_eq!(fs::read_dir(&options.temp_dir).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_estabilidad_con_clave() {
+        let dir = TestDir::new("estable");
+        let options = dir.options(1024, RecordFormat::Lines);
+        fs::create_dir_all(&options.temp_dir).unwrap();
+
+        // "clave,posición": se ordena solo por la clave, y las posiciones de
+        // cada clave deben seguir en orden creciente aunque estén en otros tramos
+        let lines: Vec<String> = random_values(2_000, 5, 3)
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| format!("{value},{i:05}"))
+            .collect();
+        fs::write(dir.0.join("input"), lines.join("\n")).unwrap();
+
+        let key = |record: &[u8]| record.split(|&b| b == b',').next().unwrap().to_vec();
+        external_sort_by(
+            dir.0.join("input"),
+            dir.0.join("output"),
+            &options,
+            |a, b| key(a).cmp(&key(b)),
+        )
+        .unwrap();
+
+        let mut expected = lines.clone();
+        expected.sort();
+        let output = fs::read_to_string(dir.0.join("output")).unwrap();
+        assert_eq!(output.lines().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_fusion_en_varias_pasadas() {
+        let dir = TestDir::new("pasadas");
+        let options = ExternalOptions {
+            max_fan_in: 3,
+            ..dir.options(1024, RecordFormat::Lines)
+        };
+        fs::create_dir_all(&options.temp_dir).unwrap();
+
+        // Con unos 40 tramos y de a 3 por fusión hacen falta varias pasadas;
+        // los empates tienen que seguir saliendo en el orden de la entrada
+        let lines: Vec<String> = random_values(3_000, 7, 4)
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| format!("{value},{i:05}"))
+            .collect();
+        fs::write(dir.0.join("input"), lines.join("\n")).unwrap();
+
+        let key = |record: &[u8]| record.split(|&b| b == b',').next().unwrap().to_vec();
+        let runs = external_sort_by(
+            dir.0.join("input"),
+            dir.0.join("output"),
+            &options,
+            |a, b| key(a).cmp(&key(b)),
+        )
+        .unwrap();
+        assert!(
+            runs > 9,
+            "se esperaban más de dos pasadas, hubo {runs} tramos"
+        );
+
+        let mut expected = lines.clone();
+        expected.sort();
+        let output = fs::read_to_string(dir.0.join("output")).unwrap();
+        assert_eq!(output.lines().collect::<Vec<_>>(), expected);
+        assert_eq!(fs::read_dir(&options.temp_dir).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_archivo_vacio() {
+        let dir = TestDir::new("vacio");
+        let options = dir.options(1024, RecordFormat::Lines);
+        fs::create_dir_all(&options.temp_dir).unwrap();
+        fs::write(dir.0.join("input"), "").unwrap();
+
+        external_sort(dir.0.join("input"), dir.0.join("output"), &options).unwrap();
+        assert_eq!(fs::read(dir.0.join("output")).unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_registros_de_cero_bytes() {
+        let dir = TestDir::new("cero");
+        let options = dir.options(1024, RecordFormat::Fixed(0));
+        fs::create_dir_all(&options.temp_dir).unwrap();
+        fs::write(dir.0.join("input"), "abc").unwrap();
+
+        let error = external_sort(dir.0.join("input"), dir.0.join("output"), &options).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+    }
+}
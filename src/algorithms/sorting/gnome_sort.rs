@@ -0,0 +1,109 @@
+use super::probe::{swap, NoProbe, Probe};
+use std::cmp::Ordering;
+
+/// # Gnome Sort
+///
+/// El ordenamiento del gnomo de jardín (Sarbazi-Azad, 2000): mira la maceta
+/// que tiene adelante y la de atrás; si están en orden avanza un paso, y si
+/// no, las intercambia y retrocede uno.
+///
+/// ## Pasos
+///
+/// 1. **Avance**: Si el elemento actual no es menor que el anterior (o es el
+///    primero), se avanza a la posición siguiente.
+/// 2. **Retroceso**: Si es menor, se intercambia con el anterior y se
+///    retrocede una posición, para seguir comparándolo.
+/// 3. **Fin**: Termina al pasar el último elemento.
+///
+/// ## Características
+///
+/// - Tiempo O(n²) en el peor caso y O(n) con la entrada ordenada
+/// - Hace los mismos intercambios que
+///   [`insertion_sort`](super::insertion_sort::insertion_sort), pero al
+///   terminar de ubicar un elemento vuelve caminando: es el ordenamiento
+///   con un solo ciclo y sin ciclos anidados
+/// - Sin memoria auxiliar ni `Clone`: solo intercambios
+/// - Es estable
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::gnome_sort::gnome_sort;
+///
+/// let mut pots = vec![5, 3, 2, 4];
+/// gnome_sort(&mut pots);
+/// assert_eq!(pots, vec![2, 3, 4, 5]);
+/// ```
+pub fn gnome_sort<T: Ord>(arr: &mut [T]) {
+    gnome_sort_by(arr, T::cmp);
+}
+
+/// Igual que [`gnome_sort`], pero con una función de comparación propia.
+pub fn gnome_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    gnome_sort_probed(arr, compare, &NoProbe);
+}
+
+/// Igual que [`gnome_sort_by`], avisando cada operación a `probe`.
+pub fn gnome_sort_probed<T, F, P>(arr: &mut [T], mut compare: F, probe: &P)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    let mut i = 1;
+    while i < arr.len() {
+        if i == 0 {
+            i = 1;
+            continue;
+        }
+        probe.compare(&arr[i], &arr[i - 1]);
+        if compare(&arr[i], &arr[i - 1]) == Ordering::Less {
+            swap(arr, i - 1, i, probe);
+            i -= 1;
+        } else {
+            i += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::insertion_sort::insertion_sort_probed;
+    use crate::algorithms::sorting::instrument::{Counters, Distribution};
+
+    #[test]
+    fn test_gnome_sort() {
+        for distribution in Distribution::ALL {
+            for len in [0, 1, 2, 3, 10, 200] {
+                // Pares (clave, posición original) para comprobar la estabilidad
+                let mut values: Vec<(u32, usize)> = distribution
+                    .generate(len, 17)
+                    .into_iter()
+                    .map(|x| x % 10)
+                    .zip(0..)
+                    .collect();
+                let mut expected = values.clone();
+                expected.sort_by_key(|&(key, _)| key);
+                gnome_sort_by(&mut values, |a, b| a.0.cmp(&b.0));
+                assert_eq!(values, expected, "{distribution} con {len} elementos");
+            }
+        }
+    }
+
+    #[test]
+    fn test_mismos_intercambios_que_insercion() {
+        let swaps = |sort: fn(&mut [u32], &Counters)| {
+            let mut values = Distribution::Random.generate(300, 2);
+            let counters = Counters::new();
+            sort(&mut values, &counters);
+            counters.counts().swaps
+        };
+        assert_eq!(
+            swaps(|arr, probe| gnome_sort_probed(arr, u32::cmp, probe)),
+            swaps(|arr, probe| insertion_sort_probed(arr, u32::cmp, probe))
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use super::probe::{swap, NoProbe, Probe};
+use crate::structures::binary_heap::heap::{build_heap, sort_heap};
+use std::cmp::Ordering;
+
+/// # Heap Sort
+///
+/// Ordenamiento in-place sobre las operaciones del
+/// [`BinaryHeap`](crate::structures::binary_heap::heap::BinaryHeap), usando
+/// el propio slice como montículo de máximos.
+///
+/// ## Pasos
+///
+/// 1. **Construcción**: Se baja cada nodo interno, desde el último hasta la
+///    raíz, hasta que cada padre es mayor o igual a sus hijos. Cuesta O(n).
+/// 2. **Extracción**: La cima (el máximo) se intercambia con el último
+///    elemento del montículo, que se achica en uno, y el nuevo primer
+///    elemento baja hasta su lugar. Se repite hasta vaciar el montículo,
+///    dejando el slice ordenado de menor a mayor.
+///
+/// ## Características
+///
+/// - Tiempo O(n log n) en cualquier caso
+/// - Sin memoria auxiliar ni `Clone`: solo intercambios
+/// - No es estable
+/// - Es la red de seguridad de [`intro_sort`](super::intro_sort::intro_sort)
+///   y [`pdq_sort`](super::pdq_sort::pdq_sort) cuando Quick Sort degenera
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::heap_sort::heap_sort;
+///
+/// let mut numbers = vec![6, 2, 3, 9, 1, 0, 5, 8, 7, 1, 0, 4, 3, 1];
+/// heap_sort(&mut numbers);
+/// assert_eq!(numbers, vec![0, 0, 1, 1, 1, 2, 3, 3, 4, 5, 6, 7, 8, 9]);
+/// ```
+pub fn heap_sort<T: Ord>(arr: &mut [T]) {
+    heap_sort_by(arr, T::cmp);
+}
+
+/// Igual que [`heap_sort`], pero con una función de comparación propia.
+pub fn heap_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    heap_sort_probed(arr, compare, &NoProbe);
+}
+
+/// Igual que [`heap_sort_by`], avisando cada operación a `probe`.
+pub fn heap_sort_probed<T, F, P>(arr: &mut [T], mut compare: F, probe: &P)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    let mut is_less = |a: &T, b: &T| {
+        probe.compare(a, b);
+        compare(a, b) == Ordering::Less
+    };
+    heap_sort_by_less(arr, &mut is_less, probe);
+}
+
+/// Heap Sort según `is_less`, para los ordenamientos que ya trabajan con
+/// esa forma de comparar.
+pub(super) fn heap_sort_by_less<T, F, P>(arr: &mut [T], is_less: &mut F, probe: &P)
+where
+    F: FnMut(&T, &T) -> bool,
+    P: Probe<T> + ?Sized,
+{
+    let mut swap = |arr: &mut [T], i, j| swap(arr, i, j, probe);
+    build_heap(arr, is_less, &mut swap);
+    sort_heap(arr, is_less, &mut swap);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::random_values;
+
+    #[test]
+    fn test_contra_sort_unstable() {
+        for len in (0..70).chain([1_000, 20_000]) {
+            let inputs = [
+                random_values(len, u32::MAX, len as u64),
+                random_values(len, 3, len as u64),
+                (0..len as u32).collect(),
+                (0..len as u32).rev().collect(),
+            ];
+            for mut values in inputs {
+                let mut expected = values.clone();
+                expected.sort_unstable();
+                heap_sort(&mut values);
+                assert_eq!(values, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_heap_sort_by() {
+        let mut words = vec!["pera", "uva", "manzana", "kiwi", "banana"];
+        heap_sort_by(&mut words, |a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+        assert_eq!(words, ["manzana", "banana", "kiwi", "pera", "uva"]);
+    }
+}
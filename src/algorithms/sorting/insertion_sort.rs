@@ -0,0 +1,217 @@
+use super::probe::{swap, NoProbe, Probe};
+use std::cmp::Ordering;
+
+/// # Insertion Sort
+///
+/// Ordena como se ordenan las cartas en la mano: cada elemento nuevo se
+/// desplaza hacia la izquierda hasta quedar en su lugar entre los anteriores.
+///
+/// ## Pasos
+///
+/// 1. **Prefijo ordenado**: Al empezar, el primer elemento solo es un
+///    prefijo ordenado.
+/// 2. **Inserción**: El elemento siguiente se intercambia con su vecino de
+///    la izquierda mientras sea estrictamente menor, y el prefijo crece en
+///    uno.
+///
+/// ## Características
+///
+/// - Tiempo O(n²) en el peor caso y O(n + k) en general, con `k` la cantidad
+///   de pares fuera de orden: con la entrada ordenada hace `n - 1`
+///   comparaciones y ningún intercambio
+/// - Sin memoria auxiliar ni `Clone`: solo intercambios
+/// - Es estable: un elemento nunca pasa por encima de uno igual
+/// - En slices chicos es el más rápido, por eso lo usan
+///   [`intro_sort`](super::intro_sort::intro_sort) y
+///   [`pdq_sort`](super::pdq_sort::pdq_sort) para terminar
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::insertion_sort::insertion_sort;
+///
+/// let mut hand = vec![7, 2, 10, 4, 2];
+/// insertion_sort(&mut hand);
+/// assert_eq!(hand, vec![2, 2, 4, 7, 10]);
+/// ```
+pub fn insertion_sort<T: Ord>(arr: &mut [T]) {
+    insertion_sort_by(arr, T::cmp);
+}
+
+/// Igual que [`insertion_sort`], pero con una función de comparación propia.
+pub fn insertion_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    insertion_sort_probed(arr, compare, &NoProbe);
+}
+
+/// Igual que [`insertion_sort_by`], avisando cada operación a `probe`.
+pub fn insertion_sort_probed<T, F, P>(arr: &mut [T], mut compare: F, probe: &P)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    let mut is_less = |a: &T, b: &T| {
+        probe.compare(a, b);
+        compare(a, b) == Ordering::Less
+    };
+    insertion_sort_by_less(arr, &mut is_less, probe);
+}
+
+/// Insertion Sort según `is_less`, para los ordenamientos que ya trabajan
+/// con esa forma de comparar.
+pub(super) fn insertion_sort_by_less<T, F, P>(arr: &mut [T], is_less: &mut F, probe: &P)
+where
+    F: FnMut(&T, &T) -> bool,
+    P: Probe<T> + ?Sized,
+{
+    for i in 1..arr.len() {
+        shift_tail(&mut arr[..=i], is_less, probe);
+    }
+}
+
+/// Mueve el último elemento hacia la izquierda hasta su lugar, suponiendo
+/// que el resto del slice ya está ordenado.
+pub(super) fn shift_tail<T, F, P>(arr: &mut [T], is_less: &mut F, probe: &P)
+where
+    F: FnMut(&T, &T) -> bool,
+    P: Probe<T> + ?Sized,
+{
+    let mut i = arr.len();
+    while i > 1 && is_less(&arr[i - 1], &arr[i - 2]) {
+        swap(arr, i - 1, i - 2, probe);
+        i -= 1;
+    }
+}
+
+/// # Binary Insertion Sort
+///
+/// Variante de [`insertion_sort`] que busca el lugar de cada elemento con
+/// búsqueda binaria en lugar de compararlo con cada vecino.
+///
+/// ## Pasos
+///
+/// 1. **Búsqueda**: Se busca en el prefijo ordenado la primera posición cuyo
+///    elemento es mayor que el nuevo.
+/// 2. **Desplazamiento**: Los elementos desde esa posición se corren un
+///    lugar a la derecha (una rotación) y el nuevo ocupa el hueco.
+///
+/// ## Características
+///
+/// - O(n log n) comparaciones, pero O(n²) movimientos en el peor caso:
+///   conviene cuando comparar es caro y mover es barato
+/// - Sin memoria auxiliar ni `Clone`
+/// - Es estable: cada elemento va después de sus iguales
+/// - Con la entrada ordenada sigue haciendo O(n log n) comparaciones, a
+///   diferencia de [`insertion_sort`]
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::insertion_sort::binary_insertion_sort_by;
+///
+/// let mut words = vec!["pera", "uva", "banana", "kiwi", "higo"];
+/// binary_insertion_sort_by(&mut words, |a, b| a.len().cmp(&b.len()));
+/// assert_eq!(words, vec!["uva", "pera", "kiwi", "higo", "banana"]);
+/// ```
+pub fn binary_insertion_sort<T: Ord>(arr: &mut [T]) {
+    binary_insertion_sort_by(arr, T::cmp);
+}
+
+/// Igual que [`binary_insertion_sort`], pero con una función de comparación
+/// propia.
+pub fn binary_insertion_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    binary_insertion_sort_probed(arr, compare, &NoProbe);
+}
+
+/// Igual que [`binary_insertion_sort_by`], avisando cada operación a `probe`.
+pub fn binary_insertion_sort_probed<T, F, P>(arr: &mut [T], mut compare: F, probe: &P)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    let mut compare = |a: &T, b: &T| {
+        probe.compare(a, b);
+        compare(a, b)
+    };
+    binary_insertion_sort_from(arr, 1, &mut compare, probe);
+}
+
+/// Inserción binaria cuando `arr[..sorted]` ya está ordenado: se agregan
+/// los demás.
+pub(super) fn binary_insertion_sort_from<T, F, P>(
+    arr: &mut [T],
+    sorted: usize,
+    compare: &mut F,
+    probe: &P,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    for i in sorted.max(1)..arr.len() {
+        let (prefix, rest) = arr.split_at(i);
+        let position = prefix.partition_point(|x| compare(&rest[0], x) != Ordering::Less);
+        arr[position..=i].rotate_right(1);
+        for k in position..=i {
+            probe.write(arr, k);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::instrument::Counters;
+    use crate::algorithms::sorting::random_values;
+
+    // (clave, posición original), para comprobar la estabilidad
+    type Pair = (u32, usize);
+
+    #[test]
+    fn test_contra_sort_estable() {
+        fn insertion(arr: &mut [Pair]) {
+            insertion_sort_by(arr, |a, b| a.0.cmp(&b.0));
+        }
+        fn binary_insertion(arr: &mut [Pair]) {
+            binary_insertion_sort_by(arr, |a, b| a.0.cmp(&b.0));
+        }
+        let sorts = [
+            ("insertion", insertion as fn(&mut [Pair])),
+            ("binary_insertion", binary_insertion),
+        ];
+        for (name, sort) in sorts {
+            for len in [0, 1, 2, 3, 10, 100, 500] {
+                let mut values: Vec<Pair> = random_values(len, 20, len as u64)
+                    .into_iter()
+                    .zip(0..)
+                    .collect();
+                let mut expected = values.clone();
+                expected.sort_by_key(|&(key, _)| key);
+                sort(&mut values);
+                assert_eq!(values, expected, "{name} con {len} elementos");
+            }
+        }
+    }
+
+    #[test]
+    fn test_comparaciones() {
+        let len = 1_000;
+        let mut sorted: Vec<u32> = (0..len as u32).collect();
+        let counters = Counters::new();
+        insertion_sort_probed(&mut sorted, u32::cmp, &counters);
+        assert_eq!(counters.counts().comparisons, len - 1);
+        assert_eq!(counters.counts().swaps, 0);
+
+        // En el peor caso la búsqueda binaria ahorra casi todas las
+        // comparaciones
+        let mut reversed: Vec<u32> = (0..len as u32).rev().collect();
+        let counters = Counters::new();
+        binary_insertion_sort_probed(&mut reversed, u32::cmp, &counters);
+        assert!(reversed.windows(2).all(|w| w[0] <= w[1]));
+        assert!(counters.counts().comparisons <= len * 10);
+    }
+}
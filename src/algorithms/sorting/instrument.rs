@@ -0,0 +1,330 @@
+use super::counting_sort::counting_sort_probed;
+use super::probe::Probe;
+use super::property::Rng;
+use super::radix_sort::radix_sort_probed;
+use super::sorter::cloning_sorters;
+use std::cell::Cell;
+use std::fmt;
+
+/// Cantidad de operaciones que hizo un ordenamiento.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Counts {
+    pub comparisons: usize,
+    pub swaps: usize,
+    /// Escrituras sueltas, sin contar las de los intercambios.
+    pub writes: usize,
+    pub allocations: usize,
+    /// Total de bytes reservados, sumando todas las reservas.
+    pub bytes: usize,
+}
+
+/// [`Probe`] que cuenta las operaciones.
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::instrument::Counters;
+/// use algoritmos_y_estructuras::algorithms::sorting::merge_sort::merge_sort_probed;
+///
+/// let mut numbers = vec![5, 1, 4, 2, 3];
+/// let counters = Counters::new();
+/// merge_sort_probed(&mut numbers, |a, b| a.cmp(b), &counters);
+///
+/// let counts = counters.counts();
+/// assert_eq!(numbers, vec![1, 2, 3, 4, 5]);
+/// assert_eq!(counts.allocations, 1);
+/// assert_eq!(counts.bytes, 5 * std::mem::size_of::<i32>());
+/// assert!(counts.comparisons <= 5 * 3);
+/// ```
+#[derive(Debug, Default)]
+pub struct Counters {
+    counts: Cell<Counts>,
+}
+
+impl Counters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lo contado hasta ahora.
+    pub fn counts(&self) -> Counts {
+        self.counts.get()
+    }
+
+    fn update(&self, f: impl FnOnce(&mut Counts)) {
+        let mut counts = self.counts.get();
+        f(&mut counts);
+        self.counts.set(counts);
+    }
+}
+
+impl<T> Probe<T> for Counters {
+    fn compare(&self, _a: &T, _b: &T) {
+        self.update(|c| c.comparisons += 1);
+    }
+
+    fn swap(&self, _slice: &[T], _i: usize, _j: usize) {
+        self.update(|c| c.swaps += 1);
+    }
+
+    fn write(&self, _slice: &[T], _i: usize) {
+        self.update(|c| c.writes += 1);
+    }
+
+    fn alloc(&self, bytes: usize) {
+        self.update(|c| {
+            c.allocations += 1;
+            c.bytes += bytes;
+        });
+    }
+}
+
+/// Distribución de los datos de entrada.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Distribution {
+    /// Valores al azar entre 0 y `len`.
+    Random,
+    /// `0, 1, 2, ...`
+    Sorted,
+    /// `len - 1, ..., 1, 0`
+    Reversed,
+    /// Valores al azar entre 0 y 7: muchos repetidos.
+    FewUnique,
+    /// Sube hasta la mitad y después baja: `0, 1, 2, 2, 1, 0`.
+    OrganPipe,
+}
+
+impl Distribution {
+    pub const ALL: [Distribution; 5] = [
+        Distribution::Random,
+        Distribution::Sorted,
+        Distribution::Reversed,
+        Distribution::FewUnique,
+        Distribution::OrganPipe,
+    ];
+
+    /// Genera `len` valores. La misma `seed` da siempre los mismos valores.
+    pub fn generate(self, len: usize, seed: u64) -> Vec<u32> {
+        let mut rng = Rng::new(seed);
+        let len_u32 = len as u32;
+        match self {
+            Distribution::Random => (0..len).map(|_| rng.below(len) as u32).collect(),
+            Distribution::Sorted => (0..len_u32).collect(),
+            Distribution::Reversed => (0..len_u32).rev().collect(),
+            Distribution::FewUnique => (0..len).map(|_| rng.below(8) as u32).collect(),
+            Distribution::OrganPipe => (0..len_u32).map(|i| i.min(len_u32 - 1 - i)).collect(),
+        }
+    }
+}
+
+impl fmt::Display for Distribution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Distribution::Random => "aleatoria",
+            Distribution::Sorted => "ordenada",
+            Distribution::Reversed => "invertida",
+            Distribution::FewUnique => "pocos distintos",
+            Distribution::OrganPipe => "órgano",
+        };
+        f.pad(name)
+    }
+}
+
+/// Un ordenamiento de `u32` que avisa sus operaciones a un [`Probe`].
+pub type ProbedSort = Box<dyn Fn(&mut [u32], &dyn Probe<u32>)>;
+
+/// Los ordenamientos que entran en el reporte, con su nombre: todos los de
+/// [`cloning_sorters`], más Counting Sort y Radix Sort.
+///
+/// Quedan afuera los que no ordenan `u32` en un solo hilo y en memoria:
+/// los de cadenas, Bucket Sort (`f64`), el paralelo y el externo.
+pub fn algorithms() -> Vec<(&'static str, ProbedSort)> {
+    let mut algorithms: Vec<(&'static str, ProbedSort)> = cloning_sorters()
+        .into_iter()
+        .map(|sorter| {
+            let name = sorter.name();
+            let sort: ProbedSort =
+                Box::new(move |arr, probe| sorter.sort_probed(arr, &mut u32::cmp, probe));
+            (name, sort)
+        })
+        .collect();
+    algorithms.push((
+        "counting",
+        Box::new(|arr, probe| counting_sort_probed(arr, |&x| x as usize, probe)),
+    ));
+    algorithms.push((
+        "radix",
+        Box::new(|arr, probe| radix_sort_probed(arr, |&x| x, probe)),
+    ));
+    algorithms
+}
+
+/// Una fila del reporte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Row {
+    pub distribution: Distribution,
+    pub algorithm: &'static str,
+    pub counts: Counts,
+}
+
+/// Operaciones de cada ordenamiento de [`algorithms`] sobre cada
+/// [`Distribution`], con la misma entrada para todos. Se muestra como una
+/// tabla por distribución.
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::instrument::{report, Distribution};
+///
+/// let report = report(1_000, 42);
+/// let heap = report
+///     .rows
+///     .iter()
+///     .find(|row| row.algorithm == "heap" && row.distribution == Distribution::Sorted)
+///     .unwrap();
+/// assert!(heap.counts.swaps > 0);
+/// println!("{report}");
+/// ```
+///
+/// Para ver la tabla con 10 000 elementos (los ordenamientos cuadráticos no
+/// dan para mucho más):
+/// `cargo test --release bench_operation_counts -- --ignored --nocapture`
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub len: usize,
+    pub rows: Vec<Row>,
+}
+
+/// Arma el [`Report`] con entradas de `len` elementos generadas con `seed`.
+///
+/// # Panics
+///
+/// Si algún ordenamiento no deja la entrada ordenada.
+pub fn report(len: usize, seed: u64) -> Report {
+    let mut rows = Vec::new();
+    for distribution in Distribution::ALL {
+        let input = distribution.generate(len, seed);
+        let mut expected = input.clone();
+        expected.sort_unstable();
+
+        for (algorithm, sort) in algorithms() {
+            let mut values = input.clone();
+            let counters = Counters::new();
+            sort(&mut values, &counters);
+            assert_eq!(values, expected, "{algorithm} con entrada {distribution}");
+            rows.push(Row {
+                distribution,
+                algorithm,
+                counts: counters.counts(),
+            });
+        }
+    }
+    Report { len, rows }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, distribution) in Distribution::ALL.into_iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "Entrada {distribution}, n = {}", self.len)?;
+            writeln!(
+                f,
+                "{:<18} {:>13} {:>12} {:>12} {:>8} {:>12}",
+                "algoritmo", "comparaciones", "intercambios", "escrituras", "reservas", "bytes"
+            )?;
+            for row in self.rows.iter().filter(|r| r.distribution == distribution) {
+                let c = row.counts;
+                writeln!(
+                    f,
+                    "{:<18} {:>13} {:>12} {:>12} {:>8} {:>12}",
+                    row.algorithm, c.comparisons, c.swaps, c.writes, c.allocations, c.bytes
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts(name: &str, distribution: Distribution, len: usize) -> Counts {
+        let (_, sort) = algorithms()
+            .into_iter()
+            .find(|&(algorithm, _)| algorithm == name)
+            .unwrap();
+        let mut values = distribution.generate(len, 7);
+        let counters = Counters::new();
+        sort(&mut values, &counters);
+        counters.counts()
+    }
+
+    #[test]
+    fn test_distribuciones() {
+        for distribution in Distribution::ALL {
+            for len in [0, 1, 2, 7, 100] {
+                let values = distribution.generate(len, 1);
+                assert_eq!(values.len(), len);
+                assert_eq!(values, distribution.generate(len, 1));
+            }
+        }
+        assert_eq!(Distribution::OrganPipe.generate(6, 0), [0, 1, 2, 2, 1, 0]);
+        assert_eq!(Distribution::Reversed.generate(3, 0), [2, 1, 0]);
+        assert!(Distribution::FewUnique
+            .generate(1_000, 3)
+            .iter()
+            .all(|&x| x < 8));
+    }
+
+    #[test]
+    fn test_conteos() {
+        let len = 2_000;
+
+        // Merge Sort: un solo buffer, y n escrituras para llenarlo más n por
+        // cada una de las log2(n) pasadas
+        let merge = counts("merge", Distribution::Random, len);
+        assert_eq!(merge.allocations, 1);
+        assert_eq!(merge.bytes, len * 4);
+        assert_eq!(merge.swaps, 0);
+        assert!(merge.writes >= len * 11);
+
+        // Con la entrada ordenada, pdq detecta el orden sin mover nada
+        let pdq = counts("pdq", Distribution::Sorted, len);
+        assert_eq!(pdq.swaps, 0);
+        assert!(pdq.comparisons < 2 * len);
+
+        // Lomuto intercambia bastante más que Hoare
+        let lomuto = counts("quick (Lomuto)", Distribution::Random, len);
+        let hoare = counts("quick (Hoare)", Distribution::Random, len);
+        assert!(lomuto.swaps > hoare.swaps);
+
+        // Los ordenamientos sin comparaciones no comparan
+        for name in ["counting", "radix"] {
+            let c = counts(name, Distribution::Random, len);
+            assert_eq!(c.comparisons, 0);
+            assert!(c.allocations >= 2);
+        }
+    }
+
+    #[test]
+    fn test_reporte() {
+        let report = report(300, 1);
+        assert_eq!(
+            report.rows.len(),
+            Distribution::ALL.len() * algorithms().len()
+        );
+
+        let table = report.to_string();
+        assert!(table.contains("Entrada órgano, n = 300"));
+        assert!(table.contains("quick (2 pivotes)"));
+    }
+
+    #[test]
+    #[ignore]
+    fn bench_operation_counts() {
+        println!("{}", report(10_000, 42));
+    }
+}
@@ -0,0 +1,132 @@
+use super::heap_sort::heap_sort_by_less;
+use super::insertion_sort::insertion_sort_by_less;
+use super::probe::{NoProbe, Probe};
+use super::quick_sort::{hoare, median_of_three};
+use std::cmp::Ordering;
+
+// Por debajo de este tamaño se ordena por inserción
+const INSERTION_LEN: usize = 16;
+
+/// # Intro Sort
+///
+/// Ordenamiento no estable de propósito general: Quick Sort con una red de
+/// seguridad (Musser, 1997).
+///
+/// ## Pasos
+///
+/// 1. **Quick Sort**: Se particiona con Hoare y la mediana de tres, siguiendo
+///    en un ciclo con la parte más grande.
+/// 2. **Límite de profundidad**: Cada partición gasta un nivel de un
+///    presupuesto de `2 · log2(n)`. Si se agota, la entrada está llevando a
+///    Quick Sort a su peor caso, y esa parte se termina con Heap Sort.
+/// 3. **Partes chicas**: Las partes de hasta 16 elementos se ordenan por
+///    inserción, que en tamaños chicos es más rápido que seguir dividiendo.
+///
+/// ## Características
+///
+/// - Tiempo O(n log n) en el peor caso, con la velocidad de Quick Sort en el
+///   caso promedio
+/// - Sin memoria auxiliar ni `Clone`: solo intercambios
+/// - No es estable
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::intro_sort::intro_sort;
+///
+/// let mut numbers = vec![6, 2, 3, 9, 1, 0, 5, 8, 7, 1, 0, 4, 3, 1];
+/// intro_sort(&mut numbers);
+/// assert_eq!(numbers, vec![0, 0, 1, 1, 1, 2, 3, 3, 4, 5, 6, 7, 8, 9]);
+/// ```
+pub fn intro_sort<T: Ord>(arr: &mut [T]) {
+    intro_sort_by(arr, T::cmp);
+}
+
+/// Igual que [`intro_sort`], pero con una función de comparación propia.
+pub fn intro_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    intro_sort_probed(arr, compare, &NoProbe);
+}
+
+/// Igual que [`intro_sort_by`], avisando cada operación a `probe`.
+pub fn intro_sort_probed<T, F, P>(arr: &mut [T], mut compare: F, probe: &P)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    let mut compare = |a: &T, b: &T| {
+        probe.compare(a, b);
+        compare(a, b)
+    };
+    let limit = 2 * arr.len().max(1).ilog2();
+    sort(arr, limit, &mut compare, probe);
+}
+
+fn sort<T, F, P>(mut arr: &mut [T], mut limit: u32, compare: &mut F, probe: &P)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    loop {
+        if arr.len() <= INSERTION_LEN {
+            insertion_sort_by_less(arr, &mut |a, b| compare(a, b) == Ordering::Less, probe);
+            return;
+        }
+        if limit == 0 {
+            heap_sort_by_less(arr, &mut |a, b| compare(a, b) == Ordering::Less, probe);
+            return;
+        }
+        limit -= 1;
+
+        let pivot = median_of_three(arr, 0, arr.len() / 2, arr.len() - 1, compare);
+        let (left, right) = hoare(arr, pivot, compare, probe);
+
+        // Recursión sobre la parte chica y ciclo sobre la grande
+        let (small, large) = if left.len() < right.len() {
+            (left, right)
+        } else {
+            (right, left)
+        };
+        sort(&mut arr[small], limit, compare, probe);
+        arr = &mut std::mem::take(&mut arr)[large];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::random_values;
+
+    #[test]
+    fn test_contra_sort_unstable() {
+        for len in (0..100).chain([1_000, 50_000]) {
+            let inputs = [
+                random_values(len, u32::MAX, len as u64),
+                random_values(len, 4, len as u64),
+                (0..len as u32).collect(),
+                (0..len as u32).rev().collect(),
+            ];
+            for mut values in inputs {
+                let mut expected = values.clone();
+                expected.sort_unstable();
+                intro_sort(&mut values);
+                assert_eq!(values, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_limite_de_profundidad() {
+        // Con un presupuesto chico, las partes que quedan pendientes se
+        // terminan con Heap Sort; el resultado tiene que ser el mismo
+        for limit in [0, 1, 3] {
+            let mut values = random_values(5_000, 1_000, limit as u64);
+            let mut expected = values.clone();
+            expected.sort_unstable();
+            sort(&mut values, limit, &mut u32::cmp, &NoProbe);
+            assert_eq!(values, expected);
+        }
+    }
+}
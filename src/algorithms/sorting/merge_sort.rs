@@ -1,6 +1,10 @@
+use super::probe::{copy, to_vec, write, NoProbe, Probe};
+use std::cmp::Ordering;
+
 /// # Merge Sort
 ///
-/// La función `merge` implementa el algoritmo de Merge Sort para ordenar un vector de enteros en Rust.
+/// La función `merge_sort` implementa el algoritmo de Merge Sort para ordenar
+/// un slice de cualquier tipo comparable, en el lugar.
 ///
 /// ## Funcionamiento
 ///
@@ -8,204 +12,240 @@
 ///
 /// ## Implementación
 ///
-/// Esta implementación utiliza dos vectores (`arr` y `arr_2`) para alternar entre la lectura y la escritura durante las fusiones. Aquí están los pasos detallados:
+/// Esta implementación es iterativa (de abajo hacia arriba) y utiliza el slice
+/// original y un único buffer auxiliar para alternar entre la lectura y la
+/// escritura durante las fusiones:
 ///
-/// 1. **Inicialización**: Se inicializan variables como el vector auxiliar `arr_2`, y un indicador `using_flag` para alternar entre los vectores.
-/// 2. **Ciclo Principal**: Mientras el tamaño de los elementos a fusionar sea menor que la longitud del array, se ejecuta un ciclo para fusionar subarrays:
-///     - Se alterna entre leer y escribir en `arr` y `arr_2` utilizando `using_flag`.
-///     - Se divide el array en bloques de tamaño `elements` y se fusionan dos bloques adyacentes.
-///     - La fusión se realiza comparando elementos de los dos bloques y escribiéndolos en el vector de escritura.
+/// 1. **Inicialización**: Se crea el buffer auxiliar como copia del slice; es
+///    la única memoria extra que se reserva.
+/// 2. **Ciclo Principal**: Mientras el tamaño de los bloques a fusionar sea menor que la longitud del array, se fusionan los bloques de a pares:
+///     - Se lee de uno de los dos vectores y se escribe en el otro, y en la siguiente pasada se intercambian los papeles.
+///     - La fusión se realiza comparando el primer elemento pendiente de cada bloque y escribiendo el menor.
 /// 3. **Incremento del Tamaño de Bloque**: Después de cada paso de fusión, se duplica el tamaño de los bloques a fusionar.
-/// 4. **Resultado Final**: Al terminar el ciclo, se retorna el vector ordenado.
+/// 4. **Resultado Final**: Si la última pasada escribió en el buffer auxiliar, se copia de vuelta al slice.
+///
+/// ## Estabilidad
+///
+/// El ordenamiento es estable: los elementos que se comparan como iguales
+/// conservan su orden relativo original. En cada fusión, ante un empate se
+/// toma siempre el elemento del bloque izquierdo, que es el que aparecía antes.
+/// Esto permite, por ejemplo, ordenar registros por un campo y luego por otro
+/// sin perder el primer orden dentro de cada grupo.
+///
+/// ## Características
+///
+/// - Tiempo O(n log n) en todos los casos
+/// - Memoria extra O(n): un solo buffer, sin importar la cantidad de pasadas
 ///
 /// ## Ejemplo de Uso
 ///
 /// ```rust
-/// pub fn merge(mut arr: Vec<i32>) -> Vec<i32> {
-///     let arr_len = arr.len();
-///     let mut arr_2: Vec<i32> = vec![0; arr_len];
-///     let mut reading: &Vec<i32>;
-///     let mut writing: &mut Vec<i32>;
-///     let mut using_flag: i8 = -1;
-///     let mut elements = 1;
-///
-///     while elements < arr_len {
-///         using_flag *= -1;
-///         if using_flag == -1 {
-///             writing = &mut arr;
-///             reading = &mut arr_2;
-///         } else {
-///             writing = &mut arr_2;
-///             reading = &mut arr;
-///         }
-///
-///         let mut pos = 0;
-///
-///         while pos < arr_len {
-///             let mut index_1 = pos;
-///             let mut index_2 = pos + elements;
-///             let slice_1 = &reading[index_1..index_2];
-///             let slice_2 = if (index_2 + elements) > arr_len {
-///                 &reading[index_2..arr_len]
-///             } else {
-///                 &reading[index_2..(index_2 + elements)]
-///             };
-///             index_1 = 0;
-///             index_2 = 0;
-///
-///             for _ in 0..(elements * 2) {
-///                 match (slice_1.get(index_1), slice_2.get(index_2)) {
-///                     (Some(value1), Some(value2)) => {
-///                         if value1 < value2 {
-///                             writing[pos + index_1 + index_2] = *value1;
-///                             index_1 += 1;
-///                         } else {
-///                             writing[pos + index_1 + index_2] = *value2;
-///                             index_2 += 1
-///                         }
-///                     }
-///                     (Some(value1), None) => {
-///                         writing[pos + index_1 + index_2] = *value1;
-///                         index_1 += 1;
-///                     }
-///                     (None, Some(value2)) => {
-///                         writing[pos + index_1 + index_2] = *value2;
-///                         index_2 += 1;
-///                     }
-///                     (None, None) => {
-///                         break;
-///                     }
-///                 }
-///             }
-///
-///             pos += elements * 2;
-///         }
-///
-///         elements *= 2;
-///     }
-///
-///     if using_flag == -1 {
-///         arr
-///     } else {
-///         arr_2
-///     }
-/// }
-/// ```
+/// use algoritmos_y_estructuras::algorithms::sorting::merge_sort::{merge_sort, merge_sort_by_key};
 ///
-/// Esta función se utiliza en pruebas para validar su correcto funcionamiento:
+/// let mut numbers = vec![6, 2, 3, 9, 1, 0, 5, 8, 7, 1, 0, 4, 3, 1];
+/// merge_sort(&mut numbers);
+/// assert_eq!(numbers, vec![0, 0, 1, 1, 1, 2, 3, 3, 4, 5, 6, 7, 8, 9]);
 ///
-/// ```rust
-/// #[cfg(test)]
-/// mod tests {
-///     use crate::algorithms::sorting::merge_sort::merge;
-///
-///     #[test]
-///     fn test_sorting() {
-///         let vec_1 = vec![6, 2, 3, 9, 1, 0, 5, 8, 7, 1, 0, 4, 3, 1];
-///         assert_eq!(vec![0, 0, 1, 1, 1, 2, 3, 3, 4, 5, 6, 7, 8, 9], merge(vec_1));
-///     }
-/// }
+/// // Ordenar registros por edad: los empatados quedan en su orden original
+/// let mut people = vec![("Ana", 30), ("Luis", 25), ("Eva", 30), ("Juan", 25)];
+/// merge_sort_by_key(&mut people, |&(_, age)| age);
+/// assert_eq!(people, vec![("Luis", 25), ("Juan", 25), ("Ana", 30), ("Eva", 30)]);
 /// ```
+pub fn merge_sort<T: Ord + Clone>(arr: &mut [T]) {
+    merge_sort_by(arr, T::cmp);
+}
+
+/// Ordena el slice con Merge Sort usando una función de comparación propia.
+///
+/// Es estable, igual que [`merge_sort`]. Por ejemplo, `|a, b| b.cmp(a)`
+/// ordena de mayor a menor.
+pub fn merge_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    merge_sort_probed(arr, compare, &NoProbe);
+}
+
+/// Igual que [`merge_sort_by`], avisando cada operación a `probe`.
+pub fn merge_sort_probed<T, F, P>(arr: &mut [T], mut compare: F, probe: &P)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    let mut compare = |a: &T, b: &T| {
+        probe.compare(a, b);
+        compare(a, b)
+    };
+    if arr.len() < 2 {
+        return;
+    }
 
-// Define la función merge que toma un vector de enteros como parámetro y devuelve un vector de enteros
-pub fn merge(mut arr: Vec<i32>) -> Vec<i32> {
-    // Obtener la longitud del array
+    // Buffer auxiliar: la única reserva de memoria del algoritmo
+    let mut arr_2: Vec<T> = to_vec(arr, probe);
+    merge_sort_in(arr, &mut arr_2, &mut compare, probe);
+}
+
+/// Merge Sort de abajo hacia arriba con `buffer`, del mismo largo que `arr`,
+/// como espacio auxiliar. Cada pasada escribe el buffer completo, así que su
+/// contenido inicial no importa.
+pub(super) fn merge_sort_in<T, F, P>(arr: &mut [T], buffer: &mut [T], compare: &mut F, probe: &P)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
     let arr_len = arr.len();
-    // Crear un vector auxiliar arr_2 del mismo tamaño que arr, inicializado con ceros
-    let mut arr_2: Vec<i32> = vec![0; arr_len];
-    // Declarar punteros para leer y escribir en los vectores
-    let mut reading: &Vec<i32>;
-    let mut writing: &mut Vec<i32>;
-    // Flag que indica qué vector se usa para leer y cuál para escribir
-    let mut using_flag: i8 = -1;
-    // Número de elementos a ser fusionados, empieza en 1
+    // Punteros para leer y escribir, que se intercambian en cada pasada
+    let mut reading: &mut [T] = arr;
+    let mut writing: &mut [T] = buffer;
+    // Si el resultado parcial quedó en el buffer auxiliar
+    let mut in_buffer = false;
+    // Número de elementos de cada bloque a fusionar, empieza en 1
     let mut elements = 1;
 
-    // Mientras el número de elementos a fusionar sea menor que la longitud del array
     while elements < arr_len {
-        // Alternar el valor de using_flag entre -1 y 1
-        using_flag *= -1;
-        if using_flag == -1 {
-            // Si using_flag es -1, escribir en arr y leer de arr_2
-            writing = &mut arr;
-            reading = &mut arr_2;
-        } else {
-            // Si using_flag es 1, escribir en arr_2 y leer de arr
-            writing = &mut arr_2;
-            reading = &mut arr;
-        }
-
-        // Inicializar la posición a 0
         let mut pos = 0;
-
-        // Mientras la posición sea menor que la longitud del array
         while pos < arr_len {
-            // Índices para fusionar dos mitades
-            let mut index_1 = pos;
-            let mut index_2 = pos + elements;
-            // Obtener los slices de los subarrays a fusionar
-            let slice_1 = &reading[index_1..index_2];
-            let slice_2 = if (index_2 + elements) > arr_len {
-                &reading[index_2..arr_len]
-            } else {
-                &reading[index_2..(index_2 + elements)]
-            };
-            // Reinicializar los índices de los slices
-            index_1 = 0;
-            index_2 = 0;
-
-            // Fusionar los dos slices en el array de escritura
-            for _ in 0..(elements * 2) {
-                match (slice_1.get(index_1), slice_2.get(index_2)) {
-                    (Some(value1), Some(value2)) => {
-                        if value1 < value2 {
-                            writing[pos + index_1 + index_2] = *value1;
-                            index_1 += 1;
-                        } else {
-                            writing[pos + index_1 + index_2] = *value2;
-                            index_2 += 1
-                        }
-                    }
-                    (Some(value1), None) => {
-                        writing[pos + index_1 + index_2] = *value1;
-                        index_1 += 1;
-                    }
-                    (None, Some(value2)) => {
-                        writing[pos + index_1 + index_2] = *value2;
-                        index_2 += 1;
-                    }
-                    (None, None) => {
-                        break;
-                    }
-                }
-            }
-
-            // Avanzar la posición al siguiente bloque de elementos
+            // Límites de los dos bloques: [pos, middle) y [middle, end)
+            let middle = (pos + elements).min(arr_len);
+            let end = (pos + elements * 2).min(arr_len);
+            probe.merge(&reading[pos..end], &writing[pos..end]);
+            merge_blocks(
+                &reading[pos..middle],
+                &reading[middle..end],
+                &mut writing[pos..end],
+                compare,
+                probe,
+            );
+
+            // Avanzar la posición al siguiente par de bloques
             pos += elements * 2;
         }
 
-        // Duplicar el tamaño de los elementos a fusionar
+        // Lo recién escrito se lee en la pasada siguiente
+        std::mem::swap(&mut reading, &mut writing);
+        in_buffer = !in_buffer;
         elements *= 2;
     }
 
-    // Devolver el array ordenado
-    if using_flag == -1 {
-        arr
-    } else {
-        arr_2
+    // Si el resultado quedó en el buffer, `writing` es el slice original
+    if in_buffer {
+        copy(reading, writing, probe);
+    }
+}
+
+/// Ordena el slice con Merge Sort comparando la clave que devuelve `key`.
+///
+/// Es estable, igual que [`merge_sort`]. La clave se recalcula en cada
+/// comparación, así que conviene que sea barata de obtener.
+pub fn merge_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    T: Clone,
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    merge_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// Ordena un vector con Merge Sort y lo devuelve.
+///
+/// Es un atajo sobre [`merge_sort`] para quien prefiere pasar el vector por valor.
+pub fn merge<T: Ord + Clone>(mut arr: Vec<T>) -> Vec<T> {
+    merge_sort(&mut arr);
+    arr
+}
+
+/// Fusiona dos bloques ordenados en `writing`, que tiene el largo de ambos
+/// juntos. Ante un empate toma el elemento de `left`.
+pub(super) fn merge_blocks<T, F, P>(
+    left: &[T],
+    right: &[T],
+    writing: &mut [T],
+    compare: &mut F,
+    probe: &P,
+) where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    let (mut index_1, mut index_2) = (0, 0);
+
+    for k in 0..writing.len() {
+        let take_right = match (left.get(index_1), right.get(index_2)) {
+            // Solo se toma el de la derecha si es estrictamente menor: así los
+            // empates conservan el orden original y el algoritmo es estable
+            (Some(value1), Some(value2)) => compare(value2, value1) == Ordering::Less,
+            (Some(_), None) => false,
+            (None, _) => true,
+        };
+
+        if take_right {
+            write(writing, k, right[index_2].clone(), probe);
+            index_2 += 1;
+        } else {
+            write(writing, k, left[index_1].clone(), probe);
+            index_1 += 1;
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    // Importar la función merge
-    use crate::algorithms::sorting::merge_sort::merge;
+    use super::*;
+    use crate::algorithms::sorting::random_values;
 
     #[test]
     fn test_sorting() {
         // Definir un vector de prueba
         let vec_1 = vec![6, 2, 3, 9, 1, 0, 5, 8, 7, 1, 0, 4, 3, 1];
-        // Imprimir el vector ordenado usando la función merge
+        // Ordenar el vector usando la función merge
         assert_eq!(vec![0, 0, 1, 1, 1, 2, 3, 3, 4, 5, 6, 7, 8, 9], merge(vec_1));
     }
+
+    #[test]
+    fn test_todos_los_largos() {
+        // Largos que no son potencia de dos dejan bloques incompletos al final
+        for len in 0..70 {
+            let mut values = random_values(len, 20, len as u64);
+            let mut expected = values.clone();
+            expected.sort();
+
+            merge_sort(&mut values);
+            assert_eq!(values, expected);
+        }
+    }
+
+    #[test]
+    fn test_tipos_no_numericos() {
+        let mut words = vec!["pera", "manzana", "kiwi", "banana", "uva"];
+        merge_sort(&mut words);
+        assert_eq!(words, vec!["banana", "kiwi", "manzana", "pera", "uva"]);
+
+        let mut owned: Vec<String> = vec!["b".into(), "c".into(), "a".into()];
+        merge_sort_by(&mut owned, |a, b| b.cmp(a));
+        assert_eq!(owned, vec!["c", "b", "a"]);
+    }
+
+    #[test]
+    fn test_estabilidad() {
+        // Registros (clave, posición original): al ordenar por clave, los de
+        // igual clave deben quedar con las posiciones en orden creciente
+        let mut records: Vec<(u8, usize)> = (0..100).map(|i| ((i * 37 % 11) as u8, i)).collect();
+        merge_sort_by_key(&mut records, |&(key, _)| key);
+
+        for pair in records.windows(2) {
+            assert!(pair[0].0 < pair[1].0 || (pair[0].0 == pair[1].0 && pair[0].1 < pair[1].1));
+        }
+
+        // Ordenar por un campo y luego, de forma estable, por otro
+        let mut people = vec![("Eva", 30), ("Ana", 25), ("Luis", 30), ("Bea", 25)];
+        merge_sort_by_key(&mut people, |&(name, _)| name);
+        merge_sort_by_key(&mut people, |&(_, age)| age);
+        assert_eq!(
+            people,
+            vec![("Ana", 25), ("Bea", 25), ("Eva", 30), ("Luis", 30)]
+        );
+    }
 }
@@ -1 +1,35 @@
+pub mod bucket_sort;
+pub mod cocktail_sort;
+pub mod comb_sort;
+pub mod counting_sort;
+pub mod cycle_sort;
+pub mod external_sort;
+pub mod gnome_sort;
+pub mod heap_sort;
+pub mod insertion_sort;
+pub mod instrument;
+pub mod intro_sort;
 pub mod merge_sort;
+pub mod natural_merge_sort;
+pub mod parallel_merge_sort;
+pub mod pdq_sort;
+pub mod probe;
+pub mod property;
+pub mod quick_sort;
+pub mod radix_sort;
+pub mod selection;
+pub mod selection_sort;
+pub mod shell_sort;
+pub mod sorter;
+pub mod string_sort;
+pub mod trace;
+
+/// `len` valores en `0..modulo`, reproducibles con `seed`, para las pruebas
+/// de los ordenamientos.
+#[cfg(test)]
+pub(crate) fn random_values(len: usize, modulo: u32, seed: u64) -> Vec<u32> {
+    let mut rng = property::Rng::new(seed);
+    (0..len)
+        .map(|_| rng.below(modulo as usize) as u32)
+        .collect()
+}
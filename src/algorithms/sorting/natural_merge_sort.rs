@@ -0,0 +1,525 @@
+use super::insertion_sort::binary_insertion_sort_from;
+use super::probe::{copy, reverse, to_vec, write, NoProbe, Probe};
+use std::cmp::Ordering;
+
+/// # Natural Merge Sort
+///
+/// Variante adaptativa de [`merge_sort`](super::merge_sort::merge_sort), al
+/// estilo de Timsort: en lugar de empezar siempre por bloques de un elemento,
+/// aprovecha los tramos que ya vienen ordenados en la entrada.
+///
+/// ## Pasos
+///
+/// 1. **Detección de tramos**: Se recorre el slice buscando el tramo
+///    ascendente (no decreciente) o estrictamente descendente más largo que
+///    empieza en la posición actual. Los descendentes se invierten en el lugar;
+///    se exige que sean estrictos para no alterar el orden de los empates.
+/// 2. **Tramos cortos**: Si un tramo es más corto que un mínimo calculado a
+///    partir de la longitud (entre 32 y 64), se extiende con inserción binaria.
+/// 3. **Pila de tramos**: Cada tramo se apila, y se fusionan los de arriba
+///    mientras sus longitudes no decrezcan lo suficiente. Así las fusiones
+///    quedan balanceadas, como en el merge sort clásico.
+/// 4. **Fusión con galope**: Antes de fusionar se descartan los elementos que
+///    ya están en su lugar. Durante la fusión, si un lado gana muchas veces
+///    seguidas se pasa a modo galope: una búsqueda exponencial encuentra de una
+///    vez cuántos elementos copiar en bloque.
+///
+/// ## Características
+///
+/// - Tiempo O(n log n) en el peor caso y O(n) con la entrada ya ordenada
+///   (en cualquiera de los dos sentidos)
+/// - Los datos casi ordenados, como registros de log con algunos desfasajes,
+///   cuestan cerca de O(n)
+/// - Es estable, igual que `merge_sort`
+/// - Memoria extra: a lo sumo la mitad del slice, para copiar el más corto
+///   de los dos tramos de cada fusión
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::natural_merge_sort::natural_merge_sort;
+///
+/// let mut log = vec![1, 2, 3, 7, 5, 6, 8, 9, 10, 4];
+/// natural_merge_sort(&mut log);
+/// assert_eq!(log, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+/// ```
+pub fn natural_merge_sort<T: Ord + Clone>(arr: &mut [T]) {
+    natural_merge_sort_by(arr, T::cmp);
+}
+
+/// Igual que [`natural_merge_sort`], pero con una función de comparación propia.
+pub fn natural_merge_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    natural_merge_sort_probed(arr, compare, &NoProbe);
+}
+
+/// Igual que [`natural_merge_sort_by`], avisando cada operación a `probe`.
+pub fn natural_merge_sort_probed<T, F, P>(arr: &mut [T], mut compare: F, probe: &P)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    let mut compare = |a: &T, b: &T| {
+        probe.compare(a, b);
+        compare(a, b)
+    };
+    let arr_len = arr.len();
+    if arr_len < 2 {
+        return;
+    }
+
+    let min_run = min_run_length(arr_len);
+    let mut runs: Vec<Run> = Vec::new();
+    let mut min_gallop = MIN_GALLOP;
+    let mut start = 0;
+
+    while start < arr_len {
+        let rest = &mut arr[start..];
+        let mut len = count_run(rest, &mut compare, probe);
+
+        // Un tramo corto se completa con inserción binaria hasta min_run
+        if len < min_run {
+            let forced = min_run.min(rest.len());
+            binary_insertion_sort_from(&mut rest[..forced], len, &mut compare, probe);
+            len = forced;
+        }
+
+        runs.push(Run { start, len });
+        start += len;
+        merge_collapse(arr, &mut runs, &mut min_gallop, &mut compare, probe);
+    }
+
+    // Al final se fusiona todo lo que quedó en la pila
+    while runs.len() > 1 {
+        let n = runs.len();
+        let at = if n >= 3 && runs[n - 3].len < runs[n - 1].len {
+            n - 3
+        } else {
+            n - 2
+        };
+        merge_at(arr, &mut runs, at, &mut min_gallop, &mut compare, probe);
+    }
+}
+
+/// Igual que [`natural_merge_sort`], pero comparando la clave que devuelve `key`.
+pub fn natural_merge_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    T: Clone,
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    natural_merge_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+// Cantidad de victorias seguidas de un lado que activa el modo galope
+const MIN_GALLOP: usize = 7;
+
+// Tramo ordenado arr[start..start + len] apilado a la espera de fusión
+#[derive(Debug, Clone, Copy)]
+struct Run {
+    start: usize,
+    len: usize,
+}
+
+// Longitud mínima de los tramos: un número entre 32 y 64 tal que n / min_run
+// sea una potencia de dos o apenas menor, para que las fusiones sean parejas
+fn min_run_length(mut n: usize) -> usize {
+    let mut extra = 0;
+    while n >= 64 {
+        extra |= n & 1;
+        n >>= 1;
+    }
+    n + extra
+}
+
+// Largo del tramo que empieza al inicio del slice; si es descendente, lo invierte
+fn count_run<T, F, P>(arr: &mut [T], compare: &mut F, probe: &P) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    if arr.len() < 2 {
+        return arr.len();
+    }
+
+    let mut end = 2;
+    if compare(&arr[1], &arr[0]) == Ordering::Less {
+        // Estrictamente descendente: invertirlo no cambia el orden de empates
+        while end < arr.len() && compare(&arr[end], &arr[end - 1]) == Ordering::Less {
+            end += 1;
+        }
+        reverse(&mut arr[..end], probe);
+    } else {
+        while end < arr.len() && compare(&arr[end], &arr[end - 1]) != Ordering::Less {
+            end += 1;
+        }
+    }
+    end
+}
+
+// Fusiona los tramos del tope de la pila hasta que, de arriba hacia abajo,
+// cada tramo sea más largo que la suma de los dos siguientes
+fn merge_collapse<T, F, P>(
+    arr: &mut [T],
+    runs: &mut Vec<Run>,
+    min_gallop: &mut usize,
+    compare: &mut F,
+    probe: &P,
+) where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    while runs.len() > 1 {
+        let n = runs.len();
+        // También se revisa el cuarto tramo: sin esa condición el invariante
+        // puede romperse más abajo en la pila (error conocido del Timsort original)
+        let unbalanced = (n >= 3 && runs[n - 3].len <= runs[n - 2].len + runs[n - 1].len)
+            || (n >= 4 && runs[n - 4].len <= runs[n - 3].len + runs[n - 2].len);
+
+        if unbalanced {
+            let at = if runs[n - 3].len < runs[n - 1].len {
+                n - 3
+            } else {
+                n - 2
+            };
+            merge_at(arr, runs, at, min_gallop, compare, probe);
+        } else if runs[n - 2].len <= runs[n - 1].len {
+            merge_at(arr, runs, n - 2, min_gallop, compare, probe);
+        } else {
+            break;
+        }
+    }
+}
+
+// Fusiona los tramos runs[at] y runs[at + 1], que son contiguos
+fn merge_at<T, F, P>(
+    arr: &mut [T],
+    runs: &mut Vec<Run>,
+    at: usize,
+    min_gallop: &mut usize,
+    compare: &mut F,
+    probe: &P,
+) where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    let Run { start, len } = runs[at];
+    let middle = start + len;
+    let end = middle + runs[at + 1].len;
+    runs[at].len += runs[at + 1].len;
+    runs.remove(at + 1);
+
+    // Los elementos del tramo izquierdo menores o iguales al primero del
+    // derecho ya están en su lugar
+    let skip = gallop(&arr[start..middle], |x| {
+        compare(&arr[middle], x) != Ordering::Less
+    });
+    let start = start + skip;
+    if start == middle {
+        return;
+    }
+    // Y también los del tramo derecho mayores o iguales al último del izquierdo
+    let keep = gallop(&arr[middle..end], |x| {
+        compare(x, &arr[middle - 1]) == Ordering::Less
+    });
+    let end = middle + keep;
+
+    // Se copia el tramo más corto, así el buffer no pasa de la mitad
+    probe.merge(&arr[start..end], &arr[start..end]);
+    let (arr, middle) = (&mut arr[start..end], middle - start);
+    if middle <= arr.len() - middle {
+        merge_low(arr, middle, min_gallop, compare, probe);
+    } else {
+        merge_high(arr, middle, min_gallop, compare, probe);
+    }
+}
+
+// Fusiona arr[..middle] y arr[middle..] copiando solo el tramo izquierdo a un
+// buffer. La posición de escritura nunca alcanza a la de lectura del tramo
+// derecho, así que este se puede leer en el lugar
+fn merge_low<T, F, P>(
+    arr: &mut [T],
+    middle: usize,
+    min_gallop: &mut usize,
+    compare: &mut F,
+    probe: &P,
+) where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    let left: Vec<T> = to_vec(&arr[..middle], probe);
+    let (mut i, mut j, mut k) = (0, middle, 0);
+    // Victorias seguidas de cada lado
+    let (mut left_wins, mut right_wins) = (0, 0);
+
+    while i < left.len() && j < arr.len() {
+        // Ante un empate gana el izquierdo, para que sea estable
+        if compare(&arr[j], &left[i]) == Ordering::Less {
+            write(arr, k, arr[j].clone(), probe);
+            j += 1;
+            right_wins += 1;
+            left_wins = 0;
+        } else {
+            write(arr, k, left[i].clone(), probe);
+            i += 1;
+            left_wins += 1;
+            right_wins = 0;
+        }
+        k += 1;
+
+        if i == left.len() || j == arr.len() || left_wins.max(right_wins) < *min_gallop {
+            continue;
+        }
+
+        // Modo galope: se busca de una vez cuántos elementos copiar en bloque
+        let count = if left_wins > 0 {
+            let count = gallop(&left[i..], |x| compare(&arr[j], x) != Ordering::Less);
+            copy(&left[i..i + count], &mut arr[k..k + count], probe);
+            i += count;
+            count
+        } else {
+            let count = gallop(&arr[j..], |x| compare(x, &left[i]) == Ordering::Less);
+            for offset in 0..count {
+                write(arr, k + offset, arr[j + offset].clone(), probe);
+            }
+            j += count;
+            count
+        };
+        k += count;
+        (left_wins, right_wins) = (0, 0);
+
+        // Si el galope rinde, se entra en él más fácilmente; si no, se encarece
+        if count >= MIN_GALLOP {
+            *min_gallop = min_gallop.saturating_sub(1).max(1);
+        } else {
+            *min_gallop += 1;
+        }
+    }
+
+    // Lo que queda del tramo derecho ya está en su lugar
+    copy(&left[i..], &mut arr[k..k + left.len() - i], probe);
+}
+
+// Como `merge_low`, pero copiando el tramo derecho y llenando `arr` desde el
+// final. La posición de escritura siempre queda por encima de la de lectura
+// del tramo izquierdo, que se lee en el lugar
+fn merge_high<T, F, P>(
+    arr: &mut [T],
+    middle: usize,
+    min_gallop: &mut usize,
+    compare: &mut F,
+    probe: &P,
+) where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    let right: Vec<T> = to_vec(&arr[middle..], probe);
+    // Quedan por fusionar arr[..i] y right[..j], que van en arr[..k]
+    let (mut i, mut j, mut k) = (middle, right.len(), arr.len());
+    // Victorias seguidas de cada lado
+    let (mut left_wins, mut right_wins) = (0, 0);
+
+    while i > 0 && j > 0 {
+        k -= 1;
+        // Ante un empate gana el derecho, que va después: así es estable
+        if compare(&right[j - 1], &arr[i - 1]) == Ordering::Less {
+            i -= 1;
+            write(arr, k, arr[i].clone(), probe);
+            left_wins += 1;
+            right_wins = 0;
+        } else {
+            j -= 1;
+            write(arr, k, right[j].clone(), probe);
+            right_wins += 1;
+            left_wins = 0;
+        }
+
+        if i == 0 || j == 0 || left_wins.max(right_wins) < *min_gallop {
+            continue;
+        }
+
+        // Modo galope, desde el final de cada tramo
+        let count = if left_wins > 0 {
+            let count = gallop_back(&arr[..i], |x| compare(&right[j - 1], x) == Ordering::Less);
+            for offset in 1..=count {
+                write(arr, k - offset, arr[i - offset].clone(), probe);
+            }
+            i -= count;
+            count
+        } else {
+            let count = gallop_back(&right[..j], |x| compare(x, &arr[i - 1]) != Ordering::Less);
+            copy(&right[j - count..j], &mut arr[k - count..k], probe);
+            j -= count;
+            count
+        };
+        k -= count;
+        (left_wins, right_wins) = (0, 0);
+
+        if count >= MIN_GALLOP {
+            *min_gallop = min_gallop.saturating_sub(1).max(1);
+        } else {
+            *min_gallop += 1;
+        }
+    }
+
+    // Lo que queda del tramo izquierdo ya está en su lugar
+    copy(&right[..j], &mut arr[..j], probe);
+}
+
+// Cantidad de elementos iniciales del slice que cumplen `pred`, sabiendo que
+// los que la cumplen van todos primero. Primero se busca con saltos que se
+// duplican (1, 2, 4, ...) y después con búsqueda binaria dentro del último
+// salto, así que cuesta O(log k) para una respuesta k
+fn gallop<T>(arr: &[T], mut pred: impl FnMut(&T) -> bool) -> usize {
+    let mut bound = 1;
+    while bound <= arr.len() && pred(&arr[bound - 1]) {
+        bound *= 2;
+    }
+    let low = bound / 2;
+    let high = (bound - 1).min(arr.len());
+    low + arr[low..high].partition_point(pred)
+}
+
+// Como `gallop`, pero cuenta los elementos finales que cumplen `pred`,
+// sabiendo que los que la cumplen van todos al final
+fn gallop_back<T>(arr: &[T], mut pred: impl FnMut(&T) -> bool) -> usize {
+    let len = arr.len();
+    let mut bound = 1;
+    while bound <= len && pred(&arr[len - bound]) {
+        bound *= 2;
+    }
+    let low = bound / 2;
+    let high = (bound - 1).min(len);
+    let window = &arr[len - high..len - low];
+    low + window.len() - window.partition_point(|x| !pred(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::random_values;
+
+    // Ordena contando comparaciones
+    fn comparisons(values: &mut [u32]) -> usize {
+        let mut count = 0;
+        natural_merge_sort_by(values, |a, b| {
+            count += 1;
+            a.cmp(b)
+        });
+        count
+    }
+
+    #[test]
+    fn test_contra_sort() {
+        for (seed, len) in (0..300).enumerate() {
+            // Valores repetidos, aleatorios y tramos ya ordenados mezclados
+            let mut values = random_values(len, 50, seed as u64);
+            values[..len / 3].sort();
+            values[len / 3..len / 2].sort_by(|a, b| b.cmp(a));
+            let mut expected = values.clone();
+            expected.sort();
+
+            natural_merge_sort(&mut values);
+            assert_eq!(values, expected);
+        }
+
+        let mut large = random_values(100_000, u32::MAX, 3);
+        let mut expected = large.clone();
+        expected.sort();
+        natural_merge_sort(&mut large);
+        assert_eq!(large, expected);
+    }
+
+    #[test]
+    fn test_entradas_casi_ordenadas() {
+        let n = 100_000;
+
+        // Ordenado y en orden inverso: un solo tramo, n - 1 comparaciones
+        let mut sorted: Vec<u32> = (0..n).collect();
+        assert_eq!(comparisons(&mut sorted), n as usize - 1);
+        let mut reversed: Vec<u32> = (0..n).rev().collect();
+        assert_eq!(comparisons(&mut reversed), n as usize - 1);
+        assert_eq!(reversed, sorted);
+
+        // Un log ordenado con algunas entradas desfasadas sigue costando O(n)
+        let mut log: Vec<u32> = (0..n).collect();
+        for i in (0..n as usize - 10).step_by(5_000) {
+            log.swap(i, i + 7);
+        }
+        let count = comparisons(&mut log);
+        assert_eq!(log, sorted);
+        assert!(count < 3 * n as usize, "{count} comparaciones");
+
+        // Dos mitades ordenadas y concatenadas: el galope evita comparar todo
+        let mut halves: Vec<u32> = (0..n / 2).map(|i| i * 2).collect();
+        halves.extend((0..n / 2).map(|i| i + n));
+        let count = comparisons(&mut halves);
+        assert!(count < n as usize + 100, "{count} comparaciones");
+    }
+
+    #[test]
+    fn test_estabilidad() {
+        // (clave, posición original) con muchas claves repetidas y tramos
+        // descendentes, que se invierten
+        let keys = random_values(5_000, 8, 11);
+        let mut records: Vec<(u32, usize)> = keys.into_iter().zip(0..).collect();
+        records[1_000..2_000].sort_by_key(|&(key, _)| std::cmp::Reverse(key));
+
+        natural_merge_sort_by_key(&mut records, |&(key, _)| key);
+        for pair in records.windows(2) {
+            assert!(pair[0].0 < pair[1].0 || (pair[0].0 == pair[1].0 && pair[0].1 < pair[1].1));
+        }
+    }
+
+    #[test]
+    fn test_tramo_izquierdo_largo() {
+        // Probe que guarda la reserva más grande
+        #[derive(Default)]
+        struct LargestAlloc(std::cell::Cell<usize>);
+        impl<T> Probe<T> for LargestAlloc {
+            fn alloc(&self, bytes: usize) {
+                self.0.set(self.0.get().max(bytes));
+            }
+        }
+
+        // Un tramo de 3000 claves repetidas seguido de uno de 1000: la fusión
+        // copia el derecho, y los empates siguen en el orden original
+        let mut records: Vec<(u32, usize)> = (0..3_000).map(|i| (i / 3, i as usize)).collect();
+        records.extend((0..1_000).map(|i| (i, 3_000 + i as usize)));
+        let mut expected = records.clone();
+        expected.sort_by_key(|&(key, _)| key);
+
+        let probe = LargestAlloc::default();
+        natural_merge_sort_probed(&mut records, |a, b| a.0.cmp(&b.0), &probe);
+        assert_eq!(records, expected);
+        // Sin contar lo que ya estaba en su lugar, a lo sumo el tramo derecho
+        let largest = probe.0.get();
+        assert!(largest > 0 && largest <= 1_000 * std::mem::size_of::<(u32, usize)>());
+    }
+
+    #[test]
+    fn test_galope() {
+        let values = [1, 3, 3, 5, 8, 9, 12];
+        for key in 0..14 {
+            let expected = values.iter().filter(|&&x| x <= key).count();
+            assert_eq!(gallop(&values, |&x| x <= key), expected);
+        }
+        assert_eq!(gallop(&[] as &[u32], |_| true), 0);
+        for key in 0..14 {
+            let expected = values.iter().filter(|&&x| x > key).count();
+            assert_eq!(gallop_back(&values, |&x| x > key), expected);
+        }
+        assert_eq!(gallop_back(&[] as &[u32], |_| true), 0);
+        assert_eq!(min_run_length(63), 63);
+        assert_eq!(min_run_length(64), 32);
+        assert_eq!(min_run_length(65), 33);
+    }
+}
@@ -0,0 +1,229 @@
+use super::merge_sort::{merge_blocks, merge_sort_in};
+use super::probe::NoProbe;
+use std::cmp::Ordering;
+use std::thread;
+
+// Por debajo de este tamaño no compensa lanzar un hilo
+const SEQUENTIAL_LEN: usize = 4096;
+
+/// Opciones de [`parallel_merge_sort_by`].
+#[derive(Debug, Clone, Copy)]
+pub struct ParallelOptions {
+    /// Niveles de la recursión que se reparten entre hilos: con profundidad
+    /// `d` se usan hasta `2^d` hilos. Con 0 el ordenamiento es secuencial.
+    pub max_depth: usize,
+    /// Si la fusión de cada nivel también se reparte entre hilos. Sin ella,
+    /// la última fusión recorre todo el slice en un solo hilo.
+    pub parallel_merge: bool,
+}
+
+impl Default for ParallelOptions {
+    /// Tantos hilos como núcleos disponibles (redondeado a una potencia de
+    /// dos) y fusión paralela.
+    fn default() -> Self {
+        let cores = thread::available_parallelism().map_or(1, |n| n.get());
+        ParallelOptions {
+            max_depth: cores.next_power_of_two().trailing_zeros() as usize,
+            parallel_merge: true,
+        }
+    }
+}
+
+/// # Parallel Merge Sort
+///
+/// Versión paralela de [`merge_sort`](super::merge_sort::merge_sort) sobre
+/// hilos de `std::thread::scope`, con las opciones por defecto.
+///
+/// ## Pasos
+///
+/// 1. **División**: El slice se parte en dos mitades; la izquierda se ordena
+///    en un hilo nuevo y la derecha en el hilo actual. Se repite hasta
+///    `max_depth` niveles o hasta que las partes son pequeñas.
+/// 2. **Hojas**: Cada parte final se ordena con el merge sort secuencial.
+/// 3. **Fusión**: Las dos mitades se fusionan en un buffer auxiliar y se
+///    copian de vuelta. Con la fusión paralela, se toma el elemento central
+///    de la mitad más larga, se busca con búsqueda binaria dónde cae en la
+///    otra, y las dos fusiones resultantes (lo menor y lo mayor) se hacen en
+///    hilos distintos.
+///
+/// ## Características
+///
+/// - Produce exactamente el mismo resultado que la versión secuencial, y es
+///   igual de estable: los cortes de la fusión paralela mandan los empates
+///   de la mitad izquierda antes que los de la derecha
+/// - Memoria extra O(n): un solo buffer compartido por todos los hilos, cada
+///   uno trabajando sobre su propio trozo, también al ordenar las hojas
+/// - Los elementos y la comparación tienen que poder compartirse entre hilos
+///   (`Send` y `Sync`)
+///
+/// Para medir la diferencia con la versión secuencial sobre 10 millones de
+/// elementos: `cargo test --release bench_parallel_merge_sort -- --ignored --nocapture`
+pub fn parallel_merge_sort<T: Ord + Clone + Send + Sync>(arr: &mut [T]) {
+    parallel_merge_sort_by(arr, ParallelOptions::default(), T::cmp);
+}
+
+/// Igual que [`parallel_merge_sort`], con opciones y una función de
+/// comparación propias.
+pub fn parallel_merge_sort_by<T, F>(arr: &mut [T], options: ParallelOptions, compare: F)
+where
+    T: Clone + Send + Sync,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    if arr.len() < 2 {
+        return;
+    }
+    let mut buffer = arr.to_vec();
+    sort(arr, &mut buffer, options, options.max_depth, &compare);
+}
+
+// Ordena `arr` usando `buffer` (del mismo largo) como espacio auxiliar
+fn sort<T, F>(arr: &mut [T], buffer: &mut [T], options: ParallelOptions, depth: usize, compare: &F)
+where
+    T: Clone + Send + Sync,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    if depth == 0 || arr.len() < SEQUENTIAL_LEN {
+        merge_sort_in(arr, buffer, &mut |a, b| compare(a, b), &NoProbe);
+        return;
+    }
+
+    // La mitad izquierda se ordena en un hilo nuevo y la derecha en este
+    let middle = arr.len() / 2;
+    {
+        let (left, right) = arr.split_at_mut(middle);
+        let (left_buffer, right_buffer) = buffer.split_at_mut(middle);
+        thread::scope(|scope| {
+            scope.spawn(|| sort(left, left_buffer, options, depth - 1, compare));
+            sort(right, right_buffer, options, depth - 1, compare);
+        });
+    }
+
+    let (left, right) = arr.split_at(middle);
+    let merge_depth = if options.parallel_merge { depth } else { 0 };
+    merge(left, right, buffer, merge_depth, compare);
+    arr.clone_from_slice(buffer);
+}
+
+// Fusiona `left` y `right` en `writing`, repartiendo el trabajo entre hilos
+// hasta `depth` niveles
+fn merge<T, F>(left: &[T], right: &[T], writing: &mut [T], depth: usize, compare: &F)
+where
+    T: Clone + Send + Sync,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    if depth == 0 || writing.len() < SEQUENTIAL_LEN {
+        merge_blocks(left, right, writing, &mut |a, b| compare(a, b), &NoProbe);
+        return;
+    }
+
+    // Se parte por el elemento central de la mitad más larga. Para conservar
+    // la estabilidad, los iguales al pivote de `left` quedan siempre antes que
+    // los iguales de `right`
+    let (left_cut, right_cut) = if left.len() >= right.len() {
+        let cut = left.len() / 2;
+        let pivot = &left[cut];
+        (
+            cut,
+            right.partition_point(|x| compare(x, pivot) == Ordering::Less),
+        )
+    } else {
+        let cut = right.len() / 2;
+        let pivot = &right[cut];
+        (
+            left.partition_point(|x| compare(x, pivot) != Ordering::Greater),
+            cut,
+        )
+    };
+
+    let (low, high) = writing.split_at_mut(left_cut + right_cut);
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            merge(
+                &left[..left_cut],
+                &right[..right_cut],
+                low,
+                depth - 1,
+                compare,
+            )
+        });
+        merge(
+            &left[left_cut..],
+            &right[right_cut..],
+            high,
+            depth - 1,
+            compare,
+        );
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::merge_sort::{merge_sort_by, merge_sort_by_key};
+    use crate::algorithms::sorting::random_values;
+    use std::time::Instant;
+
+    #[test]
+    fn test_igual_a_secuencial() {
+        // Registros (clave, posición): con pocas claves distintas, cualquier
+        // error de estabilidad cambia el resultado
+        for (len, keys) in [(0, 1), (1, 1), (5_000, 3), (50_000, 100), (120_001, 7)] {
+            let records: Vec<(u32, usize)> = random_values(len, keys, len as u64)
+                .into_iter()
+                .zip(0..)
+                .collect();
+            let mut expected = records.clone();
+            merge_sort_by_key(&mut expected, |&(key, _)| key);
+
+            for max_depth in [0, 1, 3] {
+                for parallel_merge in [false, true] {
+                    let mut sorted = records.clone();
+                    let options = ParallelOptions {
+                        max_depth,
+                        parallel_merge,
+                    };
+                    parallel_merge_sort_by(&mut sorted, options, |a, b| a.0.cmp(&b.0));
+                    assert_eq!(sorted, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_opciones_por_defecto() {
+        let mut values = random_values(200_000, u32::MAX, 5);
+        let mut expected = values.clone();
+        expected.sort();
+
+        parallel_merge_sort(&mut values);
+        assert_eq!(values, expected);
+    }
+
+    /// Compara la versión secuencial y la paralela sobre 10 millones de
+    /// elementos. Conviene correrlo en modo release:
+    /// `cargo test --release bench_parallel_merge_sort -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn bench_parallel_merge_sort() {
+        let values = random_values(10_000_000, u32::MAX, 42);
+
+        let mut sequential = values.clone();
+        let started = Instant::now();
+        merge_sort_by(&mut sequential, u32::cmp);
+        let sequential_time = started.elapsed();
+
+        let options = ParallelOptions::default();
+        let mut parallel = values.clone();
+        let started = Instant::now();
+        parallel_merge_sort_by(&mut parallel, options, u32::cmp);
+        let parallel_time = started.elapsed();
+
+        assert_eq!(parallel, sequential);
+        println!("secuencial: {sequential_time:?}");
+        println!(
+            "paralelo ({} hilos): {parallel_time:?} ({:.2}x)",
+            1 << options.max_depth,
+            sequential_time.as_secs_f64() / parallel_time.as_secs_f64()
+        );
+    }
+}
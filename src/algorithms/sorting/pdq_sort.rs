@@ -0,0 +1,540 @@
+use super::heap_sort::heap_sort_by_less;
+use super::insertion_sort::{insertion_sort_by_less, shift_tail};
+use super::probe::{reverse, swap, NoProbe, Probe};
+use std::cmp::Ordering;
+use std::mem;
+
+// Por debajo de este tamaño se ordena por inserción
+const MAX_INSERTION: usize = 20;
+// Elementos por bloque en la partición; los desplazamientos entran en un u8
+const BLOCK: usize = 128;
+
+/// # Pattern-Defeating Quick Sort
+///
+/// Variante de Quick Sort de Orson Peters (2021), en la que se basó durante
+/// años el `sort_unstable` de la biblioteca estándar. Es tan rápida como Quick Sort en entradas
+/// aleatorias y aprovecha los patrones comunes de las entradas reales.
+///
+/// ## Pasos
+///
+/// 1. **Pivote**: Mediana de tres en slices medianos y pseudomediana de nueve
+///    (la mediana de tres medianas) en los grandes. Si al elegirlo no hubo
+///    que reordenar nada, la parte probablemente ya está ordenada; si hubo
+///    que reordenar todo, probablemente está invertida y se da vuelta.
+/// 2. **Detección de orden**: Cuando la parte parece ordenada se intenta un
+///    ordenamiento por inserción que se rinde tras unos pocos elementos
+///    fuera de lugar. Así las entradas ordenadas o invertidas cuestan O(n).
+/// 3. **Partición en bloques**: Se recorren bloques de 128 elementos de cada
+///    punta anotando qué posiciones hay que intercambiar, y recién después
+///    se intercambian. Las comparaciones no dependen de saltos condicionales,
+///    lo que evita los fallos de predicción del procesador.
+/// 4. **Muchos repetidos**: Si el pivote es igual al de la partición anterior,
+///    la parte está llena de iguales a él; se separan todos de una vez y no
+///    se vuelven a tocar.
+/// 5. **Particiones desbalanceadas**: Cuando una partición deja menos de un
+///    octavo de un lado, se mezclan algunos elementos para romper el patrón
+///    y se gasta un nivel del límite. Si el límite se agota, Heap Sort.
+///
+/// ## Características
+///
+/// - Tiempo O(n log n) en el peor caso y O(n) con entradas ordenadas,
+///   invertidas o con todos los elementos iguales
+/// - Sin memoria auxiliar ni `Clone`: solo intercambios
+/// - No es estable
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::pdq_sort::pdq_sort;
+///
+/// let mut numbers = vec![6, 2, 3, 9, 1, 0, 5, 8, 7, 1, 0, 4, 3, 1];
+/// pdq_sort(&mut numbers);
+/// assert_eq!(numbers, vec![0, 0, 1, 1, 1, 2, 3, 3, 4, 5, 6, 7, 8, 9]);
+/// ```
+///
+/// Para compararlo con Intro Sort y con `slice::sort_unstable` sobre varios
+/// patrones de entrada:
+/// `cargo test --release bench_unstable_sorts -- --ignored --nocapture`
+pub fn pdq_sort<T: Ord>(arr: &mut [T]) {
+    pdq_sort_by(arr, T::cmp);
+}
+
+/// Igual que [`pdq_sort`], pero con una función de comparación propia.
+pub fn pdq_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    pdq_sort_probed(arr, compare, &NoProbe);
+}
+
+/// Igual que [`pdq_sort_by`], avisando cada operación a `probe`.
+pub fn pdq_sort_probed<T, F, P>(arr: &mut [T], mut compare: F, probe: &P)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    let limit = usize::BITS - arr.len().leading_zeros();
+    let mut is_less = |a: &T, b: &T| {
+        probe.compare(a, b);
+        compare(a, b) == Ordering::Less
+    };
+    recurse(arr, &mut is_less, None, limit, probe);
+}
+
+// Ordena `arr`. `pred` es el pivote de la partición anterior, si la hubo, y
+// es menor o igual a todos los elementos de `arr`
+fn recurse<'a, T, F, P>(
+    mut arr: &'a mut [T],
+    is_less: &mut F,
+    mut pred: Option<&'a T>,
+    mut limit: u32,
+    probe: &P,
+) where
+    F: FnMut(&T, &T) -> bool,
+    P: Probe<T> + ?Sized,
+{
+    let mut was_balanced = true;
+    let mut was_partitioned = true;
+
+    loop {
+        let len = arr.len();
+        if len <= MAX_INSERTION {
+            insertion_sort_by_less(arr, is_less, probe);
+            return;
+        }
+        if limit == 0 {
+            heap_sort_by_less(arr, is_less, probe);
+            return;
+        }
+
+        if !was_balanced {
+            break_patterns(arr, probe);
+            limit -= 1;
+        }
+
+        let (pivot, likely_sorted) = choose_pivot(arr, is_less, probe);
+
+        // Si la partición anterior fue pareja y no movió nada, y el pivote
+        // sugiere orden, se intenta terminar con pocos desplazamientos
+        if was_balanced
+            && was_partitioned
+            && likely_sorted
+            && partial_insertion_sort(arr, is_less, probe)
+        {
+            return;
+        }
+
+        // Ningún elemento es menor que `pred`: si el pivote no es mayor,
+        // todos los menores o iguales al pivote son iguales entre sí
+        if let Some(pred) = pred {
+            if !is_less(pred, &arr[pivot]) {
+                let mid = partition_equal(arr, pivot, is_less, probe);
+                arr = &mut mem::take(&mut arr)[mid..];
+                continue;
+            }
+        }
+
+        let (mid, partitioned) = partition(arr, pivot, is_less, probe);
+        was_balanced = mid.min(len - mid) >= len / 8;
+        was_partitioned = partitioned;
+
+        // Recursión sobre la parte chica y ciclo sobre la grande
+        let (left, right) = mem::take(&mut arr).split_at_mut(mid);
+        let (pivot, right) = right.split_first_mut().unwrap();
+        if left.len() < right.len() {
+            recurse(left, is_less, pred, limit, probe);
+            arr = right;
+            pred = Some(pivot);
+        } else {
+            recurse(right, is_less, Some(pivot), limit, probe);
+            arr = left;
+        }
+    }
+}
+
+// Elige el pivote y devuelve su posición, y si la parte parece ordenada.
+// Si parece invertida, la da vuelta
+fn choose_pivot<T, F, P>(arr: &mut [T], is_less: &mut F, probe: &P) -> (usize, bool)
+where
+    F: FnMut(&T, &T) -> bool,
+    P: Probe<T> + ?Sized,
+{
+    // Desde este tamaño se usa la pseudomediana de nueve
+    const SHORTEST_NINTHER: usize = 50;
+    // Intercambios posibles: 3 por cada una de las 4 medianas de tres
+    const MAX_SWAPS: usize = 4 * 3;
+
+    let len = arr.len();
+    let mut a = len / 4;
+    let mut b = len / 4 * 2;
+    let mut c = len / 4 * 3;
+    let mut swaps = 0;
+
+    if len >= 8 {
+        // Ordena dos índices según los elementos a los que apuntan
+        let mut sort2 = |a: &mut usize, b: &mut usize| {
+            if is_less(&arr[*b], &arr[*a]) {
+                mem::swap(a, b);
+                swaps += 1;
+            }
+        };
+        // Deja en `b` el índice de la mediana de los tres
+        let mut sort3 = |a: &mut usize, b: &mut usize, c: &mut usize| {
+            sort2(a, b);
+            sort2(b, c);
+            sort2(a, b);
+        };
+
+        if len >= SHORTEST_NINTHER {
+            // Reemplaza `a` por la mediana de `a - 1`, `a` y `a + 1`
+            let mut sort_adjacent = |a: &mut usize| {
+                let (mut before, mut after) = (*a - 1, *a + 1);
+                sort3(&mut before, a, &mut after);
+            };
+            sort_adjacent(&mut a);
+            sort_adjacent(&mut b);
+            sort_adjacent(&mut c);
+        }
+        sort3(&mut a, &mut b, &mut c);
+    }
+
+    if swaps < MAX_SWAPS {
+        (b, swaps == 0)
+    } else {
+        // Todas las comparaciones dieron al revés: probablemente descendente
+        reverse(arr, probe);
+        (len - 1 - b, true)
+    }
+}
+
+// Ordena por inserción dejando pasar solo unos pocos elementos fuera de
+// lugar. Devuelve si el slice quedó ordenado
+fn partial_insertion_sort<T, F, P>(arr: &mut [T], is_less: &mut F, probe: &P) -> bool
+where
+    F: FnMut(&T, &T) -> bool,
+    P: Probe<T> + ?Sized,
+{
+    // Pares fuera de orden que se corrigen antes de rendirse
+    const MAX_STEPS: usize = 5;
+    // En slices más cortos no se corrige nada: la recursión es más barata
+    const SHORTEST_SHIFTING: usize = 50;
+
+    let len = arr.len();
+    let mut i = 1;
+    for _ in 0..MAX_STEPS {
+        while i < len && !is_less(&arr[i], &arr[i - 1]) {
+            i += 1;
+        }
+        if i == len {
+            return true;
+        }
+        if len < SHORTEST_SHIFTING {
+            return false;
+        }
+
+        // Se intercambia el par y cada uno se desplaza hasta su lugar
+        swap(arr, i - 1, i, probe);
+        shift_tail(&mut arr[..i], is_less, probe);
+        shift_head(&mut arr[i..], is_less, probe);
+    }
+    false
+}
+
+// Mueve el primer elemento hacia la derecha hasta su lugar, suponiendo que
+// el resto del slice ya está ordenado
+fn shift_head<T, F, P>(arr: &mut [T], is_less: &mut F, probe: &P)
+where
+    F: FnMut(&T, &T) -> bool,
+    P: Probe<T> + ?Sized,
+{
+    let mut i = 0;
+    while i + 1 < arr.len() && is_less(&arr[i + 1], &arr[i]) {
+        swap(arr, i, i + 1, probe);
+        i += 1;
+    }
+}
+
+// Mezcla tres elementos del centro con posiciones pseudoaleatorias, para
+// romper patrones que desbalancean las particiones
+fn break_patterns<T, P: Probe<T> + ?Sized>(arr: &mut [T], probe: &P) {
+    let len = arr.len();
+    if len < 8 {
+        return;
+    }
+
+    // Xorshift con semilla fija: el resultado es determinista
+    let mut seed = len as u64;
+    let mut next = || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed as usize
+    };
+
+    let mask = len.next_power_of_two() - 1;
+    let middle = len / 4 * 2;
+    for i in 0..3 {
+        let mut other = next() & mask;
+        if other >= len {
+            other -= len;
+        }
+        swap(arr, middle - 1 + i, other, probe);
+    }
+}
+
+// Deja los elementos iguales al pivote al principio, sabiendo que ninguno es
+// menor, y devuelve cuántos son
+fn partition_equal<T, F, P>(arr: &mut [T], pivot: usize, is_less: &mut F, probe: &P) -> usize
+where
+    F: FnMut(&T, &T) -> bool,
+    P: Probe<T> + ?Sized,
+{
+    swap(arr, 0, pivot, probe);
+    let (pivot, rest) = arr.split_first_mut().unwrap();
+
+    let mut l = 0;
+    let mut r = rest.len();
+    loop {
+        while l < r && !is_less(pivot, &rest[l]) {
+            l += 1;
+        }
+        while l < r && is_less(pivot, &rest[r - 1]) {
+            r -= 1;
+        }
+        if l >= r {
+            break;
+        }
+        r -= 1;
+        swap(rest, l, r, probe);
+        l += 1;
+    }
+    // Más el pivote
+    l + 1
+}
+
+// Particiona alrededor del pivote y devuelve su posición final, y si el
+// slice ya estaba particionado
+fn partition<T, F, P>(arr: &mut [T], pivot: usize, is_less: &mut F, probe: &P) -> (usize, bool)
+where
+    F: FnMut(&T, &T) -> bool,
+    P: Probe<T> + ?Sized,
+{
+    swap(arr, 0, pivot, probe);
+    let (pivot, rest) = arr.split_first_mut().unwrap();
+
+    // Se saltean las puntas que ya están de su lado
+    let mut l = 0;
+    let mut r = rest.len();
+    while l < r && is_less(&rest[l], pivot) {
+        l += 1;
+    }
+    while l < r && !is_less(&rest[r - 1], pivot) {
+        r -= 1;
+    }
+
+    let mid = l + partition_in_blocks(&mut rest[l..r], pivot, is_less, probe);
+    swap(arr, 0, mid, probe);
+    (mid, l >= r)
+}
+
+// Partición de Edelkamp y Weiß (BlockQuicksort): deja al principio los
+// menores que el pivote y devuelve cuántos son
+fn partition_in_blocks<T, F, P>(arr: &mut [T], pivot: &T, is_less: &mut F, probe: &P) -> usize
+where
+    F: FnMut(&T, &T) -> bool,
+    P: Probe<T> + ?Sized,
+{
+    // Bloque izquierdo: empieza en `l`. Los desplazamientos pendientes de
+    // intercambiar son `offsets_l[start_l..end_l]`
+    let mut l = 0;
+    let mut block_l = BLOCK;
+    let mut offsets_l = [0u8; BLOCK];
+    let (mut start_l, mut end_l) = (0, 0);
+
+    // Bloque derecho: termina en `r` y los desplazamientos se cuentan hacia
+    // la izquierda
+    let mut r = arr.len();
+    let mut block_r = BLOCK;
+    let mut offsets_r = [0u8; BLOCK];
+    let (mut start_r, mut end_r) = (0, 0);
+
+    loop {
+        // En la última vuelta, los bloques se achican para cubrir justo lo
+        // que falta, sin contar un bloque que todavía tenga pendientes
+        let is_done = r - l <= 2 * BLOCK;
+        if is_done {
+            let mut rem = r - l;
+            if start_l < end_l || start_r < end_r {
+                rem -= BLOCK;
+            }
+            if start_l < end_l {
+                block_r = rem;
+            } else if start_r < end_r {
+                block_l = rem;
+            } else {
+                block_l = rem / 2;
+                block_r = rem - block_l;
+            }
+        }
+
+        // Se anotan todas las posiciones, pero el final solo avanza con las
+        // que están del lado equivocado. Así no hay saltos que predecir
+        if start_l == end_l {
+            start_l = 0;
+            end_l = 0;
+            for i in 0..block_l {
+                offsets_l[end_l] = i as u8;
+                end_l += !is_less(&arr[l + i], pivot) as usize;
+            }
+        }
+        if start_r == end_r {
+            start_r = 0;
+            end_r = 0;
+            for i in 0..block_r {
+                offsets_r[end_r] = i as u8;
+                end_r += is_less(&arr[r - 1 - i], pivot) as usize;
+            }
+        }
+
+        let count = (end_l - start_l).min(end_r - start_r);
+        for _ in 0..count {
+            let left = l + offsets_l[start_l] as usize;
+            let right = r - 1 - offsets_r[start_r] as usize;
+            swap(arr, left, right, probe);
+            start_l += 1;
+            start_r += 1;
+        }
+
+        // Los bloques sin pendientes quedaron resueltos
+        if start_l == end_l {
+            l += block_l;
+        }
+        if start_r == end_r {
+            r -= block_r;
+        }
+        if is_done {
+            break;
+        }
+    }
+
+    // Queda a lo sumo un bloque con pendientes, y ya es lo único entre `l` y
+    // `r`: sus elementos mal ubicados se llevan a la otra punta
+    if start_l < end_l {
+        while start_l < end_l {
+            end_l -= 1;
+            r -= 1;
+            swap(arr, l + offsets_l[end_l] as usize, r, probe);
+        }
+        r
+    } else {
+        while start_r < end_r {
+            end_r -= 1;
+            swap(arr, l, r - 1 - offsets_r[end_r] as usize, probe);
+            l += 1;
+        }
+        l
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::intro_sort::intro_sort;
+    use crate::algorithms::sorting::random_values;
+    use std::time::{Duration, Instant};
+
+    fn patterns(len: usize) -> Vec<(&'static str, Vec<u32>)> {
+        vec![
+            ("aleatorio", random_values(len, u32::MAX, len as u64)),
+            ("pocos distintos", random_values(len, 8, len as u64)),
+            ("ordenado", (0..len as u32).collect()),
+            ("invertido", (0..len as u32).rev().collect()),
+            (
+                "diente de sierra",
+                (0..len as u32).map(|x| x % 1_000).collect(),
+            ),
+            ("casi ordenado", {
+                let mut values: Vec<u32> = (0..len as u32).collect();
+                let swaps = random_values(len / 100, len.max(1) as u32, 7);
+                for pair in swaps.chunks_exact(2) {
+                    values.swap(pair[0] as usize, pair[1] as usize);
+                }
+                values
+            }),
+            ("iguales", vec![42; len]),
+        ]
+    }
+
+    #[test]
+    fn test_contra_sort_unstable() {
+        for len in (0..100).chain([255, 256, 257, 1_000, 50_000]) {
+            for (_, mut values) in patterns(len) {
+                let mut expected = values.clone();
+                expected.sort_unstable();
+                pdq_sort(&mut values);
+                assert_eq!(values, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_patrones_lineales() {
+        // Ordenados, invertidos o todos iguales se resuelven con una pasada
+        let len = 100_000;
+        for (name, mut values) in patterns(len) {
+            if !matches!(name, "ordenado" | "invertido" | "iguales") {
+                continue;
+            }
+            let mut comparisons = 0;
+            pdq_sort_by(&mut values, |a, b| {
+                comparisons += 1;
+                a.cmp(b)
+            });
+            assert!(values.windows(2).all(|w| w[0] <= w[1]));
+            assert!(comparisons < 2 * len, "{name}: {comparisons}");
+        }
+    }
+
+    #[test]
+    fn test_partition_in_blocks() {
+        for len in [0, 1, 2, 127, 128, 129, 256, 257, 1_000] {
+            let mut values = random_values(len, 100, len as u64);
+            let smaller = values.iter().filter(|&&x| x < 50).count();
+            let mid = partition_in_blocks(&mut values, &50, &mut |a, b| a < b, &NoProbe);
+            assert_eq!(mid, smaller);
+            assert!(values[..mid].iter().all(|&x| x < 50));
+            assert!(values[mid..].iter().all(|&x| x >= 50));
+        }
+    }
+
+    /// Compara Intro Sort, PDQ Sort y `slice::sort_unstable` sobre varios
+    /// patrones de entrada. Conviene correrlo en modo release:
+    /// `cargo test --release bench_unstable_sorts -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn bench_unstable_sorts() {
+        fn time(values: &[u32], sort: fn(&mut [u32])) -> Duration {
+            let mut values = values.to_vec();
+            let started = Instant::now();
+            sort(&mut values);
+            let elapsed = started.elapsed();
+            assert!(values.windows(2).all(|w| w[0] <= w[1]));
+            elapsed
+        }
+
+        let len = 5_000_000;
+        println!(
+            "{:<18} {:>12} {:>12} {:>12}",
+            "patrón", "intro_sort", "pdq_sort", "sort_unstable"
+        );
+        for (name, values) in patterns(len) {
+            println!(
+                "{:<18} {:>12?} {:>12?} {:>12?}",
+                name,
+                time(&values, intro_sort),
+                time(&values, pdq_sort),
+                time(&values, <[u32]>::sort_unstable)
+            );
+        }
+    }
+}
@@ -0,0 +1,85 @@
+/// Observador de las operaciones de un ordenamiento.
+///
+/// Los ordenamientos de este módulo avisan a un `Probe` cada vez que
+/// comparan, intercambian o escriben un elemento, reservan memoria auxiliar
+/// o empiezan a fusionar dos bloques. Todos los métodos tienen una
+/// implementación vacía, así que cada observador implementa solo lo que le
+/// interesa. Las funciones públicas de cada algoritmo usan [`NoProbe`], que
+/// el compilador elimina por completo.
+///
+/// Los métodos reciben `&self` para que el observador se pueda compartir
+/// entre la función de comparación y el resto del algoritmo; quien necesite
+/// guardar estado usa `Cell` o `RefCell`.
+pub trait Probe<T> {
+    /// Se compararon `a` y `b`.
+    fn compare(&self, _a: &T, _b: &T) {}
+
+    /// Se intercambiaron `slice[i]` y `slice[j]`.
+    fn swap(&self, _slice: &[T], _i: usize, _j: usize) {}
+
+    /// Se escribió `slice[i]`, que ya tiene su valor nuevo.
+    fn write(&self, _slice: &[T], _i: usize) {}
+
+    /// Se reservaron `bytes` bytes de memoria auxiliar.
+    fn alloc(&self, _bytes: usize) {}
+
+    /// Se creó `buffer`, un buffer auxiliar de elementos. Las operaciones
+    /// siguientes pueden referirse a él.
+    fn buffer(&self, _buffer: &[T]) {}
+
+    /// Los bloques de `from` se van a fusionar en `to`, del mismo largo.
+    /// Pueden ser el mismo slice si la fusión es en el lugar.
+    fn merge(&self, _from: &[T], _to: &[T]) {}
+}
+
+/// Observador que no hace nada.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoProbe;
+
+impl<T> Probe<T> for NoProbe {}
+
+/// Intercambia `arr[i]` y `arr[j]` y avisa a `probe`.
+pub(super) fn swap<T, P: Probe<T> + ?Sized>(arr: &mut [T], i: usize, j: usize, probe: &P) {
+    arr.swap(i, j);
+    probe.swap(arr, i, j);
+}
+
+/// Escribe `value` en `arr[i]` y avisa a `probe`.
+pub(super) fn write<T, P: Probe<T> + ?Sized>(arr: &mut [T], i: usize, value: T, probe: &P) {
+    arr[i] = value;
+    probe.write(arr, i);
+}
+
+/// Copia `from` en `to`, del mismo largo, avisando cada escritura.
+pub(super) fn copy<T: Clone, P: Probe<T> + ?Sized>(from: &[T], to: &mut [T], probe: &P) {
+    to.clone_from_slice(from);
+    for i in 0..to.len() {
+        probe.write(to, i);
+    }
+}
+
+/// Invierte `arr` con intercambios, avisando cada uno.
+pub(super) fn reverse<T, P: Probe<T> + ?Sized>(arr: &mut [T], probe: &P) {
+    let len = arr.len();
+    for i in 0..len / 2 {
+        swap(arr, i, len - 1 - i, probe);
+    }
+}
+
+/// Copia `arr` a un buffer nuevo, avisando la reserva y las escrituras.
+pub(super) fn to_vec<T: Clone, P: Probe<T> + ?Sized>(arr: &[T], probe: &P) -> Vec<T> {
+    probe.alloc(std::mem::size_of_val(arr));
+    let buffer = arr.to_vec();
+    probe.buffer(&buffer);
+    for i in 0..buffer.len() {
+        probe.write(&buffer, i);
+    }
+    buffer
+}
+
+/// Escribe `value` en `arr[i]`, avisa a `probe` y devuelve el valor anterior.
+pub(super) fn replace<T, P: Probe<T> + ?Sized>(arr: &mut [T], i: usize, value: T, probe: &P) -> T {
+    let old = std::mem::replace(&mut arr[i], value);
+    probe.write(arr, i);
+    old
+}
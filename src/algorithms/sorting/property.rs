@@ -0,0 +1,453 @@
+use super::counting_sort::counting_sort_by_key;
+use super::instrument::Distribution;
+use super::radix_sort::radix_sort_by_key;
+use super::sorter::parallel_sorters;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Generador pseudoaleatorio congruencial lineal (las constantes de Knuth
+/// para MMIX). No sirve para criptografía, pero con la misma semilla da
+/// siempre la misma secuencia, que es lo que hace falta para reproducir un
+/// caso que falla. Es el único generador del crate: lo usan también
+/// [`Distribution::generate`], el pivote aleatorio de Quick Sort, la
+/// propagación de etiquetas y las pruebas de los demás módulos.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    /// Los bits bajos del estado tienen períodos cortos (el último alterna
+    /// entre 0 y 1), así que se mezclan con los altos.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self
+            .state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        self.state ^ (self.state >> 29)
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Un número en `[0, 1)`, con los 53 bits de precisión de un `f64`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Un número en `0..bound`. `bound` no puede ser 0.
+    pub fn below(&mut self, bound: usize) -> usize {
+        ((self.next_u32() as u64 * bound as u64) >> 32) as usize
+    }
+
+    /// Baraja `items` con el algoritmo de Fisher-Yates.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Elemento de las pruebas: la clave con la que se ordena y la posición que
+/// ocupaba en la entrada. La posición no participa de la comparación; sirve
+/// para ver si los iguales conservaron su orden y si no se perdió ni se
+/// duplicó ningún elemento.
+pub type Tagged = (u32, usize);
+
+/// Un ordenamiento de [`Tagged`] por su clave.
+pub type TaggedSort = Box<dyn Fn(&mut [Tagged])>;
+
+/// Un ordenamiento bajo prueba.
+pub struct Subject {
+    pub name: &'static str,
+    /// Si se le exige estabilidad.
+    pub stable: bool,
+    pub sort: TaggedSort,
+}
+
+/// Todos los ordenamientos que saben ordenar [`Tagged`] por su clave: los
+/// de [`parallel_sorters`], Counting Sort y Radix Sort.
+///
+/// Los de cadenas, Bucket Sort (`f64`) y el externo no entran, porque no
+/// pueden ordenar por una clave dejando de lado el resto del elemento.
+pub fn subjects() -> Vec<Subject> {
+    let mut subjects: Vec<Subject> = parallel_sorters()
+        .into_iter()
+        .map(|sorter| Subject {
+            name: sorter.name(),
+            stable: sorter.is_stable(),
+            sort: Box::new(move |arr| sorter.sort_by_sync(arr, &|a, b| a.0.cmp(&b.0))),
+        })
+        .collect();
+    subjects.push(Subject {
+        name: "counting",
+        stable: true,
+        sort: Box::new(|arr| counting_sort_by_key(arr, |&(key, _)| key as usize)),
+    });
+    subjects.push(Subject {
+        name: "radix",
+        stable: true,
+        sort: Box::new(|arr| radix_sort_by_key(arr, |&(key, _)| key)),
+    });
+    subjects
+}
+
+/// Propiedad que no se cumplió.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Property {
+    /// El ordenamiento entró en pánico.
+    Panic,
+    /// La salida no es una permutación de la entrada: falta o sobra algún
+    /// elemento.
+    Permutation,
+    /// Las claves no quedaron en el mismo orden que con `slice::sort`.
+    Order,
+    /// Los elementos con la misma clave no quedaron en el orden en que
+    /// estaban, como con `slice::sort`.
+    Stability,
+}
+
+impl fmt::Display for Property {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Property::Panic => "pánico",
+            Property::Permutation => "permutación",
+            Property::Order => "orden",
+            Property::Stability => "estabilidad",
+        };
+        f.pad(name)
+    }
+}
+
+/// Ordena `input` con `subject` y lo compara con `slice::sort`.
+pub fn check(subject: &Subject, input: &[u32]) -> Result<(), Property> {
+    let mut values: Vec<Tagged> = input.iter().copied().zip(0..).collect();
+    panic::catch_unwind(AssertUnwindSafe(|| (subject.sort)(&mut values)))
+        .map_err(|_| Property::Panic)?;
+
+    // Cada posición original tiene que aparecer una sola vez, con su clave
+    let mut seen = vec![false; input.len()];
+    for &(key, i) in &values {
+        if i >= input.len() || input[i] != key || seen[i] {
+            return Err(Property::Permutation);
+        }
+        seen[i] = true;
+    }
+    if values.len() != input.len() {
+        return Err(Property::Permutation);
+    }
+
+    // `slice::sort` es estable, así que también dice el orden de los iguales
+    let mut expected: Vec<Tagged> = input.iter().copied().zip(0..).collect();
+    expected.sort_by_key(|&(key, _)| key);
+    if !values.iter().map(|v| v.0).eq(expected.iter().map(|v| v.0)) {
+        return Err(Property::Order);
+    }
+    if subject.stable && values != expected {
+        return Err(Property::Stability);
+    }
+    Ok(())
+}
+
+/// Una entrada al azar de hasta `max_len` elementos.
+///
+/// El largo tiende a ser chico, donde aparecen la mayoría de los casos
+/// borde, y una de cada diez entradas está vacía y otra tiene un solo
+/// elemento. Los valores salen de una [`Distribution`] y a veces se
+/// reducen a pocas claves distintas o se desordenan con algunos
+/// intercambios.
+pub fn generate(rng: &mut Rng, max_len: usize) -> Vec<u32> {
+    let len = match rng.below(10) {
+        0 => 0,
+        1 => 1.min(max_len),
+        2 => rng.below(max_len.min(8) + 1),
+        _ => {
+            let bound = rng.below(max_len + 1) + 1;
+            rng.below(bound)
+        }
+    };
+    let distribution = Distribution::ALL[rng.below(Distribution::ALL.len())];
+    let mut values = distribution.generate(len, rng.next_u64());
+
+    if len > 0 && rng.below(3) == 0 {
+        let keys = rng.below(len) as u32 + 1;
+        values.iter_mut().for_each(|x| *x %= keys);
+    }
+    if len > 1 && rng.below(4) == 0 {
+        for _ in 0..rng.below(4) + 1 {
+            let (i, j) = (rng.below(len), rng.below(len));
+            values.swap(i, j);
+        }
+    }
+    values
+}
+
+/// Achica una entrada que hace fallar a `fails` mientras siga fallando:
+/// primero quita bloques de elementos, de todo el largo hasta de a uno,
+/// yendo por mitades, y después baja los valores hacia 0, todos juntos y de
+/// a uno. Repite hasta que ningún cambio mantiene la falla.
+pub fn shrink<F>(mut input: Vec<u32>, mut fails: F) -> Vec<u32>
+where
+    F: FnMut(&[u32]) -> bool,
+{
+    loop {
+        let mut shrunk = false;
+
+        let mut chunk = input.len();
+        while chunk > 0 {
+            let mut start = 0;
+            while start < input.len() {
+                let end = (start + chunk).min(input.len());
+                let candidate: Vec<u32> = [&input[..start], &input[end..]].concat();
+                if fails(&candidate) {
+                    input = candidate;
+                    shrunk = true;
+                } else {
+                    start += chunk;
+                }
+            }
+            chunk /= 2;
+        }
+
+        // Todos juntos a la mitad, para no romper los empates entre ellos
+        let halved: Vec<u32> = input.iter().map(|x| x / 2).collect();
+        if halved != input && fails(&halved) {
+            input = halved;
+            shrunk = true;
+        }
+
+        for i in 0..input.len() {
+            let value = input[i];
+            for smaller in [0, value / 2, value.saturating_sub(1)] {
+                if smaller >= input[i] {
+                    continue;
+                }
+                let mut candidate = input.clone();
+                candidate[i] = smaller;
+                if fails(&candidate) {
+                    input = candidate;
+                    shrunk = true;
+                    break;
+                }
+            }
+        }
+
+        if !shrunk {
+            return input;
+        }
+    }
+}
+
+/// Opciones de [`run`].
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    /// Semilla del primer caso; el caso `k` usa `seed + k`.
+    pub seed: u64,
+    /// Entradas al azar por ordenamiento.
+    pub cases: usize,
+    pub max_len: usize,
+}
+
+impl Default for Config {
+    /// 200 casos de hasta 300 elementos.
+    fn default() -> Self {
+        Config {
+            seed: 0,
+            cases: 200,
+            max_len: 300,
+        }
+    }
+}
+
+/// Un caso que falla, ya reducido.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub name: &'static str,
+    pub property: Property,
+    /// Semilla del caso: `generate(&mut Rng::new(seed), max_len)` vuelve a
+    /// dar la entrada original.
+    pub seed: u64,
+    pub original_len: usize,
+    /// La entrada reducida, que sigue fallando en `property`.
+    pub input: Vec<u32>,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} falla en {} con la semilla {} ({} elementos), reducida a {:?}",
+            self.name, self.property, self.seed, self.original_len, self.input
+        )
+    }
+}
+
+/// # Prueba diferencial
+///
+/// Compara cada ordenamiento de `subjects` con `slice::sort` sobre
+/// entradas al azar, y devuelve la primera falla reducida.
+///
+/// ## Pasos
+///
+/// 1. **Generación**: Para cada caso se arma una entrada con [`generate`] y
+///    la semilla del caso.
+/// 2. **Comparación**: Se ordena con [`check`], que revisa que la salida
+///    sea una permutación de la entrada, que las claves queden como con
+///    `slice::sort` y, si el ordenamiento es estable, que los iguales
+///    también.
+/// 3. **Reducción**: Si algo falla, se achica la entrada con [`shrink`]
+///    mientras siga fallando en la misma propiedad.
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::property::{
+///     run, subjects, Config, Property, Subject,
+/// };
+///
+/// let config = Config { seed: 1, cases: 20, max_len: 50 };
+/// assert_eq!(run(&subjects(), config), Ok(()));
+///
+/// // `sort_unstable` no conserva el orden de los iguales
+/// let unstable = Subject {
+///     name: "sort_unstable",
+///     stable: true,
+///     sort: Box::new(|arr| arr.sort_unstable_by_key(|&(key, _)| key)),
+/// };
+/// let failure = run(&[unstable], Config::default()).unwrap_err();
+/// assert_eq!(failure.property, Property::Stability);
+/// ```
+pub fn run(subjects: &[Subject], config: Config) -> Result<(), Failure> {
+    for subject in subjects {
+        for case in 0..config.cases as u64 {
+            let seed = config.seed.wrapping_add(case);
+            let input = generate(&mut Rng::new(seed), config.max_len);
+            if let Err(property) = check(subject, &input) {
+                let original_len = input.len();
+                let input = shrink(input, |candidate| {
+                    check(subject, candidate) == Err(property)
+                });
+                return Err(Failure {
+                    name: subject.name,
+                    property,
+                    seed,
+                    original_len,
+                    input,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subject(name: &'static str, stable: bool, sort: fn(&mut [Tagged])) -> Subject {
+        Subject {
+            name,
+            stable,
+            sort: Box::new(sort),
+        }
+    }
+
+    #[test]
+    fn test_todos_los_ordenamientos() {
+        let config = Config {
+            seed: 2024,
+            ..Config::default()
+        };
+        if let Err(failure) = run(&subjects(), config) {
+            panic!("{failure}");
+        }
+    }
+
+    #[test]
+    fn test_entradas() {
+        let mut rng = Rng::new(3);
+        let lens: Vec<usize> = (0..200).map(|_| generate(&mut rng, 100).len()).collect();
+        assert!(lens.contains(&0));
+        assert!(lens.contains(&1));
+        assert!(lens.iter().all(|&len| len <= 100));
+        assert!(lens.iter().any(|&len| len > 50));
+
+        // La misma semilla da la misma entrada
+        assert_eq!(
+            generate(&mut Rng::new(9), 100),
+            generate(&mut Rng::new(9), 100)
+        );
+    }
+
+    #[test]
+    fn test_detecta_cada_propiedad() {
+        // Ordena todo menos el último elemento
+        let partial = subject("parcial", false, |arr| {
+            let len = arr.len().saturating_sub(1);
+            arr[..len].sort();
+        });
+        let failure = run(&[partial], Config::default()).unwrap_err();
+        assert_eq!(failure.property, Property::Order);
+        assert_eq!(failure.input, vec![1, 0]);
+
+        // Pisa el primer elemento con el último
+        let lossy = subject("con pérdida", false, |arr| {
+            if let Some(&last) = arr.last() {
+                arr[0] = last;
+            }
+            arr.sort();
+        });
+        let failure = run(&[lossy], Config::default()).unwrap_err();
+        assert_eq!(failure.property, Property::Permutation);
+        assert_eq!(failure.input, vec![0, 0]);
+
+        // Invierte los iguales
+        let reversed = subject("inestable", true, |arr| {
+            arr.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+        });
+        let failure = run(&[reversed], Config::default()).unwrap_err();
+        assert_eq!(failure.property, Property::Stability);
+        assert_eq!(failure.input, vec![0, 0]);
+
+        let panics = subject("pánico", false, |arr| {
+            assert!(arr.len() < 10);
+            arr.sort();
+        });
+        let failure = run(&[panics], Config::default()).unwrap_err();
+        assert_eq!(failure.property, Property::Panic);
+        assert_eq!(failure.input, vec![0; 10]);
+    }
+
+    #[test]
+    fn test_shrink() {
+        // Falla si hay un 7 antes de un 3: lo mínimo es [7, 3]
+        let fails = |arr: &[u32]| {
+            arr.iter()
+                .position(|&x| x == 7)
+                .is_some_and(|i| arr[i..].contains(&3))
+        };
+        let input = vec![5, 7, 9, 1, 8, 3, 3, 0, 7, 2];
+        assert_eq!(shrink(input, fails), vec![7, 3]);
+        assert_eq!(shrink(vec![1, 2], fails), vec![1, 2]);
+    }
+
+    /// Muchos más casos y entradas más largas que las pruebas de siempre:
+    /// `cargo test --release stress_sorts -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn stress_sorts() {
+        let config = Config {
+            seed: 1,
+            cases: 5_000,
+            max_len: 2_000,
+        };
+        if let Err(failure) = run(&subjects(), config) {
+            panic!("{failure}");
+        }
+    }
+}
@@ -0,0 +1,519 @@
+use super::probe::{swap, NoProbe, Probe};
+use super::property::Rng;
+use std::cmp::Ordering;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::ops::Range;
+
+/// Esquema de partición de [`quick_sort_by`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Partition {
+    /// Lomuto: un solo índice recorre el slice y acumula los menores al
+    /// pivote al principio. Es el más simple, pero hace más intercambios y con
+    /// muchos repetidos degenera a O(n²).
+    Lomuto,
+    /// Hoare: dos índices avanzan desde los extremos y se cruzan
+    /// intercambiando los elementos que están del lado equivocado. Los iguales
+    /// al pivote frenan a ambos índices, así que los repetidos se reparten en
+    /// las dos mitades.
+    Hoare,
+    /// Dijkstra (bandera holandesa): divide en menores, iguales y mayores al
+    /// pivote. Los iguales quedan en su lugar definitivo, por lo que con muchos
+    /// repetidos el costo se acerca a O(n).
+    ThreeWay,
+    /// Yaroslavskiy: dos pivotes `p ≤ q` dividen en tres partes (`< p`,
+    /// entre ambos y `> q`). Es el que usa Java para sus tipos primitivos.
+    DualPivot,
+}
+
+/// Estrategia de elección del pivote de [`quick_sort_by`].
+///
+/// Con [`Partition::DualPivot`], `MedianOfThree` y `Ninther` toman como
+/// pivotes el segundo y el cuarto de una muestra ordenada de cinco elementos
+/// equiespaciados.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pivot {
+    /// El primer elemento. Con la entrada ya ordenada el costo es O(n²).
+    First,
+    /// Un elemento al azar: el caso O(n²) pasa a ser improbable para
+    /// cualquier entrada.
+    Random,
+    /// La mediana del primero, el del medio y el último.
+    MedianOfThree,
+    /// La mediana de tres medianas de tres (Tukey), sobre nueve elementos
+    /// equiespaciados; en slices cortos se usa la mediana de tres.
+    Ninther,
+}
+
+/// Opciones de [`quick_sort_by`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuickSortOptions {
+    pub partition: Partition,
+    pub pivot: Pivot,
+}
+
+impl Default for QuickSortOptions {
+    /// Partición de Hoare con mediana de tres.
+    fn default() -> Self {
+        QuickSortOptions {
+            partition: Partition::Hoare,
+            pivot: Pivot::MedianOfThree,
+        }
+    }
+}
+
+/// # Quick Sort
+///
+/// Ordena el slice en el lugar con Quick Sort y las opciones por defecto.
+///
+/// ## Funcionamiento
+///
+/// Quick Sort también sigue el paradigma de divide y vencerás, pero el
+/// trabajo se hace antes de dividir: se elige un pivote y se reordena el slice
+/// (partición) para que los menores queden a su izquierda y los mayores a su
+/// derecha. El pivote queda así en su posición final, y se ordena cada lado
+/// por separado.
+///
+/// ## Características
+///
+/// - Tiempo O(n log n) en promedio y O(n²) en el peor caso, que depende de
+///   la estrategia de pivote (ver [`Pivot`])
+/// - No usa memoria auxiliar: solo intercambios, así que no exige `Clone`
+/// - Siempre se sigue con la parte más grande en un ciclo y se llama
+///   recursivamente con las demás, lo que limita la pila a O(log n)
+/// - No es estable
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::quick_sort::{
+///     quick_sort, quick_sort_by, Partition, Pivot, QuickSortOptions,
+/// };
+///
+/// let mut numbers = vec![6, 2, 3, 9, 1, 0, 5, 8, 7, 1, 0, 4, 3, 1];
+/// quick_sort(&mut numbers);
+/// assert_eq!(numbers, vec![0, 0, 1, 1, 1, 2, 3, 3, 4, 5, 6, 7, 8, 9]);
+///
+/// let options = QuickSortOptions { partition: Partition::DualPivot, pivot: Pivot::Ninther };
+/// quick_sort_by(&mut numbers, options, |a, b| b.cmp(a));
+/// assert_eq!(numbers, vec![9, 8, 7, 6, 5, 4, 3, 3, 2, 1, 1, 1, 0, 0]);
+/// ```
+pub fn quick_sort<T: Ord>(arr: &mut [T]) {
+    quick_sort_by(arr, QuickSortOptions::default(), T::cmp);
+}
+
+/// Igual que [`quick_sort`], con opciones y una función de comparación propias.
+pub fn quick_sort_by<T, F>(arr: &mut [T], options: QuickSortOptions, compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    quick_sort_probed(arr, options, compare, &NoProbe);
+}
+
+/// Igual que [`quick_sort_by`], avisando cada operación a `probe`.
+pub fn quick_sort_probed<T, F, P>(
+    arr: &mut [T],
+    options: QuickSortOptions,
+    mut compare: F,
+    probe: &P,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    let mut compare = |a: &T, b: &T| {
+        probe.compare(a, b);
+        compare(a, b)
+    };
+    // El generador solo se usa con Pivot::Random; RandomState toma la
+    // semilla del sistema
+    let mut rng = Rng::new(RandomState::new().hash_one(arr.len()));
+    sort(arr, options, &mut rng, &mut compare, probe);
+}
+
+fn sort<T, F, P>(
+    mut arr: &mut [T],
+    options: QuickSortOptions,
+    rng: &mut Rng,
+    compare: &mut F,
+    probe: &P,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    while arr.len() > 1 {
+        let parts = match options.partition {
+            Partition::DualPivot => {
+                let (p, q) = choose_two_pivots(arr, options.pivot, rng, compare);
+                dual_pivot(arr, p, q, compare, probe)
+            }
+            scheme => {
+                let pivot = choose_pivot(arr, options.pivot, rng, compare);
+                let (left, right) = match scheme {
+                    Partition::Lomuto => lomuto(arr, pivot, compare, probe),
+                    Partition::Hoare => hoare(arr, pivot, compare, probe),
+                    _ => three_way(arr, pivot, compare, probe),
+                };
+                [left, right, 0..0]
+            }
+        };
+
+        // Recursión sobre las partes chicas y ciclo sobre la más grande
+        let largest = (0..parts.len()).max_by_key(|&i| parts[i].len()).unwrap();
+        for (i, part) in parts.iter().enumerate() {
+            if i != largest {
+                sort(&mut arr[part.clone()], options, rng, compare, probe);
+            }
+        }
+        arr = &mut std::mem::take(&mut arr)[parts[largest].clone()];
+    }
+}
+
+/// Índice del elemento mediano entre tres posiciones.
+pub(super) fn median_of_three<T, F>(
+    arr: &[T],
+    a: usize,
+    b: usize,
+    c: usize,
+    compare: &mut F,
+) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let less = |x: usize, y: usize, compare: &mut F| compare(&arr[x], &arr[y]) == Ordering::Less;
+    if less(a, b, compare) {
+        if less(b, c, compare) {
+            b
+        } else if less(a, c, compare) {
+            c
+        } else {
+            a
+        }
+    } else if less(a, c, compare) {
+        a
+    } else if less(b, c, compare) {
+        c
+    } else {
+        b
+    }
+}
+
+fn choose_pivot<T, F>(arr: &[T], strategy: Pivot, rng: &mut Rng, compare: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let last = arr.len() - 1;
+    let middle = arr.len() / 2;
+    match strategy {
+        Pivot::First => 0,
+        Pivot::Random => rng.below(arr.len()),
+        Pivot::Ninther if arr.len() >= 40 => {
+            let step = arr.len() / 8;
+            let low = median_of_three(arr, 0, step, 2 * step, compare);
+            let mid = median_of_three(arr, middle - step, middle, middle + step, compare);
+            let high = median_of_three(arr, last - 2 * step, last - step, last, compare);
+            median_of_three(arr, low, mid, high, compare)
+        }
+        Pivot::MedianOfThree | Pivot::Ninther => median_of_three(arr, 0, middle, last, compare),
+    }
+}
+
+// Dos pivotes distintos, devueltos de forma que arr[p] ≤ arr[q]
+fn choose_two_pivots<T, F>(
+    arr: &[T],
+    strategy: Pivot,
+    rng: &mut Rng,
+    compare: &mut F,
+) -> (usize, usize)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let last = arr.len() - 1;
+    let (p, q) = match strategy {
+        Pivot::First => (0, last),
+        Pivot::Random => {
+            let p = rng.below(arr.len());
+            // Un índice al azar entre los demás
+            let q = (p + 1 + rng.below(last)) % arr.len();
+            (p, q)
+        }
+        Pivot::MedianOfThree | Pivot::Ninther if arr.len() >= 5 => {
+            // Muestra de cinco posiciones equiespaciadas, ordenada por inserción
+            let step = arr.len() / 5;
+            let mut sample: Vec<usize> = (0..5).map(|i| i * step + step / 2).collect();
+            for i in 1..5 {
+                let mut j = i;
+                while j > 0 && compare(&arr[sample[j]], &arr[sample[j - 1]]) == Ordering::Less {
+                    sample.swap(j, j - 1);
+                    j -= 1;
+                }
+            }
+            (sample[1], sample[3])
+        }
+        Pivot::MedianOfThree | Pivot::Ninther => (0, last),
+    };
+
+    if compare(&arr[q], &arr[p]) == Ordering::Less {
+        (q, p)
+    } else {
+        (p, q)
+    }
+}
+
+// Partición de Lomuto. Retorna las partes a ordenar: [..pivote] y [pivote + 1..]
+fn lomuto<T, F, P>(
+    arr: &mut [T],
+    pivot: usize,
+    compare: &mut F,
+    probe: &P,
+) -> (Range<usize>, Range<usize>)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    let last = arr.len() - 1;
+    swap(arr, pivot, last, probe);
+
+    // arr[..store] son los menores al pivote encontrados hasta ahora
+    let mut store = 0;
+    for i in 0..last {
+        if compare(&arr[i], &arr[last]) == Ordering::Less {
+            swap(arr, i, store, probe);
+            store += 1;
+        }
+    }
+    swap(arr, store, last, probe);
+
+    (0..store, store + 1..arr.len())
+}
+
+/// Partición de Hoare, con el pivote al principio para dejarlo luego en su
+/// lugar final. Retorna las partes a ordenar, a cada lado del pivote.
+pub(super) fn hoare<T, F, P>(
+    arr: &mut [T],
+    pivot: usize,
+    compare: &mut F,
+    probe: &P,
+) -> (Range<usize>, Range<usize>)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    swap(arr, 0, pivot, probe);
+    let (mut i, mut j) = (0, arr.len());
+
+    loop {
+        // Avanzar i mientras el elemento sea menor al pivote
+        i += 1;
+        while i < arr.len() && compare(&arr[i], &arr[0]) == Ordering::Less {
+            i += 1;
+        }
+        // Retroceder j mientras el elemento sea mayor al pivote
+        j -= 1;
+        while j > 0 && compare(&arr[0], &arr[j]) == Ordering::Less {
+            j -= 1;
+        }
+        if i >= j {
+            break;
+        }
+        swap(arr, i, j, probe);
+    }
+    swap(arr, 0, j, probe);
+
+    (0..j, j + 1..arr.len())
+}
+
+/// Partición de Dijkstra. Retorna las partes de menores y mayores al pivote;
+/// los iguales quedan en el medio, ya ordenados.
+pub(super) fn three_way<T, F, P>(
+    arr: &mut [T],
+    pivot: usize,
+    compare: &mut F,
+    probe: &P,
+) -> (Range<usize>, Range<usize>)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    swap(arr, 0, pivot, probe);
+    // arr[..lt] < pivote, arr[lt..i] == pivote, arr[gt..] > pivote.
+    // arr[lt] siempre es igual al pivote, así que sirve para comparar
+    let (mut lt, mut i, mut gt) = (0, 1, arr.len());
+
+    while i < gt {
+        match compare(&arr[i], &arr[lt]) {
+            Ordering::Less => {
+                swap(arr, lt, i, probe);
+                lt += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                gt -= 1;
+                swap(arr, i, gt, probe);
+            }
+            Ordering::Equal => i += 1,
+        }
+    }
+
+    (0..lt, gt..arr.len())
+}
+
+// Partición de Yaroslavskiy con pivotes arr[p] ≤ arr[q]. Retorna las tres
+// partes: menores a p, entre p y q, y mayores a q
+fn dual_pivot<T, F, P>(
+    arr: &mut [T],
+    p: usize,
+    q: usize,
+    compare: &mut F,
+    probe: &P,
+) -> [Range<usize>; 3]
+where
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    let last = arr.len() - 1;
+    // Los pivotes van a los extremos; si q estaba en 0, el primer swap lo movió a p
+    swap(arr, 0, p, probe);
+    swap(arr, last, if q == 0 { p } else { q }, probe);
+
+    // arr[1..lt] < p, arr[lt..k] entre ambos, arr[gt + 1..last] > q
+    let (mut lt, mut k, mut gt) = (1, 1, last - 1);
+    let less_than_p =
+        |arr: &[T], i: usize, compare: &mut F| compare(&arr[i], &arr[0]) == Ordering::Less;
+    let greater_than_q =
+        |arr: &[T], i: usize, compare: &mut F| compare(&arr[i], &arr[last]) == Ordering::Greater;
+
+    while k <= gt && gt > 0 {
+        if less_than_p(arr, k, compare) {
+            swap(arr, k, lt, probe);
+            lt += 1;
+        } else if greater_than_q(arr, k, compare) {
+            while k < gt && greater_than_q(arr, gt, compare) {
+                gt -= 1;
+            }
+            swap(arr, k, gt, probe);
+            gt -= 1;
+            if less_than_p(arr, k, compare) {
+                swap(arr, k, lt, probe);
+                lt += 1;
+            }
+        }
+        k += 1;
+    }
+
+    // Los pivotes pasan a sus posiciones finales
+    lt -= 1;
+    gt += 1;
+    swap(arr, 0, lt, probe);
+    swap(arr, last, gt, probe);
+
+    [0..lt, lt + 1..gt, gt + 1..arr.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::random_values;
+
+    const PARTITIONS: [Partition; 4] = [
+        Partition::Lomuto,
+        Partition::Hoare,
+        Partition::ThreeWay,
+        Partition::DualPivot,
+    ];
+    const PIVOTS: [Pivot; 4] = [
+        Pivot::First,
+        Pivot::Random,
+        Pivot::MedianOfThree,
+        Pivot::Ninther,
+    ];
+
+    // Ordena contando comparaciones
+    fn comparisons(values: &mut [u32], partition: Partition, pivot: Pivot) -> usize {
+        let mut count = 0;
+        quick_sort_by(values, QuickSortOptions { partition, pivot }, |a, b| {
+            count += 1;
+            a.cmp(b)
+        });
+        count
+    }
+
+    #[test]
+    fn test_todas_las_combinaciones() {
+        for partition in PARTITIONS {
+            for pivot in PIVOTS {
+                for len in (0..60).chain([500, 2_001]) {
+                    let inputs = [
+                        random_values(len, u32::MAX, len as u64),
+                        random_values(len, 3, len as u64),
+                        (0..len as u32).collect(),
+                        (0..len as u32).rev().collect(),
+                        vec![7; len],
+                    ];
+                    for mut values in inputs {
+                        let mut expected = values.clone();
+                        expected.sort();
+                        quick_sort_by(&mut values, QuickSortOptions { partition, pivot }, u32::cmp);
+                        assert_eq!(values, expected, "{partition:?} con {pivot:?}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_particiones() {
+        let values = random_values(300, 20, 9);
+        for partition in [Partition::Lomuto, Partition::Hoare, Partition::ThreeWay] {
+            let mut arr = values.clone();
+            let pivot_value = arr[17];
+            let (left, right) = match partition {
+                Partition::Lomuto => lomuto(&mut arr, 17, &mut u32::cmp, &NoProbe),
+                Partition::Hoare => hoare(&mut arr, 17, &mut u32::cmp, &NoProbe),
+                _ => three_way(&mut arr, 17, &mut u32::cmp, &NoProbe),
+            };
+            // Todo lo que queda fuera de las partes es igual al pivote
+            assert!(arr[left.clone()].iter().all(|&x| x <= pivot_value));
+            assert!(arr[right.clone()].iter().all(|&x| x >= pivot_value));
+            assert!(arr[left.end..right.start].iter().all(|&x| x == pivot_value));
+        }
+
+        let mut arr = values.clone();
+        let [low, middle, high] = dual_pivot(&mut arr, 3, 40, &mut u32::cmp, &NoProbe);
+        let (p, q) = (arr[low.end], arr[high.start - 1]);
+        assert!(arr[low].iter().all(|&x| x < p));
+        assert!(arr[middle].iter().all(|&x| p <= x && x <= q));
+        assert!(arr[high].iter().all(|&x| x > q));
+    }
+
+    #[test]
+    fn test_costos() {
+        let n = 2_000;
+        let sorted: Vec<u32> = (0..n).collect();
+        let equal = vec![1; n as usize];
+
+        // El primer elemento como pivote sobre datos ordenados es el peor caso
+        let worst = comparisons(&mut sorted.clone(), Partition::Lomuto, Pivot::First);
+        assert!(worst >= (n * (n - 1) / 2) as usize);
+        let median = comparisons(&mut sorted.clone(), Partition::Lomuto, Pivot::MedianOfThree);
+        assert!(median < 40_000, "{median} comparaciones");
+
+        // Con todos iguales, Hoare reparte y Dijkstra termina en una pasada
+        let hoare = comparisons(&mut equal.clone(), Partition::Hoare, Pivot::First);
+        assert!(hoare < 40_000, "{hoare} comparaciones");
+        let three_way = comparisons(&mut equal.clone(), Partition::ThreeWay, Pivot::First);
+        assert_eq!(three_way, n as usize - 1);
+        let lomuto = comparisons(&mut equal.clone(), Partition::Lomuto, Pivot::Random);
+        assert!(lomuto >= (n * (n - 1) / 2) as usize);
+    }
+
+    #[test]
+    fn test_tipos_sin_clone() {
+        // Solo hacen falta intercambios: sirve para tipos que no implementan Clone
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Ticket(u32);
+
+        let mut tickets: Vec<Ticket> = random_values(100, 50, 4).into_iter().map(Ticket).collect();
+        quick_sort(&mut tickets);
+        assert!(tickets.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+}
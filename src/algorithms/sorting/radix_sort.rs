@@ -0,0 +1,460 @@
+use super::probe::{copy, to_vec, write, NoProbe, Probe};
+use std::mem;
+
+// Por debajo de este tamaño, el MSD ordena por inserción
+const MSD_INSERTION_LEN: usize = 32;
+// Cubetas del MSD: una por byte y una más para las cadenas que terminan
+const MSD_BUCKETS: usize = 257;
+
+/// Tipos que se pueden ordenar por dígitos: cada valor se traduce a un entero
+/// sin signo con el mismo orden.
+///
+/// - Los enteros sin signo se usan tal cual.
+/// - En los enteros con signo se invierte el bit de signo, así los negativos
+///   quedan antes que los positivos.
+/// - En los flotantes IEEE 754, los positivos se ordenan como sus bits con
+///   el bit de signo encendido, y los negativos con todos los bits
+///   invertidos (a mayor magnitud, menor valor). El orden resultante es el
+///   de `total_cmp`: `-NaN < -∞ < … < -0.0 < 0.0 < … < ∞ < NaN`.
+pub trait RadixKey: Copy {
+    /// Bytes significativos de la clave: cuántas pasadas hace el LSD.
+    const BYTES: usize;
+
+    fn radix_key(self) -> u64;
+}
+
+macro_rules! radix_key_unsigned {
+    ($($t:ty),*) => {$(
+        impl RadixKey for $t {
+            const BYTES: usize = std::mem::size_of::<$t>();
+
+            fn radix_key(self) -> u64 {
+                self as u64
+            }
+        }
+    )*};
+}
+
+macro_rules! radix_key_signed {
+    ($($t:ty => $u:ty),*) => {$(
+        impl RadixKey for $t {
+            const BYTES: usize = std::mem::size_of::<$t>();
+
+            fn radix_key(self) -> u64 {
+                (self as $u ^ (1 << (<$u>::BITS - 1))) as u64
+            }
+        }
+    )*};
+}
+
+macro_rules! radix_key_float {
+    ($($t:ty => $u:ty),*) => {$(
+        impl RadixKey for $t {
+            const BYTES: usize = std::mem::size_of::<$t>();
+
+            fn radix_key(self) -> u64 {
+                let bits = self.to_bits();
+                let sign = 1 << (<$u>::BITS - 1);
+                let key = if bits & sign == 0 { bits | sign } else { !bits };
+                key as u64
+            }
+        }
+    )*};
+}
+
+radix_key_unsigned!(u8, u16, u32, u64, usize);
+radix_key_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, isize => usize);
+radix_key_float!(f32 => u32, f64 => u64);
+
+/// # Radix Sort (LSD)
+///
+/// Ordenamiento sin comparaciones para enteros y flotantes: se ordena de a un
+/// byte, desde el menos significativo hasta el más significativo.
+///
+/// ## Pasos
+///
+/// 1. **Histogramas**: En una sola pasada se cuenta, para cada byte de la
+///    clave, cuántos elementos tienen cada uno de los 256 valores posibles.
+/// 2. **Distribución**: Para cada byte, empezando por el menos significativo,
+///    los elementos se copian a un buffer auxiliar agrupados por el valor de
+///    ese byte, en el orden en que aparecen. Como cada pasada es estable, el
+///    orden de los bytes anteriores se conserva dentro de cada grupo.
+/// 3. **Pasadas triviales**: Si todos los elementos comparten el valor de un
+///    byte, esa pasada se salta. Con marcas de tiempo, que suelen compartir
+///    los bytes altos, esto ahorra buena parte del trabajo.
+///
+/// ## Características
+///
+/// - Tiempo O(n · b), con `b` la cantidad de bytes de la clave
+/// - Memoria extra O(n) para el buffer
+/// - Estable
+/// - Ver [`RadixKey`] para el orden de negativos y flotantes
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::radix_sort::radix_sort;
+///
+/// let mut numbers = vec![170, -45, 75, -90, 802, 24, 2, 66];
+/// radix_sort(&mut numbers);
+/// assert_eq!(numbers, vec![-90, -45, 2, 24, 66, 75, 170, 802]);
+///
+/// let mut floats = vec![2.5, -0.5, 1e-9, -3.0, 0.0];
+/// radix_sort(&mut floats);
+/// assert_eq!(floats, vec![-3.0, -0.5, 0.0, 1e-9, 2.5]);
+/// ```
+///
+/// Para compararlo con los ordenamientos por comparación sobre 10 millones
+/// de marcas de tiempo `u64`:
+/// `cargo test --release bench_radix_sort -- --ignored --nocapture`
+pub fn radix_sort<T: RadixKey>(arr: &mut [T]) {
+    radix_sort_by_key(arr, |&x| x);
+}
+
+/// Igual que [`radix_sort`], ordenando por la clave que devuelve `key`.
+pub fn radix_sort_by_key<T, K, F>(arr: &mut [T], key: F)
+where
+    T: Clone,
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+{
+    radix_sort_probed(arr, key, &NoProbe);
+}
+
+/// Igual que [`radix_sort_by_key`], avisando cada operación a `probe`.
+pub fn radix_sort_probed<T, K, F, P>(arr: &mut [T], mut key: F, probe: &P)
+where
+    T: Clone,
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+    P: Probe<T> + ?Sized,
+{
+    let len = arr.len();
+    if len < 2 {
+        return;
+    }
+
+    probe.alloc(K::BYTES * mem::size_of::<[usize; 256]>());
+    let mut counts = vec![[0usize; 256]; K::BYTES];
+    for item in arr.iter() {
+        let k = key(item).radix_key();
+        for (byte, count) in counts.iter_mut().enumerate() {
+            count[digit(k, byte)] += 1;
+        }
+    }
+
+    let mut buffer = to_vec(arr, probe);
+    let mut in_buffer = false;
+    for (byte, count) in counts.iter().enumerate() {
+        if count.contains(&len) {
+            continue;
+        }
+
+        // Dónde empieza cada grupo en el destino
+        let mut offsets = [0usize; 256];
+        let mut sum = 0;
+        for (offset, &count) in offsets.iter_mut().zip(count) {
+            *offset = sum;
+            sum += count;
+        }
+
+        if in_buffer {
+            scatter(&buffer, arr, &mut offsets, byte, &mut key, probe);
+        } else {
+            scatter(arr, &mut buffer, &mut offsets, byte, &mut key, probe);
+        }
+        in_buffer = !in_buffer;
+    }
+
+    if in_buffer {
+        copy(&buffer, arr, probe);
+    }
+}
+
+fn digit(key: u64, byte: usize) -> usize {
+    (key >> (8 * byte)) as usize & 0xff
+}
+
+// Copia `from` en `to` agrupando por el byte `byte` de la clave
+fn scatter<T, K, F, P>(
+    from: &[T],
+    to: &mut [T],
+    offsets: &mut [usize; 256],
+    byte: usize,
+    key: &mut F,
+    probe: &P,
+) where
+    T: Clone,
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+    P: Probe<T> + ?Sized,
+{
+    for item in from {
+        let d = digit(key(item).radix_key(), byte);
+        write(to, offsets[d], item.clone(), probe);
+        offsets[d] += 1;
+    }
+}
+
+/// # Radix Sort (MSD) para cadenas de bytes
+///
+/// Ordena cadenas en orden lexicográfico de bytes (el mismo que `Ord` para
+/// `str` y `[u8]`), empezando por el primer byte.
+///
+/// ## Pasos
+///
+/// 1. **Cubetas**: Se reparten las cadenas en 257 cubetas según el byte en
+///    la posición actual: una para las que ya terminaron y una por cada
+///    valor de byte. El reparto se hace en el lugar, intercambiando cada
+///    cadena hasta su cubeta (American Flag Sort).
+/// 2. **Cubetas pendientes**: Cada cubeta, salvo la de las terminadas (que
+///    son todas iguales), queda en una pila de trabajo para ordenarse por el
+///    byte siguiente.
+/// 3. **Partes chicas**: Con menos de 32 cadenas se ordena por inserción,
+///    comparando desde la posición actual.
+///
+/// ## Características
+///
+/// - Solo lee los bytes necesarios para distinguir cada cadena de las demás
+/// - Sin memoria auxiliar ni `Clone`: solo intercambios
+/// - No es estable (las cadenas con los mismos bytes son iguales)
+/// - Es iterativo: un prefijo común largo solo agranda la pila de trabajo,
+///   que está en el heap y tiene a lo sumo 255 cubetas por byte del prefijo
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::radix_sort::msd_radix_sort;
+///
+/// let mut words = vec!["sol", "sal", "", "solar", "árbol", "Sol", "so"];
+/// msd_radix_sort(&mut words);
+/// assert_eq!(words, vec!["", "Sol", "sal", "so", "sol", "solar", "árbol"]);
+/// ```
+pub fn msd_radix_sort<T: AsRef<[u8]>>(arr: &mut [T]) {
+    msd_sort(arr, 0);
+}
+
+/// Cubeta de `item` en la posición `depth`: 0 si ya terminó y, si no, el
+/// byte más uno.
+pub(super) fn bucket<T: AsRef<[u8]>>(item: &T, depth: usize) -> usize {
+    item.as_ref().get(depth).map_or(0, |&b| b as usize + 1)
+}
+
+// Ordena `arr`, sabiendo que todas las cadenas comparten los primeros
+// `depth` bytes
+fn msd_sort<T: AsRef<[u8]>>(arr: &mut [T], depth: usize) {
+    // Partes pendientes: rango del slice y bytes que ya comparten
+    let mut pending = vec![(0, arr.len(), depth)];
+    while let Some((start, end, depth)) = pending.pop() {
+        let part = &mut arr[start..end];
+        if part.len() < MSD_INSERTION_LEN {
+            insertion_sort_from(part, depth);
+            continue;
+        }
+
+        let mut counts = [0usize; MSD_BUCKETS];
+        for item in part.iter() {
+            counts[bucket(item, depth)] += 1;
+        }
+        let mut starts = [0usize; MSD_BUCKETS];
+        let mut sum = 0;
+        for (start, &count) in starts.iter_mut().zip(&counts) {
+            *start = sum;
+            sum += count;
+        }
+
+        // `next[b]` es la primera posición de la cubeta `b` que todavía no
+        // tiene una cadena suya; cada intercambio deja una en su lugar definitivo
+        let mut next = starts;
+        for b in 0..MSD_BUCKETS {
+            let end = starts[b] + counts[b];
+            while next[b] < end {
+                let target = bucket(&part[next[b]], depth);
+                if target == b {
+                    next[b] += 1;
+                } else {
+                    part.swap(next[b], next[target]);
+                    next[target] += 1;
+                }
+            }
+        }
+
+        for b in 1..MSD_BUCKETS {
+            if counts[b] > 1 {
+                let bucket_start = start + starts[b];
+                pending.push((bucket_start, bucket_start + counts[b], depth + 1));
+            }
+        }
+    }
+}
+
+/// Ordenamiento por inserción de cadenas que comparten los primeros `depth`
+/// bytes, comparando solo desde ahí.
+pub(super) fn insertion_sort_from<T: AsRef<[u8]>>(arr: &mut [T], depth: usize) {
+    for i in 1..arr.len() {
+        let mut j = i;
+        while j > 0 && arr[j].as_ref()[depth..] < arr[j - 1].as_ref()[depth..] {
+            arr.swap(j, j - 1);
+            j -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::merge_sort::merge_sort;
+    use crate::algorithms::sorting::property::Rng;
+    use std::cmp::Ordering;
+    use std::time::Instant;
+
+    // Valores con los 64 bits al azar, para que cada pasada tenga trabajo
+    fn random_bits(len: usize, seed: u64) -> Vec<u64> {
+        let mut rng = Rng::new(seed);
+        (0..len).map(|_| rng.next_u64()).collect()
+    }
+
+    fn check<T: RadixKey + PartialEq + std::fmt::Debug>(
+        values: Vec<T>,
+        compare: impl FnMut(&T, &T) -> Ordering,
+    ) {
+        let mut expected = values.clone();
+        expected.sort_by(compare);
+        let mut sorted = values;
+        radix_sort(&mut sorted);
+        assert_eq!(sorted, expected);
+    }
+
+    #[test]
+    fn test_enteros() {
+        for len in [0, 1, 2, 100, 10_000] {
+            let values = random_bits(len, len as u64);
+            check(values.iter().map(|&x| x as u8).collect(), u8::cmp);
+            check(values.iter().map(|&x| x as u16).collect(), u16::cmp);
+            check(values.iter().map(|&x| x as u32).collect(), u32::cmp);
+            check(values.clone(), u64::cmp);
+            check(values.iter().map(|&x| x as usize).collect(), usize::cmp);
+            check(values.iter().map(|&x| x as i8).collect(), i8::cmp);
+            check(values.iter().map(|&x| x as i32).collect(), i32::cmp);
+            check(values.iter().map(|&x| x as i64).collect(), i64::cmp);
+        }
+        check(vec![i64::MAX, 0, -1, i64::MIN, 1], i64::cmp);
+    }
+
+    #[test]
+    fn test_flotantes() {
+        // Se comparan los bits, para distinguir -0.0 de 0.0 y los NaN
+        let mut values: Vec<f64> = random_bits(5_000, 1)
+            .into_iter()
+            .map(|x| f64::from_bits(x) % 1e6)
+            .collect();
+        values.extend([
+            0.0,
+            -0.0,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+            -f64::NAN,
+            f64::MIN_POSITIVE,
+            -f64::MIN_POSITIVE,
+        ]);
+        let mut expected = values.clone();
+        expected.sort_by(f64::total_cmp);
+        let mut sorted = values.clone();
+        radix_sort(&mut sorted);
+        let bits = |v: &[f64]| v.iter().map(|x| x.to_bits()).collect::<Vec<_>>();
+        assert_eq!(bits(&sorted), bits(&expected));
+
+        let mut singles: Vec<f32> = values.iter().map(|&x| x as f32).collect();
+        let mut expected: Vec<f32> = singles.clone();
+        expected.sort_by(f32::total_cmp);
+        radix_sort(&mut singles);
+        let bits = |v: &[f32]| v.iter().map(|x| x.to_bits()).collect::<Vec<_>>();
+        assert_eq!(bits(&singles), bits(&expected));
+    }
+
+    #[test]
+    fn test_estabilidad() {
+        let records: Vec<(i16, usize)> = random_bits(3_000, 9)
+            .into_iter()
+            .map(|x| x as i16 % 50)
+            .zip(0..)
+            .collect();
+        let mut expected = records.clone();
+        expected.sort_by_key(|&(key, _)| key);
+        let mut sorted = records;
+        radix_sort_by_key(&mut sorted, |&(key, _)| key);
+        assert_eq!(sorted, expected);
+    }
+
+    #[test]
+    fn test_msd_cadenas() {
+        // Alfabeto chico para que haya muchos prefijos compartidos
+        let words: Vec<String> = random_bits(5_000, 3)
+            .into_iter()
+            .map(|x| {
+                let len = (x % 12) as usize;
+                (0..len)
+                    .map(|i| ["a", "b", "ñ"][(x >> (i * 2 + 8)) as usize % 3])
+                    .collect()
+            })
+            .collect();
+        let mut expected = words.clone();
+        expected.sort();
+        let mut sorted = words;
+        msd_radix_sort(&mut sorted);
+        assert_eq!(sorted, expected);
+
+        let mut bytes: Vec<Vec<u8>> = vec![vec![2, 0], vec![], vec![2], vec![0, 255], vec![0]];
+        msd_radix_sort(&mut bytes);
+        assert_eq!(
+            bytes,
+            vec![vec![], vec![0], vec![0, 255], vec![2], vec![2, 0]]
+        );
+    }
+
+    #[test]
+    fn test_msd_prefijo_largo() {
+        // Cada byte del prefijo común es un nivel más: con recursión, 5000
+        // niveles desbordaban la pila
+        let prefix = "x".repeat(5_000);
+        let mut values: Vec<String> = (0..40)
+            .map(|i| format!("{prefix}{}", (i * 17) % 40))
+            .collect();
+        let mut expected = values.clone();
+        expected.sort();
+        msd_radix_sort(&mut values);
+        assert_eq!(values, expected);
+    }
+
+    /// Compara Radix Sort con Merge Sort y `slice::sort_unstable` sobre 10
+    /// millones de marcas de tiempo en nanosegundos a lo largo de un día.
+    /// Conviene correrlo en modo release:
+    /// `cargo test --release bench_radix_sort -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn bench_radix_sort() {
+        const DAY_NS: u64 = 86_400_000_000_000;
+        let start = 1_700_000_000_000_000_000;
+        let values: Vec<u64> = random_bits(10_000_000, 42)
+            .into_iter()
+            .map(|x| start + x % DAY_NS)
+            .collect();
+
+        let mut timings = Vec::new();
+        for (name, sort) in [
+            ("radix_sort", radix_sort::<u64> as fn(&mut [u64])),
+            ("merge_sort", merge_sort::<u64>),
+            ("sort_unstable", <[u64]>::sort_unstable),
+        ] {
+            let mut sorted = values.clone();
+            let started = Instant::now();
+            sort(&mut sorted);
+            timings.push((name, started.elapsed()));
+            assert!(sorted.windows(2).all(|w| w[0] <= w[1]));
+        }
+        for (name, elapsed) in timings {
+            println!("{name:<14} {elapsed:?}");
+        }
+    }
+}
@@ -0,0 +1,384 @@
+use super::insertion_sort::insertion_sort_by_less;
+use super::pdq_sort::pdq_sort_by;
+use super::probe::NoProbe;
+use super::quick_sort::{median_of_three, three_way};
+use crate::structures::binary_heap::comparator::{Comparator, Min, Reverse};
+use crate::structures::binary_heap::heap::BinaryHeap;
+use std::cmp::Ordering;
+
+// Por debajo de este tamaño se ordena por inserción
+const INSERTION_LEN: usize = 16;
+
+// Elementos que pueden recorrer en total las particiones de Quickselect, por
+// cada elemento de la entrada
+const WORK_FACTOR: usize = 4;
+
+/// # Selección del n-ésimo (Introselect)
+///
+/// Reordena `arr` para que en la posición `n` quede el elemento que estaría
+/// ahí si el slice estuviera ordenado, con los menores o iguales antes y los
+/// mayores o iguales después, y lo devuelve. Sirve para medianas y
+/// percentiles sin ordenar todo.
+///
+/// ## Pasos
+///
+/// 1. **Quickselect**: Se particiona en tres (menores, iguales y mayores al
+///    pivote, elegido con la mediana de tres) y se sigue solo con la parte
+///    que contiene la posición `n`. Si `n` cae entre los iguales, terminó.
+/// 2. **Presupuesto**: Cada partición gasta tantas unidades como elementos
+///    recorre, de un presupuesto de `4 · n` para toda la selección (la regla
+///    de trabajo acumulado de Musser).
+/// 3. **Mediana de medianas**: Agotado el presupuesto, el pivote pasa a ser
+///    la mediana de las medianas de grupos de cinco (Blum, Floyd, Pratt,
+///    Rivest y Tarjan, 1973), que garantiza descartar al menos un 30% en
+///    cada paso.
+///
+/// ## Características
+///
+/// - Tiempo O(n) en promedio, como Quickselect, y también O(n) en el peor
+///   caso: las particiones de Quickselect recorren a lo sumo `4 · n`
+///   elementos, y lo que queda se resuelve en tiempo lineal con la mediana
+///   de medianas
+/// - Sin memoria auxiliar ni `Clone`: solo intercambios
+///
+/// # Panics
+///
+/// Si `n >= arr.len()`.
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::selection::select_nth;
+///
+/// // Percentil 90 de latencias en milisegundos, por rango más cercano: el
+/// // noveno de diez
+/// let mut latencies = vec![12, 250, 8, 31, 19, 7, 44, 15, 980, 23];
+/// let p90 = latencies.len() * 9 / 10 - 1;
+/// assert_eq!(*select_nth(&mut latencies, p90), 250);
+/// assert!(latencies[..p90].iter().all(|&x| x <= 250));
+/// ```
+pub fn select_nth<T: Ord>(arr: &mut [T], n: usize) -> &T {
+    select_nth_by(arr, n, T::cmp)
+}
+
+/// Igual que [`select_nth`], pero con una función de comparación propia.
+pub fn select_nth_by<T, F>(arr: &mut [T], n: usize, mut compare: F) -> &T
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    assert!(
+        n < arr.len(),
+        "posición {n} fuera de rango (largo {})",
+        arr.len()
+    );
+    let budget = WORK_FACTOR * arr.len();
+    select(arr, n, budget, &mut compare);
+    &arr[n]
+}
+
+// Deja en `n` el elemento que le corresponde. Cuando al `budget` no le
+// alcanza para recorrer la parte actual, y en particular si es 0, los
+// pivotes pasan a ser medianas de medianas
+fn select<T, F>(arr: &mut [T], mut n: usize, mut budget: usize, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let (mut start, mut end) = (0, arr.len());
+    loop {
+        let part = &mut arr[start..end];
+        let len = part.len();
+        if len <= INSERTION_LEN {
+            insertion_sort_by_less(part, &mut |a, b| compare(a, b) == Ordering::Less, &NoProbe);
+            return;
+        }
+
+        let pivot = if budget >= len {
+            budget -= len;
+            median_of_three(part, 0, len / 2, len - 1, compare)
+        } else {
+            median_of_medians(part, compare)
+        };
+        let (less, greater) = three_way(part, pivot, compare, &NoProbe);
+
+        let next = if n < less.end {
+            less
+        } else if n >= greater.start {
+            greater
+        } else {
+            return;
+        };
+        n -= next.start;
+        start += next.start;
+        end = start + next.len();
+    }
+}
+
+// Mueve al principio la mediana de cada grupo de cinco y devuelve la
+// posición de la mediana de todas ellas
+fn median_of_medians<T, F>(arr: &mut [T], compare: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // La posición `group` cae en un grupo que ya se recorrió, así que el
+    // intercambio no pisa ninguna mediana pendiente
+    let groups = arr.len() / 5;
+    for group in 0..groups {
+        let five = &mut arr[5 * group..5 * group + 5];
+        insertion_sort_by_less(five, &mut |a, b| compare(a, b) == Ordering::Less, &NoProbe);
+        arr.swap(group, 5 * group + 2);
+    }
+
+    let middle = groups / 2;
+    select(&mut arr[..groups], middle, 0, compare);
+    middle
+}
+
+/// # Ordenamiento parcial
+///
+/// Deja ordenados los `k` menores elementos al principio de `arr`; el resto
+/// queda en un orden cualquiera. Con `k` mayor que el largo, ordena todo.
+///
+/// ## Pasos
+///
+/// 1. **Selección**: [`select_nth`] deja en la posición `k - 1` el k-ésimo
+///    menor, con todos los menores antes.
+/// 2. **Orden**: Se ordenan solo esos primeros `k - 1` con
+///    [`pdq_sort`](super::pdq_sort::pdq_sort).
+///
+/// ## Características
+///
+/// - Tiempo O(n + k log k)
+/// - No es estable
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::selection::partial_sort;
+///
+/// let mut numbers = vec![9, 4, 7, 1, 8, 2, 6, 3, 5];
+/// partial_sort(&mut numbers, 3);
+/// assert_eq!(numbers[..3], [1, 2, 3]);
+/// ```
+pub fn partial_sort<T: Ord>(arr: &mut [T], k: usize) {
+    partial_sort_by(arr, k, T::cmp);
+}
+
+/// Igual que [`partial_sort`], pero con una función de comparación propia.
+pub fn partial_sort_by<T, F>(arr: &mut [T], k: usize, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let k = k.min(arr.len());
+    if k == 0 {
+        return;
+    }
+    select_nth_by(arr, k - 1, &mut compare);
+    pdq_sort_by(&mut arr[..k - 1], compare);
+}
+
+/// Adaptador de iteradores que se queda con los `k` mayores elementos.
+pub trait TopKExt: Iterator + Sized {
+    /// # Top k
+    ///
+    /// Los `k` mayores elementos del iterador, de mayor a menor. Consume el
+    /// iterador entero en la primera llamada a `next`, pero sin guardarlo:
+    /// usa un [`BinaryHeap`] de mínimos de a lo sumo `k` elementos, cuya
+    /// cima es el menor de los candidatos. Cada elemento nuevo que supera a
+    /// la cima la reemplaza.
+    ///
+    /// ## Características
+    ///
+    /// - Tiempo O(n log k) y memoria O(k), sin importar el largo de la
+    ///   entrada
+    /// - Entre iguales no se garantiza cuál queda
+    ///
+    /// ## Ejemplo de Uso
+    ///
+    /// ```rust
+    /// use algoritmos_y_estructuras::algorithms::sorting::selection::TopKExt;
+    ///
+    /// let latencies = [12, 250, 8, 31, 19, 7, 44, 15, 980, 23];
+    /// let slowest: Vec<u32> = latencies.into_iter().top_k(3).collect();
+    /// assert_eq!(slowest, vec![980, 250, 44]);
+    /// ```
+    fn top_k(self, k: usize) -> TopK<Self, Min>
+    where
+        Self::Item: Ord,
+    {
+        TopK::new(self, k, Min)
+    }
+
+    /// Igual que [`top_k`](TopKExt::top_k), pero con una función de
+    /// comparación propia.
+    fn top_k_by<F>(self, k: usize, compare: F) -> TopK<Self, Reverse<F>>
+    where
+        F: Fn(&Self::Item, &Self::Item) -> Ordering,
+    {
+        TopK::new(self, k, Reverse(compare))
+    }
+}
+
+impl<I: Iterator> TopKExt for I {}
+
+/// Iterador de [`TopKExt::top_k`] y [`TopKExt::top_k_by`].
+pub struct TopK<I: Iterator, C> {
+    source: Option<(I, BinaryHeap<I::Item, C>)>,
+    k: usize,
+    sorted: std::vec::IntoIter<I::Item>,
+}
+
+impl<I: Iterator, C: Comparator<I::Item>> TopK<I, C> {
+    // `comparator` ordena el montículo de candidatos: la cima es el menor
+    fn new(iter: I, k: usize, comparator: C) -> Self {
+        TopK {
+            source: Some((iter, BinaryHeap::with_comparator(comparator))),
+            k,
+            sorted: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl<I: Iterator, C: Comparator<I::Item>> Iterator for TopK<I, C> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if let Some((iter, mut heap)) = self.source.take() {
+            if self.k > 0 {
+                for item in iter {
+                    if heap.len() < self.k {
+                        heap.push(item);
+                    } else if heap
+                        .peek()
+                        .is_some_and(|top| heap.comparator().compare(&item, top) == Ordering::Less)
+                    {
+                        heap.pop();
+                        heap.push(item);
+                    }
+                }
+            }
+            // En un montículo de mínimos, el orden ascendente del comparador
+            // es de mayor a menor
+            self.sorted = heap.into_sorted_vec().into_iter();
+        }
+        self.sorted.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::random_values;
+
+    fn check_selected(values: &[u32], n: usize, sorted: &[u32]) {
+        assert_eq!(values[n], sorted[n]);
+        assert!(values[..n].iter().all(|&x| x <= sorted[n]));
+        assert!(values[n..].iter().all(|&x| x >= sorted[n]));
+    }
+
+    #[test]
+    fn test_select_nth() {
+        for len in [1, 2, 17, 100, 1_000] {
+            for modulo in [3, u32::MAX] {
+                let values = random_values(len, modulo, len as u64);
+                let mut sorted = values.clone();
+                sorted.sort();
+                for n in (0..len).step_by(len / 17 + 1).chain([len - 1]) {
+                    let mut selected = values.clone();
+                    assert_eq!(*select_nth(&mut selected, n), sorted[n]);
+                    check_selected(&selected, n, &sorted);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_mediana_de_medianas() {
+        // Sin presupuesto, todos los pivotes son medianas de medianas
+        let inputs = [
+            random_values(10_000, u32::MAX, 1),
+            random_values(10_000, 5, 2),
+            (0..10_000).collect(),
+            (0..10_000).rev().collect(),
+        ];
+        for values in inputs {
+            let mut sorted = values.clone();
+            sorted.sort();
+            for n in [0, 1, 4_999, 5_000, 9_999] {
+                let mut selected = values.clone();
+                select(&mut selected, n, 0, &mut u32::cmp);
+                check_selected(&selected, n, &sorted);
+            }
+        }
+    }
+
+    #[test]
+    fn test_comparaciones_lineales() {
+        // Con el presupuesto de trabajo las comparaciones no pasan de un múltiplo
+        // de n en ninguna entrada, incluidas las que arruinan la mediana de tres
+        let len = 20_000;
+        let organ_pipe: Vec<u32> = (0..len / 2).chain((0..len / 2).rev()).collect();
+        let inputs = [
+            random_values(len as usize, u32::MAX, 4),
+            (0..len).collect(),
+            (0..len).rev().collect(),
+            organ_pipe,
+            vec![7; len as usize],
+        ];
+        for values in inputs {
+            for n in [0, len as usize / 3, len as usize / 2, len as usize - 1] {
+                let mut comparisons = 0;
+                let mut selected = values.clone();
+                select_nth_by(&mut selected, n, |a, b| {
+                    comparisons += 1;
+                    a.cmp(b)
+                });
+                assert!(
+                    comparisons <= 20 * len as usize,
+                    "{comparisons} comparaciones"
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_select_nth_fuera_de_rango() {
+        select_nth(&mut [1, 2, 3], 3);
+    }
+
+    #[test]
+    fn test_partial_sort() {
+        let values = random_values(1_000, 100, 3);
+        let mut sorted = values.clone();
+        sorted.sort();
+        for k in [0, 1, 2, 10, 999, 1_000, 5_000] {
+            let mut partial = values.clone();
+            partial_sort(&mut partial, k);
+            let k = k.min(values.len());
+            assert_eq!(partial[..k], sorted[..k]);
+        }
+
+        let mut words = vec!["kiwi", "banana", "uva", "pera", "manzana"];
+        partial_sort_by(&mut words, 2, |a, b| b.len().cmp(&a.len()));
+        assert_eq!(words[..2], ["manzana", "banana"]);
+    }
+
+    #[test]
+    fn test_top_k() {
+        let values = random_values(5_000, 1_000, 4);
+        let mut expected = values.clone();
+        expected.sort_by(|a, b| b.cmp(a));
+        for k in [0, 1, 10, 4_999, 5_000, 10_000] {
+            let top: Vec<u32> = values.iter().copied().top_k(k).collect();
+            assert_eq!(top, expected[..k.min(5_000)]);
+        }
+
+        // Los más cortos primero: se invierte el orden de la comparación
+        let words = ["kiwi", "banana", "uva", "peras", "manzana", "sandía"];
+        let shortest: Vec<&str> = words
+            .into_iter()
+            .top_k_by(2, |a, b| b.len().cmp(&a.len()))
+            .collect();
+        assert_eq!(shortest, ["uva", "kiwi"]);
+    }
+}
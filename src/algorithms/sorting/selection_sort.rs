@@ -0,0 +1,90 @@
+use super::probe::{swap, NoProbe, Probe};
+use std::cmp::Ordering;
+
+/// # Selection Sort
+///
+/// En cada pasada busca el menor de los elementos que faltan y lo lleva a
+/// su lugar definitivo.
+///
+/// ## Pasos
+///
+/// 1. **Selección**: Se recorre la parte sin ordenar buscando el menor.
+/// 2. **Intercambio**: El menor se intercambia con el primero de la parte
+///    sin ordenar, que se achica en uno.
+///
+/// ## Características
+///
+/// - Tiempo O(n²) siempre: hace `n(n - 1)/2` comparaciones aunque la
+///   entrada ya esté ordenada
+/// - A lo sumo `n - 1` intercambios, uno por pasada: conviene cuando mover
+///   es mucho más caro que comparar. Ver también
+///   [`cycle_sort`](super::cycle_sort::cycle_sort)
+/// - Sin memoria auxiliar ni `Clone`
+/// - No es estable: el intercambio puede pasar un elemento por encima de
+///   otro igual
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::selection_sort::selection_sort;
+///
+/// let mut numbers = vec![64, 25, 12, 22, 11];
+/// selection_sort(&mut numbers);
+/// assert_eq!(numbers, vec![11, 12, 22, 25, 64]);
+/// ```
+pub fn selection_sort<T: Ord>(arr: &mut [T]) {
+    selection_sort_by(arr, T::cmp);
+}
+
+/// Igual que [`selection_sort`], pero con una función de comparación propia.
+pub fn selection_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    selection_sort_probed(arr, compare, &NoProbe);
+}
+
+/// Igual que [`selection_sort_by`], avisando cada operación a `probe`.
+pub fn selection_sort_probed<T, F, P>(arr: &mut [T], mut compare: F, probe: &P)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    for i in 0..arr.len().saturating_sub(1) {
+        let mut min = i;
+        for j in i + 1..arr.len() {
+            probe.compare(&arr[j], &arr[min]);
+            if compare(&arr[j], &arr[min]) == Ordering::Less {
+                min = j;
+            }
+        }
+        if min != i {
+            swap(arr, i, min, probe);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::instrument::{Counters, Distribution};
+
+    #[test]
+    fn test_selection_sort() {
+        for distribution in Distribution::ALL {
+            for len in [0, 1, 2, 3, 50, 300] {
+                let mut values = distribution.generate(len, 11);
+                let mut expected = values.clone();
+                expected.sort();
+
+                let counters = Counters::new();
+                selection_sort_probed(&mut values, u32::cmp, &counters);
+                assert_eq!(values, expected);
+
+                let counts = counters.counts();
+                assert_eq!(counts.comparisons, len * len.saturating_sub(1) / 2);
+                assert!(counts.swaps < len.max(1));
+            }
+        }
+    }
+}
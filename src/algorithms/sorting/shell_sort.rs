@@ -0,0 +1,186 @@
+use super::probe::{swap, NoProbe, Probe};
+use std::cmp::Ordering;
+
+/// Secuencia de saltos de [`shell_sort_by`]. Por defecto, la de Ciura.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Gaps {
+    /// La original de Shell (1959): `n/2, n/4, ..., 1`. Con saltos que son
+    /// potencias de dos, las posiciones pares e impares no se mezclan hasta
+    /// el final, y el peor caso es O(n²).
+    Shell,
+    /// Knuth (1973): `1, 4, 13, 40, ...`, cada uno `3h + 1`, menores que
+    /// `n/3`. El peor caso es O(n^1.5).
+    Knuth,
+    /// Ciura (2001): `1, 4, 10, 23, 57, 132, 301, 701, 1750`, encontrada
+    /// experimentalmente, y después cada salto multiplicado por 2,25. No hay
+    /// una cota conocida, pero en la práctica es la que menos compara.
+    #[default]
+    Ciura,
+}
+
+impl Gaps {
+    /// Los saltos para un slice de `len` elementos, de mayor a menor. El
+    /// último siempre es 1, salvo que `len` sea menor que 2.
+    pub fn sequence(self, len: usize) -> Vec<usize> {
+        if len < 2 {
+            return Vec::new();
+        }
+        let mut gaps = Vec::new();
+        match self {
+            Gaps::Shell => {
+                let mut gap = len / 2;
+                while gap > 0 {
+                    gaps.push(gap);
+                    gap /= 2;
+                }
+                return gaps;
+            }
+            Gaps::Knuth => {
+                let mut gap = 1;
+                while gap == 1 || gap < len.div_ceil(3) {
+                    gaps.push(gap);
+                    gap = 3 * gap + 1;
+                }
+            }
+            Gaps::Ciura => {
+                const CIURA: [usize; 9] = [1, 4, 10, 23, 57, 132, 301, 701, 1750];
+                gaps.extend(CIURA.iter().copied().take_while(|&gap| gap < len));
+                if gaps.len() == CIURA.len() {
+                    let mut gap = CIURA[CIURA.len() - 1] * 9 / 4;
+                    while gap < len {
+                        gaps.push(gap);
+                        gap = gap * 9 / 4;
+                    }
+                }
+            }
+        }
+        gaps.reverse();
+        gaps
+    }
+}
+
+/// # Shell Sort
+///
+/// Generalización de [`insertion_sort`](super::insertion_sort::insertion_sort)
+/// que empieza comparando elementos lejanos (Shell, 1959): así los que están
+/// muy fuera de lugar avanzan de a saltos grandes en lugar de uno por uno.
+///
+/// ## Pasos
+///
+/// 1. **Saltos**: Se elige una secuencia decreciente de saltos que termina en
+///    1 (ver [`Gaps`]).
+/// 2. **Inserción con salto**: Para cada salto `h`, se ordenan por inserción
+///    las subsecuencias de elementos a distancia `h`. Al final el slice
+///    queda "h-ordenado".
+/// 3. **Último paso**: Con `h = 1` es una inserción común, pero a esa altura
+///    cada elemento está cerca de su lugar y se mueve poco.
+///
+/// ## Características
+///
+/// - La complejidad depende de la secuencia de saltos: O(n²) en el peor caso
+///   con la de Shell, O(n^1.5) con la de Knuth
+/// - Sin memoria auxiliar ni `Clone`: solo intercambios
+/// - No es estable: los saltos pasan elementos por encima de otros iguales
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::shell_sort::{shell_sort, shell_sort_by, Gaps};
+///
+/// let mut numbers = vec![35, 33, 42, 10, 14, 19, 27, 44];
+/// shell_sort(&mut numbers);
+/// assert_eq!(numbers, vec![10, 14, 19, 27, 33, 35, 42, 44]);
+///
+/// shell_sort_by(&mut numbers, Gaps::Knuth, |a, b| b.cmp(a));
+/// assert_eq!(numbers, vec![44, 42, 35, 33, 27, 19, 14, 10]);
+/// assert_eq!(Gaps::Knuth.sequence(100), vec![13, 4, 1]);
+/// ```
+pub fn shell_sort<T: Ord>(arr: &mut [T]) {
+    shell_sort_by(arr, Gaps::default(), T::cmp);
+}
+
+/// Igual que [`shell_sort`], con una secuencia de saltos y una función de
+/// comparación propias.
+pub fn shell_sort_by<T, F>(arr: &mut [T], gaps: Gaps, compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    shell_sort_probed(arr, gaps, compare, &NoProbe);
+}
+
+/// Igual que [`shell_sort_by`], avisando cada operación a `probe`.
+pub fn shell_sort_probed<T, F, P>(arr: &mut [T], gaps: Gaps, mut compare: F, probe: &P)
+where
+    F: FnMut(&T, &T) -> Ordering,
+    P: Probe<T> + ?Sized,
+{
+    for gap in gaps.sequence(arr.len()) {
+        for i in gap..arr.len() {
+            // Inserción en la subsecuencia i, i - gap, i - 2·gap, ...
+            let mut j = i;
+            while j >= gap {
+                probe.compare(&arr[j], &arr[j - gap]);
+                if compare(&arr[j], &arr[j - gap]) != Ordering::Less {
+                    break;
+                }
+                swap(arr, j, j - gap, probe);
+                j -= gap;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::instrument::{Counters, Distribution};
+
+    #[test]
+    fn test_secuencias() {
+        assert_eq!(Gaps::Shell.sequence(20), vec![10, 5, 2, 1]);
+        assert_eq!(Gaps::Knuth.sequence(20), vec![4, 1]);
+        assert_eq!(Gaps::Knuth.sequence(2), vec![1]);
+        assert_eq!(Gaps::Ciura.sequence(60), vec![57, 23, 10, 4, 1]);
+        assert_eq!(Gaps::Ciura.sequence(10_000)[..3], [8858, 3937, 1750]);
+        for gaps in [Gaps::Shell, Gaps::Knuth, Gaps::Ciura] {
+            assert!(gaps.sequence(1).is_empty());
+            for len in 2..200 {
+                let sequence = gaps.sequence(len);
+                assert_eq!(sequence.last(), Some(&1));
+                assert!(sequence.windows(2).all(|w| w[0] > w[1]));
+                assert!(sequence[0] < len);
+            }
+        }
+    }
+
+    #[test]
+    fn test_shell_sort() {
+        for gaps in [Gaps::Shell, Gaps::Knuth, Gaps::Ciura] {
+            for distribution in Distribution::ALL {
+                for len in [0, 1, 2, 3, 10, 100, 1_000] {
+                    let mut values = distribution.generate(len, 3);
+                    let mut expected = values.clone();
+                    expected.sort();
+                    shell_sort_by(&mut values, gaps, u32::cmp);
+                    assert_eq!(values, expected, "{gaps:?} con {distribution}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_ciura_compara_menos() {
+        let comparisons = |gaps| {
+            let mut values = Distribution::Random.generate(10_000, 5);
+            let counters = Counters::new();
+            shell_sort_probed(&mut values, gaps, u32::cmp, &counters);
+            counters.counts().comparisons
+        };
+        let (shell, knuth, ciura) = (
+            comparisons(Gaps::Shell),
+            comparisons(Gaps::Knuth),
+            comparisons(Gaps::Ciura),
+        );
+        assert!(ciura < knuth && ciura < shell, "{shell} {knuth} {ciura}");
+    }
+}
@@ -0,0 +1,438 @@
+use super::cocktail_sort::cocktail_sort_probed;
+use super::comb_sort::comb_sort_probed;
+use super::cycle_sort::cycle_sort_probed;
+use super::gnome_sort::gnome_sort_probed;
+use super::heap_sort::heap_sort_probed;
+use super::insertion_sort::{binary_insertion_sort_probed, insertion_sort_probed};
+use super::intro_sort::intro_sort_probed;
+use super::merge_sort::merge_sort_probed;
+use super::natural_merge_sort::natural_merge_sort_probed;
+use super::parallel_merge_sort::{parallel_merge_sort_by, ParallelOptions};
+use super::pdq_sort::pdq_sort_probed;
+use super::probe::{NoProbe, Probe};
+use super::quick_sort::{quick_sort_probed, Partition, Pivot, QuickSortOptions};
+use super::selection_sort::selection_sort_probed;
+use super::shell_sort::{shell_sort_probed, Gaps};
+use std::cmp::Ordering;
+use std::sync::mpsc;
+use std::thread;
+
+/// Interfaz común de los ordenamientos por comparación, para recorrerlos
+/// todos sin nombrar cada función.
+///
+/// Cada algoritmo es un tipo (casi siempre sin campos) que implementa este
+/// trait llamando a su función `*_probed`. Como la comparación y el
+/// observador se reciben como objetos de trait, un `Sorter` se puede guardar
+/// en un `Box<dyn Sorter<T>>`; ver [`sorters`], [`cloning_sorters`] y
+/// [`parallel_sorters`], según lo que se pueda exigir de `T`.
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::sorter::{sorters, Sorter};
+///
+/// for sorter in sorters::<i32>() {
+///     let mut numbers = vec![5, -1, 3, 0, 3];
+///     sorter.sort_by(&mut numbers, &mut |a, b| b.cmp(a));
+///     assert_eq!(numbers, vec![5, 3, 3, 0, -1], "{}", sorter.name());
+/// }
+/// ```
+pub trait Sorter<T> {
+    /// Nombre corto del algoritmo, con sus opciones entre paréntesis si
+    /// las tiene.
+    fn name(&self) -> &'static str;
+
+    /// Si los elementos iguales conservan su orden relativo.
+    fn is_stable(&self) -> bool;
+
+    /// Si ordena sin un buffer auxiliar proporcional a la entrada.
+    fn is_in_place(&self) -> bool;
+
+    /// Ordena `arr` según `compare`, avisando cada operación a `probe`.
+    fn sort_probed(
+        &self,
+        arr: &mut [T],
+        compare: &mut dyn FnMut(&T, &T) -> Ordering,
+        probe: &dyn Probe<T>,
+    );
+
+    /// Ordena `arr` según `compare`.
+    fn sort_by(&self, arr: &mut [T], compare: &mut dyn FnMut(&T, &T) -> Ordering) {
+        self.sort_probed(arr, compare, &NoProbe);
+    }
+
+    /// Ordena `arr` según `compare`, que se puede compartir entre hilos. Los
+    /// ordenamientos paralelos la reparten entre sus hilos; los demás la usan
+    /// igual que en [`Sorter::sort_by`].
+    fn sort_by_sync(&self, arr: &mut [T], compare: &(dyn Fn(&T, &T) -> Ordering + Sync)) {
+        self.sort_by(arr, &mut |a, b| compare(a, b));
+    }
+
+    /// Ordena `arr` de menor a mayor.
+    fn sort(&self, arr: &mut [T])
+    where
+        T: Ord,
+    {
+        self.sort_by_sync(arr, &T::cmp);
+    }
+}
+
+// Un tipo sin campos por algoritmo, que delega en su función `*_probed`
+macro_rules! sorter {
+    ($(
+        $(#[$doc:meta])*
+        $sorter:ident [$($bound:ident)?] => $sort:ident,
+        $name:literal, stable: $stable:literal, in_place: $in_place:literal;
+    )*) => {$(
+        $(#[$doc])*
+        #[derive(Debug, Default, Clone, Copy)]
+        pub struct $sorter;
+
+        impl<T $(: $bound)?> Sorter<T> for $sorter {
+            fn name(&self) -> &'static str {
+                $name
+            }
+
+            fn is_stable(&self) -> bool {
+                $stable
+            }
+
+            fn is_in_place(&self) -> bool {
+                $in_place
+            }
+
+            fn sort_probed(
+                &self,
+                arr: &mut [T],
+                compare: &mut dyn FnMut(&T, &T) -> Ordering,
+                probe: &dyn Probe<T>,
+            ) {
+                $sort(arr, compare, probe);
+            }
+        }
+    )*};
+}
+
+sorter! {
+    /// [`merge_sort`](super::merge_sort::merge_sort).
+    MergeSort [Clone] => merge_sort_probed,
+        "merge", stable: true, in_place: false;
+    /// [`natural_merge_sort`](super::natural_merge_sort::natural_merge_sort).
+    NaturalMergeSort [Clone] => natural_merge_sort_probed,
+        "natural_merge", stable: true, in_place: false;
+    /// [`intro_sort`](super::intro_sort::intro_sort).
+    IntroSort [] => intro_sort_probed,
+        "intro", stable: false, in_place: true;
+    /// [`pdq_sort`](super::pdq_sort::pdq_sort).
+    PdqSort [] => pdq_sort_probed,
+        "pdq", stable: false, in_place: true;
+    /// [`heap_sort`](super::heap_sort::heap_sort).
+    HeapSort [] => heap_sort_probed,
+        "heap", stable: false, in_place: true;
+    /// [`insertion_sort`](super::insertion_sort::insertion_sort).
+    InsertionSort [] => insertion_sort_probed,
+        "insertion", stable: true, in_place: true;
+    /// [`binary_insertion_sort`](super::insertion_sort::binary_insertion_sort).
+    BinaryInsertionSort [] => binary_insertion_sort_probed,
+        "binary_insertion", stable: true, in_place: true;
+    /// [`selection_sort`](super::selection_sort::selection_sort).
+    SelectionSort [] => selection_sort_probed,
+        "selection", stable: false, in_place: true;
+    /// [`cocktail_sort`](super::cocktail_sort::cocktail_sort).
+    CocktailSort [] => cocktail_sort_probed,
+        "cocktail", stable: true, in_place: true;
+    /// [`comb_sort`](super::comb_sort::comb_sort).
+    CombSort [] => comb_sort_probed,
+        "comb", stable: false, in_place: true;
+    /// [`gnome_sort`](super::gnome_sort::gnome_sort).
+    GnomeSort [] => gnome_sort_probed,
+        "gnome", stable: true, in_place: true;
+    /// [`cycle_sort`](super::cycle_sort::cycle_sort).
+    CycleSort [Clone] => cycle_sort_probed,
+        "cycle", stable: false, in_place: true;
+}
+
+/// [`quick_sort`](super::quick_sort::quick_sort) con las opciones dadas. El
+/// nombre solo muestra la partición.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct QuickSort(pub QuickSortOptions);
+
+impl<T> Sorter<T> for QuickSort {
+    fn name(&self) -> &'static str {
+        match self.0.partition {
+            Partition::Lomuto => "quick (Lomuto)",
+            Partition::Hoare => "quick (Hoare)",
+            Partition::ThreeWay => "quick (3 vías)",
+            Partition::DualPivot => "quick (2 pivotes)",
+        }
+    }
+
+    fn is_stable(&self) -> bool {
+        false
+    }
+
+    fn is_in_place(&self) -> bool {
+        true
+    }
+
+    fn sort_probed(
+        &self,
+        arr: &mut [T],
+        compare: &mut dyn FnMut(&T, &T) -> Ordering,
+        probe: &dyn Probe<T>,
+    ) {
+        quick_sort_probed(arr, self.0, compare, probe);
+    }
+}
+
+/// [`shell_sort`](super::shell_sort::shell_sort) con la secuencia de saltos
+/// dada.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ShellSort(pub Gaps);
+
+impl<T> Sorter<T> for ShellSort {
+    fn name(&self) -> &'static str {
+        match self.0 {
+            Gaps::Shell => "shell (Shell)",
+            Gaps::Knuth => "shell (Knuth)",
+            Gaps::Ciura => "shell (Ciura)",
+        }
+    }
+
+    fn is_stable(&self) -> bool {
+        false
+    }
+
+    fn is_in_place(&self) -> bool {
+        true
+    }
+
+    fn sort_probed(
+        &self,
+        arr: &mut [T],
+        compare: &mut dyn FnMut(&T, &T) -> Ordering,
+        probe: &dyn Probe<T>,
+    ) {
+        shell_sort_probed(arr, self.0, compare, probe);
+    }
+}
+
+/// [`parallel_merge_sort`](super::parallel_merge_sort::parallel_merge_sort)
+/// con las opciones dadas.
+///
+/// Con [`Sorter::sort`] y [`Sorter::sort_by_sync`] la comparación se reparte
+/// entre los hilos, igual que en `parallel_merge_sort_by`. Con
+/// [`Sorter::sort_by`] y [`Sorter::sort_probed`], en cambio, la comparación y
+/// el observador no se pueden compartir, así que se usan desde el hilo que
+/// llama: los hilos del ordenamiento le envían copias de los elementos a
+/// comparar y esperan la respuesta. Ese camino es más lento que el Merge Sort
+/// secuencial y no sirve para medir tiempos; el observador solo se entera
+/// de las comparaciones y de la reserva del buffer.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ParallelMergeSort(pub ParallelOptions);
+
+impl<T: Clone + Send + Sync> Sorter<T> for ParallelMergeSort {
+    fn name(&self) -> &'static str {
+        "parallel_merge"
+    }
+
+    fn is_stable(&self) -> bool {
+        true
+    }
+
+    fn is_in_place(&self) -> bool {
+        false
+    }
+
+    fn sort_by_sync(&self, arr: &mut [T], compare: &(dyn Fn(&T, &T) -> Ordering + Sync)) {
+        parallel_merge_sort_by(arr, self.0, compare);
+    }
+
+    fn sort_probed(
+        &self,
+        arr: &mut [T],
+        compare: &mut dyn FnMut(&T, &T) -> Ordering,
+        probe: &dyn Probe<T>,
+    ) {
+        if arr.len() < 2 {
+            return;
+        }
+        probe.alloc(std::mem::size_of_val(arr));
+
+        let options = self.0;
+        let (requests, pending) = mpsc::channel::<(T, T, mpsc::Sender<Ordering>)>();
+        thread::scope(|scope| {
+            scope.spawn(move || {
+                parallel_merge_sort_by(arr, options, |a, b| {
+                    let (reply, answer) = mpsc::channel();
+                    requests
+                        .send((a.clone(), b.clone(), reply))
+                        .expect("la comparación terminó antes que el ordenamiento");
+                    answer
+                        .recv()
+                        .expect("la comparación terminó antes que el ordenamiento")
+                });
+            });
+
+            // El canal se cierra cuando el ordenamiento termina y suelta `requests`
+            for (a, b, reply) in pending {
+                probe.compare(&a, &b);
+                // Si el hilo ya terminó por un pánico, la respuesta no importa
+                let _ = reply.send(compare(&a, &b));
+            }
+        });
+    }
+}
+
+/// Los ordenamientos por comparación que no le piden nada a `T`, con cada
+/// partición de Quick Sort y cada secuencia de Shell Sort.
+///
+/// Quedan afuera los que no se pueden expresar con una comparación:
+/// Counting Sort y Radix Sort (ordenan por clave), Bucket Sort (`f64`) y
+/// los de cadenas; y también el externo, que trabaja sobre archivos.
+pub fn sorters<T>() -> Vec<Box<dyn Sorter<T>>> {
+    let quick = |partition| {
+        QuickSort(QuickSortOptions {
+            partition,
+            pivot: Pivot::MedianOfThree,
+        })
+    };
+    vec![
+        Box::new(quick(Partition::Lomuto)),
+        Box::new(quick(Partition::Hoare)),
+        Box::new(quick(Partition::ThreeWay)),
+        Box::new(quick(Partition::DualPivot)),
+        Box::new(IntroSort),
+        Box::new(PdqSort),
+        Box::new(HeapSort),
+        Box::new(InsertionSort),
+        Box::new(BinaryInsertionSort),
+        Box::new(SelectionSort),
+        Box::new(ShellSort(Gaps::Shell)),
+        Box::new(ShellSort(Gaps::Knuth)),
+        Box::new(ShellSort(Gaps::Ciura)),
+        Box::new(CocktailSort),
+        Box::new(CombSort),
+        Box::new(GnomeSort),
+    ]
+}
+
+/// Los de [`sorters`] más los que copian elementos: Merge Sort, Natural
+/// Merge Sort y Cycle Sort.
+pub fn cloning_sorters<T: Clone>() -> Vec<Box<dyn Sorter<T>>> {
+    let mut sorters = sorters();
+    sorters.push(Box::new(MergeSort));
+    sorters.push(Box::new(NaturalMergeSort));
+    sorters.push(Box::new(CycleSort));
+    sorters
+}
+
+/// Todos los ordenamientos por comparación del módulo: los de
+/// [`cloning_sorters`] más el Merge Sort paralelo, que necesita mandar
+/// elementos entre hilos. Para medir tiempos con esta lista hay que ordenar
+/// con [`Sorter::sort`] o [`Sorter::sort_by_sync`]: con una comparación
+/// `FnMut` el paralelo compara de a una (ver [`ParallelMergeSort`]).
+pub fn parallel_sorters<T: Clone + Send + Sync>() -> Vec<Box<dyn Sorter<T>>> {
+    let mut sorters = cloning_sorters();
+    sorters.push(Box::new(ParallelMergeSort::default()));
+    sorters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::instrument::{Counters, Distribution};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_todos_ordenan() {
+        for sorter in parallel_sorters::<u32>() {
+            for distribution in Distribution::ALL {
+                for len in [0, 1, 2, 3, 10, 100] {
+                    let mut values = distribution.generate(len, 29);
+                    let mut expected = values.clone();
+                    expected.sort();
+                    sorter.sort(&mut values);
+                    assert_eq!(
+                        values,
+                        expected,
+                        "{} con {distribution} y {len} elementos",
+                        sorter.name()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_estabilidad() {
+        // Pares (clave, posición original) con muchas claves repetidas
+        let values: Vec<(u32, usize)> = Distribution::Random
+            .generate(300, 31)
+            .into_iter()
+            .map(|x| x % 8)
+            .zip(0..)
+            .collect();
+        let mut expected = values.clone();
+        expected.sort_by_key(|&(key, _)| key);
+
+        for sorter in parallel_sorters().iter().filter(|s| s.is_stable()) {
+            let mut sorted = values.clone();
+            sorter.sort_by(&mut sorted, &mut |a, b| a.0.cmp(&b.0));
+            assert_eq!(sorted, expected, "{}", sorter.name());
+        }
+    }
+
+    #[test]
+    fn test_nombres_y_memoria() {
+        let sorters = parallel_sorters::<u32>();
+        let names: HashSet<_> = sorters.iter().map(|s| s.name()).collect();
+        assert_eq!(names.len(), sorters.len());
+
+        // Los que dicen ordenar en el lugar no reservan memoria auxiliar
+        for sorter in &sorters {
+            let mut values = Distribution::Random.generate(1_000, 37);
+            let counters = Counters::new();
+            sorter.sort_probed(&mut values, &mut u32::cmp, &counters);
+            let allocations = counters.counts().allocations;
+            assert_eq!(allocations == 0, sorter.is_in_place(), "{}", sorter.name());
+        }
+    }
+
+    #[test]
+    fn test_sin_clone() {
+        // Un tipo que no se puede copiar igual se ordena con los de `sorters`
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Key(u32);
+
+        for sorter in sorters::<Key>() {
+            let mut keys: Vec<Key> = [3, 1, 2, 1].into_iter().map(Key).collect();
+            sorter.sort(&mut keys);
+            assert_eq!(keys, [1, 1, 2, 3].map(Key), "{}", sorter.name());
+        }
+    }
+
+    #[test]
+    fn test_paralelo_con_hilos() {
+        // Con más elementos que el umbral secuencial las comparaciones llegan
+        // desde varios hilos
+        let sorter = ParallelMergeSort(ParallelOptions {
+            max_depth: 2,
+            parallel_merge: true,
+        });
+        let mut values = Distribution::Random.generate(20_000, 41);
+        let mut expected = values.clone();
+        expected.sort();
+
+        let mut unprobed = values.clone();
+        let counters = Counters::new();
+        sorter.sort_probed(&mut values, &mut u32::cmp, &counters);
+        assert_eq!(values, expected);
+        assert!(counters.counts().comparisons > 0);
+
+        // Con una comparación `Sync` no hace falta pasar por el hilo que llama
+        sorter.sort_by_sync(&mut unprobed, &|a, b| b.cmp(a));
+        expected.reverse();
+        assert_eq!(unprobed, expected);
+    }
+}
@@ -0,0 +1,409 @@
+use super::radix_sort::{bucket, insertion_sort_from};
+use std::mem;
+
+// Por debajo de este tamaño, el multikey ordena por inserción
+const MULTIKEY_INSERTION_LEN: usize = 16;
+// Cadenas que entran en una cubeta del trie antes de reventarla
+const BURST_LIMIT: usize = 1024;
+
+/// # Multikey Quick Sort
+///
+/// Quick Sort de tres vías para cadenas (Bentley y Sedgewick, 1997): en vez
+/// de comparar cadenas completas, cada partición mira un solo byte.
+///
+/// ## Pasos
+///
+/// 1. **Pivote**: Se elige el byte en la posición actual de la mediana de
+///    tres cadenas (el primero, el del medio y el último).
+/// 2. **Partición**: Las cadenas se dividen en tres grupos según su byte en
+///    la posición actual: menor, igual o mayor que el del pivote. Las que ya
+///    terminaron cuentan como menores a cualquier byte.
+/// 3. **Recursión**: Los grupos menor y mayor se ordenan desde la misma
+///    posición; el grupo igual, desde la siguiente, sin volver a comparar el
+///    byte que ya comparten.
+///
+/// ## Características
+///
+/// - Cada byte de un prefijo compartido se compara una sola vez por cadena,
+///   no una vez por comparación como en los ordenamientos genéricos
+/// - Sin memoria auxiliar ni `Clone`: solo intercambios
+/// - No es estable (las cadenas con los mismos bytes son iguales)
+/// - Sirve para cualquier `T: AsRef<[u8]>`: `String`, `&str`, `&[u8]`,
+///   `Vec<u8>`...
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::string_sort::multikey_quick_sort;
+///
+/// let mut urls = vec![
+///     String::from("https://www.rust-lang.org/learn"),
+///     String::from("https://docs.rs"),
+///     String::from("https://www.rust-lang.org"),
+///     String::from("http://example.com"),
+/// ];
+/// multikey_quick_sort(&mut urls);
+/// assert_eq!(
+///     urls,
+///     vec![
+///         "http://example.com",
+///         "https://docs.rs",
+///         "https://www.rust-lang.org",
+///         "https://www.rust-lang.org/learn",
+///     ]
+/// );
+/// ```
+///
+/// Para compararlo con los demás ordenamientos sobre un millón de URLs:
+/// `cargo test --release bench_string_sorts -- --ignored --nocapture`
+pub fn multikey_quick_sort<T: AsRef<[u8]>>(arr: &mut [T]) {
+    multikey(arr, 0);
+}
+
+// Ordena `arr`, sabiendo que todas las cadenas comparten los primeros
+// `depth` bytes
+fn multikey<T: AsRef<[u8]>>(mut arr: &mut [T], mut depth: usize) {
+    loop {
+        if arr.len() < MULTIKEY_INSERTION_LEN {
+            insertion_sort_from(arr, depth);
+            return;
+        }
+
+        let last = arr.len() - 1;
+        let pivot = median_of_three(
+            bucket(&arr[0], depth),
+            bucket(&arr[last / 2], depth),
+            bucket(&arr[last], depth),
+        );
+
+        // Partición de tres vías: [0, lt) menores, [lt, i) iguales y
+        // [gt, len) mayores
+        let (mut lt, mut i, mut gt) = (0, 0, arr.len());
+        while i < gt {
+            let b = bucket(&arr[i], depth);
+            if b < pivot {
+                arr.swap(lt, i);
+                lt += 1;
+                i += 1;
+            } else if b > pivot {
+                gt -= 1;
+                arr.swap(i, gt);
+            } else {
+                i += 1;
+            }
+        }
+
+        let (less, rest) = mem::take(&mut arr).split_at_mut(lt);
+        let (equal, greater) = rest.split_at_mut(gt - lt);
+        multikey(less, depth);
+        multikey(greater, depth);
+
+        // Si el pivote es el fin de cadena, las iguales ya son idénticas
+        if pivot == 0 {
+            return;
+        }
+        arr = equal;
+        depth += 1;
+    }
+}
+
+fn median_of_three(a: usize, b: usize, c: usize) -> usize {
+    a.max(b).min(a.min(b).max(c))
+}
+
+/// # Burst Sort
+///
+/// Ordenamiento de cadenas sobre un trie que crece a medida que hace falta
+/// (Sinha y Zobel, 2004). Los prefijos se recorren una sola vez al insertar,
+/// y las cadenas de cada hoja se ordenan juntas, en poca memoria.
+///
+/// ## Pasos
+///
+/// 1. **Inserción**: Cada cadena baja por el trie, un nodo por byte, hasta
+///    llegar a una cubeta (una lista de cadenas) o hasta terminarse. Las que
+///    terminan en un nodo se guardan aparte: son todas iguales.
+/// 2. **Ráfagas**: Cuando una cubeta supera las 1024 cadenas, se reemplaza
+///    por un nodo nuevo y sus cadenas se reparten por el byte siguiente.
+/// 3. **Recorrido**: Se recorre el trie en orden. En cada nodo van primero
+///    las cadenas terminadas y después los hijos por byte; cada cubeta se
+///    ordena con [`multikey_quick_sort`] desde su profundidad.
+/// 4. **Permutación**: El orden obtenido se aplica al slice con intercambios,
+///    siguiendo los ciclos de la permutación.
+///
+/// ## Características
+///
+/// - Memoria extra O(n + 256·L), con `L` la cantidad de nodos del trie: las
+///   cubetas guardan referencias a las cadenas y sus posiciones, y cada nodo
+///   tiene una entrada por byte posible
+/// - La inserción es iterativa; el recorrido es recursivo, con profundidad
+///   igual a la altura del trie, que no pasa del prefijo común más largo
+///   entre más de 1024 cadenas
+/// - Sin `Clone`: las cadenas se mueven solo al aplicar la permutación
+/// - No es estable (las cadenas con los mismos bytes son iguales)
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::string_sort::burst_sort;
+///
+/// let mut words: Vec<&[u8]> = vec![b"banana", b"ban", b"apple", b"", b"band"];
+/// burst_sort(&mut words);
+/// let expected: Vec<&[u8]> = vec![b"", b"apple", b"ban", b"banana", b"band"];
+/// assert_eq!(words, expected);
+/// ```
+pub fn burst_sort<T: AsRef<[u8]>>(arr: &mut [T]) {
+    let mut root = Node::default();
+    for (index, item) in arr.iter().enumerate() {
+        let entry = Entry {
+            bytes: item.as_ref(),
+            index,
+        };
+        root.insert(entry, 0);
+    }
+
+    let mut order = Vec::with_capacity(arr.len());
+    root.collect(0, &mut order);
+
+    // `destination[i]` es la posición final del elemento que está en `i`
+    let mut destination = vec![0; arr.len()];
+    for (position, &index) in order.iter().enumerate() {
+        destination[index] = position;
+    }
+    for i in 0..arr.len() {
+        while destination[i] != i {
+            let j = destination[i];
+            arr.swap(i, j);
+            destination.swap(i, j);
+        }
+    }
+}
+
+// Una cadena del slice y su posición original
+struct Entry<'a> {
+    bytes: &'a [u8],
+    index: usize,
+}
+
+impl AsRef<[u8]> for Entry<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.bytes
+    }
+}
+
+enum Slot<'a> {
+    Bucket(Vec<Entry<'a>>),
+    Node(Box<Node<'a>>),
+}
+
+// Nodo del trie: el hijo `b` contiene las cadenas cuyo byte en la
+// profundidad del nodo es `b`
+struct Node<'a> {
+    ended: Vec<usize>,
+    children: Vec<Slot<'a>>,
+}
+
+impl Default for Node<'_> {
+    fn default() -> Self {
+        Node {
+            ended: Vec::new(),
+            children: (0..256).map(|_| Slot::Bucket(Vec::new())).collect(),
+        }
+    }
+}
+
+impl<'a> Node<'a> {
+    // Inserta `entry` en este nodo, que está a profundidad `depth`
+    fn insert(&mut self, entry: Entry<'a>, depth: usize) {
+        let mut node = self;
+        let mut depth = depth;
+        loop {
+            let Some(&byte) = entry.bytes.get(depth) else {
+                node.ended.push(entry.index);
+                return;
+            };
+            let byte = byte as usize;
+            if let Slot::Bucket(bucket) = &mut node.children[byte] {
+                bucket.push(entry);
+                if bucket.len() > BURST_LIMIT {
+                    node.burst(byte, depth + 1);
+                }
+                return;
+            }
+            let Slot::Node(child) = &mut node.children[byte] else {
+                unreachable!();
+            };
+            node = child;
+            depth += 1;
+        }
+    }
+
+    // Reemplaza la cubeta del hijo `byte` por un nodo a profundidad `depth`.
+    // La cubeta tiene `BURST_LIMIT + 1` cadenas, así que a lo sumo una de las
+    // cubetas nuevas se pasa del límite, y se revienta en la vuelta siguiente
+    fn burst(&mut self, byte: usize, depth: usize) {
+        let (mut node, mut byte, mut depth) = (self, byte, depth);
+        loop {
+            let Slot::Bucket(bucket) =
+                mem::replace(&mut node.children[byte], Slot::Bucket(Vec::new()))
+            else {
+                return;
+            };
+            let mut child = Node::default();
+            for entry in bucket {
+                match entry.bytes.get(depth) {
+                    None => child.ended.push(entry.index),
+                    Some(&next) => match &mut child.children[next as usize] {
+                        Slot::Bucket(bucket) => bucket.push(entry),
+                        Slot::Node(_) => unreachable!("un nodo nuevo solo tiene cubetas"),
+                    },
+                }
+            }
+            let full = child.children.iter().position(
+                |slot| matches!(slot, Slot::Bucket(bucket) if bucket.len() > BURST_LIMIT),
+            );
+
+            node.children[byte] = Slot::Node(Box::new(child));
+            let Some(full) = full else {
+                return;
+            };
+            let Slot::Node(child) = &mut node.children[byte] else {
+                unreachable!();
+            };
+            (node, byte, depth) = (child, full, depth + 1);
+        }
+    }
+
+    // Agrega a `order` las posiciones originales en orden
+    fn collect(self, depth: usize, order: &mut Vec<usize>) {
+        order.extend(self.ended);
+        for slot in self.children {
+            match slot {
+                Slot::Bucket(mut bucket) => {
+                    multikey(&mut bucket, depth + 1);
+                    order.extend(bucket.iter().map(|entry| entry.index));
+                }
+                Slot::Node(child) => child.collect(depth + 1, order),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::merge_sort::merge_sort;
+    use crate::algorithms::sorting::property::Rng;
+    use crate::algorithms::sorting::radix_sort::msd_radix_sort;
+    use std::time::Instant;
+
+    // URLs sintéticas: pocos dominios y rutas con prefijos compartidos
+    fn urls(len: usize, seed: u64) -> Vec<String> {
+        let mut rng = Rng::new(seed);
+        let mut next = || rng.next_u32() as usize;
+        let hosts = [
+            "https://www.rust-lang.org",
+            "https://docs.rs",
+            "http://a.io",
+        ];
+        let parts = ["", "api", "v1", "users", "ñandú", "x"];
+        (0..len)
+            .map(|_| {
+                let mut url = String::from(hosts[next() % hosts.len()]);
+                for _ in 0..next() % 5 {
+                    url.push('/');
+                    url.push_str(parts[next() % parts.len()]);
+                }
+                if next() % 2 == 0 {
+                    url.push_str(&format!("?id={}", next() % 1_000));
+                }
+                url
+            })
+            .collect()
+    }
+
+    fn check(sort: fn(&mut [String])) {
+        for len in [0, 1, 2, 15, 16, 17, 500, 20_000] {
+            let mut values = urls(len, len as u64);
+            let mut expected = values.clone();
+            expected.sort();
+            sort(&mut values);
+            assert_eq!(values, expected);
+        }
+    }
+
+    #[test]
+    fn test_multikey_quick_sort() {
+        check(multikey_quick_sort);
+
+        let mut bytes: Vec<&[u8]> = vec![&[3, 0], &[], &[3], &[255], &[0, 0], &[0]];
+        let mut expected = bytes.clone();
+        expected.sort();
+        multikey_quick_sort(&mut bytes);
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn test_burst_sort() {
+        // Con 20 000 URLs de pocos prefijos, las cubetas revientan varias veces
+        check(burst_sort);
+
+        let values = urls(5_000, 7);
+        let mut bytes: Vec<&[u8]> = values.iter().map(|url| url.as_bytes()).collect();
+        let mut expected = bytes.clone();
+        expected.sort();
+        burst_sort(&mut bytes);
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn test_repetidas() {
+        let mut values = vec![String::from("misma"); 3_000];
+        values.extend(vec![String::new(); 3_000]);
+        let mut expected = values.clone();
+        expected.sort();
+
+        let mut sorted = values.clone();
+        multikey_quick_sort(&mut sorted);
+        assert_eq!(sorted, expected);
+        burst_sort(&mut values);
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn test_prefijo_largo() {
+        // Más de 1024 cadenas con el mismo prefijo: cada ráfaga deja una
+        // cubeta llena un byte más abajo, hasta el final del prefijo
+        let prefix = "ab".repeat(1_000);
+        let mut values: Vec<String> = (0..1_500)
+            .map(|i| format!("{prefix}{}", (i * 7) % 1_500))
+            .collect();
+        let mut expected = values.clone();
+        expected.sort();
+        burst_sort(&mut values);
+        assert_eq!(values, expected);
+    }
+
+    /// Compara los ordenamientos de cadenas con Merge Sort y
+    /// `slice::sort_unstable` sobre un millón de URLs. Conviene correrlo en
+    /// modo release:
+    /// `cargo test --release bench_string_sorts -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn bench_string_sorts() {
+        let values = urls(1_000_000, 42);
+        for (name, sort) in [
+            ("merge_sort", merge_sort::<String> as fn(&mut [String])),
+            ("sort_unstable", <[String]>::sort_unstable),
+            ("msd_radix_sort", msd_radix_sort),
+            ("multikey_quick_sort", multikey_quick_sort),
+            ("burst_sort", burst_sort),
+        ] {
+            let mut sorted = values.clone();
+            let started = Instant::now();
+            sort(&mut sorted);
+            let elapsed = started.elapsed();
+            assert!(sorted.windows(2).all(|w| w[0] <= w[1]));
+            println!("{name:<20} {elapsed:?}");
+        }
+    }
+}
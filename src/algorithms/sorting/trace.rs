@@ -0,0 +1,518 @@
+use super::probe::Probe;
+use std::cell::RefCell;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::Range;
+
+/// Posición de un elemento: el buffer donde está (0 es el slice que se
+/// ordena, y los auxiliares se numeran desde 1 en el orden en que se crean)
+/// y su índice dentro de él.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub buffer: usize,
+    pub index: usize,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}[{}]", buffer_name(self.buffer), self.index)
+    }
+}
+
+/// Un rango de posiciones dentro de un buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub buffer: usize,
+    pub range: Range<usize>,
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Range { start, end } = self.range;
+        write!(f, "{}[{start}..{end}]", buffer_name(self.buffer))
+    }
+}
+
+fn buffer_name(buffer: usize) -> String {
+    match buffer {
+        0 => String::from("slice"),
+        n => format!("buffer {n}"),
+    }
+}
+
+/// Un paso de un ordenamiento.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event<T> {
+    /// Se crearon un buffer auxiliar y sus valores iniciales.
+    Buffer { id: usize, values: Vec<T> },
+    /// Se compararon dos elementos.
+    Compare(Location, Location),
+    /// Se intercambiaron dos elementos.
+    Swap(Location, Location),
+    /// Se escribió un valor.
+    Write(Location, T),
+    /// Los bloques de `from` se van a fusionar en `to`.
+    Merge { from: Span, to: Span },
+}
+
+impl<T: fmt::Debug> fmt::Display for Event<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Event::Buffer { id, values } => {
+                write!(
+                    f,
+                    "nuevo {} de {} elementos",
+                    buffer_name(*id),
+                    values.len()
+                )
+            }
+            Event::Compare(a, b) => write!(f, "comparar {a} con {b}"),
+            Event::Swap(a, b) => write!(f, "intercambiar {a} y {b}"),
+            Event::Write(at, value) => write!(f, "escribir {value:?} en {at}"),
+            Event::Merge { from, to } => write!(f, "fusionar {from} en {to}"),
+        }
+    }
+}
+
+/// [`Probe`] que traduce cada operación a un [`Event`] y se lo pasa a
+/// `callback`.
+///
+/// Los ordenamientos avisan con referencias a los elementos, y el `Tracer`
+/// las ubica por su dirección de memoria: en el slice que se ordena o en
+/// alguno de los buffers auxiliares que el algoritmo le avisó que creó. Las
+/// comparaciones contra copias temporales (que no están en ningún buffer)
+/// no generan eventos.
+pub struct Tracer<T, F> {
+    // Dirección y largo de cada buffer; el 0 es el slice
+    regions: RefCell<Vec<(usize, usize)>>,
+    callback: RefCell<F>,
+    marker: PhantomData<fn(T)>,
+}
+
+impl<T, F> Tracer<T, F>
+where
+    T: Clone,
+    F: FnMut(Event<T>),
+{
+    /// Crea un `Tracer` para ordenar `arr`.
+    pub fn new(arr: &[T], callback: F) -> Self {
+        Tracer {
+            regions: RefCell::new(vec![(arr.as_ptr() as usize, arr.len())]),
+            callback: RefCell::new(callback),
+            marker: PhantomData,
+        }
+    }
+
+    fn locate(&self, element: &T) -> Option<Location> {
+        let size = mem::size_of::<T>();
+        if size == 0 {
+            return None;
+        }
+        let address = element as *const T as usize;
+        // Se busca desde el más nuevo: si se liberó un buffer, otro puede
+        // ocupar su dirección
+        let regions = self.regions.borrow();
+        regions
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(buffer, &(start, len))| {
+                let index = address.checked_sub(start)? / size;
+                (index < len).then_some(Location { buffer, index })
+            })
+    }
+
+    fn span(&self, slice: &[T]) -> Option<Span> {
+        let start = self.locate(slice.first()?)?;
+        Some(Span {
+            buffer: start.buffer,
+            range: start.index..start.index + slice.len(),
+        })
+    }
+
+    fn emit(&self, event: Event<T>) {
+        (self.callback.borrow_mut())(event);
+    }
+}
+
+impl<T, F> Probe<T> for Tracer<T, F>
+where
+    T: Clone,
+    F: FnMut(Event<T>),
+{
+    fn compare(&self, a: &T, b: &T) {
+        if let (Some(a), Some(b)) = (self.locate(a), self.locate(b)) {
+            self.emit(Event::Compare(a, b));
+        }
+    }
+
+    fn swap(&self, slice: &[T], i: usize, j: usize) {
+        if let (Some(a), Some(b)) = (self.locate(&slice[i]), self.locate(&slice[j])) {
+            self.emit(Event::Swap(a, b));
+        }
+    }
+
+    fn write(&self, slice: &[T], i: usize) {
+        if let Some(at) = self.locate(&slice[i]) {
+            self.emit(Event::Write(at, slice[i].clone()));
+        }
+    }
+
+    fn buffer(&self, buffer: &[T]) {
+        let id = {
+            let mut regions = self.regions.borrow_mut();
+            regions.push((buffer.as_ptr() as usize, buffer.len()));
+            regions.len() - 1
+        };
+        self.emit(Event::Buffer {
+            id,
+            values: buffer.to_vec(),
+        });
+    }
+
+    fn merge(&self, from: &[T], to: &[T]) {
+        if let (Some(from), Some(to)) = (self.span(from), self.span(to)) {
+            self.emit(Event::Merge { from, to });
+        }
+    }
+}
+
+/// Los pasos de un ordenamiento, junto con la entrada.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trace<T> {
+    pub initial: Vec<T>,
+    pub events: Vec<Event<T>>,
+}
+
+/// Ordena `arr` con `sort` y devuelve todos sus pasos.
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::algorithms::sorting::merge_sort::merge_sort_probed;
+/// use algoritmos_y_estructuras::algorithms::sorting::trace::{record, Event, Location, Renderer};
+///
+/// let mut numbers = vec![3, 1, 2];
+/// let trace = record(&mut numbers, |arr, probe| merge_sort_probed(arr, u32::cmp, probe));
+/// assert_eq!(numbers, vec![1, 2, 3]);
+///
+/// // La primera comparación es entre los dos primeros elementos del slice
+/// let first = trace.events.iter().find(|e| matches!(e, Event::Compare(..)));
+/// let at = |index| Location { buffer: 0, index };
+/// assert_eq!(first, Some(&Event::Compare(at(1), at(0))));
+///
+/// for frame in Renderer::default().frames(&trace) {
+///     println!("{frame}");
+/// }
+/// ```
+pub fn record<T, S>(arr: &mut [T], sort: S) -> Trace<T>
+where
+    T: Clone,
+    S: FnOnce(&mut [T], &dyn Probe<T>),
+{
+    let initial = arr.to_vec();
+    let mut events = Vec::new();
+    let tracer = Tracer::new(arr, |event| events.push(event));
+    sort(arr, &tracer);
+    drop(tracer);
+    Trace { initial, events }
+}
+
+/// Reproduce un [`Trace`] paso a paso sobre una copia de la entrada.
+#[derive(Debug, Clone)]
+pub struct Replay<'a, T> {
+    events: std::slice::Iter<'a, Event<T>>,
+    buffers: Vec<Vec<T>>,
+}
+
+impl<'a, T: Clone> Replay<'a, T> {
+    pub fn new(trace: &'a Trace<T>) -> Self {
+        Replay {
+            events: trace.events.iter(),
+            buffers: vec![trace.initial.clone()],
+        }
+    }
+
+    /// El contenido de cada buffer después del último paso; el 0 es el slice.
+    pub fn buffers(&self) -> &[Vec<T>] {
+        &self.buffers
+    }
+
+    /// Aplica el paso siguiente y lo devuelve, o `None` si no quedan.
+    pub fn step(&mut self) -> Option<&'a Event<T>> {
+        let event = self.events.next()?;
+        match event {
+            Event::Buffer { id, values } => {
+                self.buffers.truncate(*id);
+                self.buffers.push(values.clone());
+            }
+            Event::Swap(a, b) => {
+                let value = self.buffers[a.buffer][a.index].clone();
+                let other = mem::replace(&mut self.buffers[b.buffer][b.index], value);
+                self.buffers[a.buffer][a.index] = other;
+            }
+            Event::Write(at, value) => self.buffers[at.buffer][at.index] = value.clone(),
+            Event::Compare(..) | Event::Merge { .. } => {}
+        }
+        Some(event)
+    }
+}
+
+/// Dibuja un [`Trace`] en la terminal como una secuencia de gráficos de
+/// barras, uno por paso.
+///
+/// Cada cuadro muestra el slice y, si el algoritmo creó alguno, el último
+/// buffer auxiliar, con una columna por elemento: conviene usarlo con pocos
+/// elementos. Debajo de cada gráfico se marcan las posiciones del paso:
+/// `^` las comparadas, `*` las intercambiadas, `w` la escrita, y `-` y `=`
+/// los bloques que se leen y se escriben en una fusión. Después de una
+/// fusión, cada buffer indica si es el de lectura o el de escritura.
+///
+/// Para ver cómo Merge Sort alterna sus buffers:
+/// `cargo test demo_merge_sort -- --ignored --nocapture`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Renderer {
+    /// Alto de las barras, en líneas.
+    pub height: usize,
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Renderer { height: 10 }
+    }
+}
+
+impl Renderer {
+    /// Los cuadros de `trace`: el primero es la entrada, y después uno por
+    /// cada evento.
+    pub fn frames<'a, T>(&self, trace: &'a Trace<T>) -> Frames<'a, T>
+    where
+        T: Clone + Into<u64> + fmt::Debug,
+    {
+        Frames {
+            renderer: *self,
+            replay: Replay::new(trace),
+            started: false,
+            merge: None,
+        }
+    }
+
+    fn render<T>(
+        &self,
+        buffers: &[Vec<T>],
+        event: Option<&Event<T>>,
+        merge: Option<&(usize, usize)>,
+    ) -> String
+    where
+        T: Clone + Into<u64> + fmt::Debug,
+    {
+        let shown: Vec<usize> = match buffers.len() {
+            1 => vec![0],
+            n => vec![0, n - 1],
+        };
+        let max = shown
+            .iter()
+            .flat_map(|&b| buffers[b].iter())
+            .map(|value| value.clone().into())
+            .max()
+            .unwrap_or(0)
+            .max(1);
+
+        let mut frame = match event {
+            Some(event) => format!("{event}\n"),
+            None => String::from("entrada\n"),
+        };
+        for &buffer in &shown {
+            let role = match merge {
+                Some(&(from, _)) if from == buffer => " (lectura)",
+                Some(&(_, to)) if to == buffer => " (escritura)",
+                _ => "",
+            };
+            frame.push_str(&format!("{}{role}\n", buffer_name(buffer)));
+
+            let heights: Vec<usize> = buffers[buffer]
+                .iter()
+                .map(|value| {
+                    // En u128 el producto no desborda aunque el valor sea grande
+                    let value: u64 = value.clone().into();
+                    (value as u128 * self.height as u128).div_ceil(max as u128) as usize
+                })
+                .collect();
+            for level in (1..=self.height).rev() {
+                let row: String = heights
+                    .iter()
+                    .map(|&h| if h >= level { '█' } else { ' ' })
+                    .collect();
+                frame.push_str(row.trim_end());
+                frame.push('\n');
+            }
+
+            let mut marks = vec![' '; heights.len()];
+            let mut mark = |at: &Location, symbol| {
+                if at.buffer == buffer {
+                    marks[at.index] = symbol;
+                }
+            };
+            match event {
+                Some(Event::Compare(a, b)) => {
+                    mark(a, '^');
+                    mark(b, '^');
+                }
+                Some(Event::Swap(a, b)) => {
+                    mark(a, '*');
+                    mark(b, '*');
+                }
+                Some(Event::Write(at, _)) => mark(at, 'w'),
+                Some(Event::Merge { from, to }) => {
+                    for (span, symbol) in [(from, '-'), (to, '=')] {
+                        if span.buffer == buffer {
+                            marks[span.range.clone()].fill(symbol);
+                        }
+                    }
+                }
+                _ => {}
+            }
+            let marks: String = marks.into_iter().collect();
+            frame.push_str(marks.trim_end());
+            frame.push('\n');
+        }
+        frame
+    }
+}
+
+/// Iterador de los cuadros de un [`Trace`]; ver [`Renderer::frames`].
+#[derive(Debug, Clone)]
+pub struct Frames<'a, T> {
+    renderer: Renderer,
+    replay: Replay<'a, T>,
+    started: bool,
+    // Buffers de lectura y escritura de la última fusión
+    merge: Option<(usize, usize)>,
+}
+
+impl<T> Iterator for Frames<'_, T>
+where
+    T: Clone + Into<u64> + fmt::Debug,
+{
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if !self.started {
+            self.started = true;
+            return Some(self.renderer.render(self.replay.buffers(), None, None));
+        }
+        let event = self.replay.step()?;
+        if let Event::Merge { from, to } = event {
+            self.merge = Some((from.buffer, to.buffer));
+        }
+        Some(
+            self.renderer
+                .render(self.replay.buffers(), Some(event), self.merge.as_ref()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::instrument::{algorithms, Distribution};
+    use crate::algorithms::sorting::merge_sort::merge_sort_probed;
+
+    #[test]
+    fn test_replay_reproduce_el_resultado() {
+        // Si algún algoritmo modifica un buffer sin avisar, la reproducción
+        // no llega al mismo resultado
+        for distribution in Distribution::ALL {
+            for len in [0, 1, 2, 10, 100, 300] {
+                for (name, sort) in algorithms() {
+                    let mut values = distribution.generate(len, 5);
+                    let trace = record(&mut values, sort);
+
+                    let mut replay = Replay::new(&trace);
+                    while replay.step().is_some() {}
+                    assert_eq!(replay.buffers()[0], values, "{name} con {distribution}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_merge_alterna_buffers() {
+        let mut values = Distribution::Random.generate(8, 1);
+        let trace = record(&mut values, |arr, probe| {
+            merge_sort_probed(arr, u32::cmp, probe)
+        });
+
+        let merges: Vec<(usize, usize, Range<usize>)> = trace
+            .events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Merge { from, to } => Some((from.buffer, to.buffer, to.range.clone())),
+                _ => None,
+            })
+            .collect();
+        // Bloques de 1, 2 y 4: cada pasada lee de donde escribió la anterior
+        assert_eq!(
+            merges,
+            [
+                (0, 1, 0..2),
+                (0, 1, 2..4),
+                (0, 1, 4..6),
+                (0, 1, 6..8),
+                (1, 0, 0..4),
+                (1, 0, 4..8),
+                (0, 1, 0..8),
+            ]
+        );
+        // El resultado quedó en el buffer y se copia de vuelta al slice
+        let last_writes: Vec<&Event<u32>> = trace.events.iter().rev().take(8).collect();
+        assert!(last_writes
+            .iter()
+            .all(|event| matches!(event, Event::Write(Location { buffer: 0, .. }, _))));
+    }
+
+    #[test]
+    fn test_renderer() {
+        let mut values = vec![3u32, 1, 2];
+        let trace = record(&mut values, |arr, probe| {
+            merge_sort_probed(arr, u32::cmp, probe)
+        });
+        let frames: Vec<String> = Renderer { height: 3 }.frames(&trace).collect();
+        assert_eq!(frames.len(), trace.events.len() + 1);
+        assert_eq!(frames[0], "entrada\nslice\n█\n█ █\n███\n\n");
+
+        let merge = frames
+            .iter()
+            .find(|frame| frame.starts_with("fusionar"))
+            .unwrap();
+        assert!(merge.starts_with("fusionar slice[0..2] en buffer 1[0..2]\n"));
+        assert!(merge.contains("slice (lectura)\n"));
+        assert!(merge.contains("\n--\n"));
+        assert!(merge.contains("buffer 1 (escritura)\n"));
+    }
+
+    #[test]
+    fn test_renderer_valores_grandes() {
+        let mut values = vec![u64::MAX, 1, u64::MAX / 2];
+        let trace = record(&mut values, |arr, probe| {
+            merge_sort_probed(arr, u64::cmp, probe)
+        });
+        let frames: Vec<String> = Renderer { height: 2 }.frames(&trace).collect();
+        assert_eq!(frames[0], "entrada\nslice\n█\n███\n\n");
+    }
+
+    #[test]
+    #[ignore]
+    fn demo_merge_sort() {
+        let mut values = Distribution::Random.generate(32, 7);
+        let trace = record(&mut values, |arr, probe| {
+            merge_sort_probed(arr, u32::cmp, probe)
+        });
+        for frame in Renderer::default().frames(&trace) {
+            // Borra la pantalla y vuelve al principio
+            print!("\x1b[2J\x1b[H{frame}");
+            std::thread::sleep(std::time::Duration::from_millis(60));
+        }
+    }
+}
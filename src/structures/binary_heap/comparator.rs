@@ -0,0 +1,49 @@
+use std::cmp::Ordering;
+
+/// Criterio de orden de un [`BinaryHeap`](super::heap::BinaryHeap): la cima
+/// del montículo es siempre el elemento mayor según `compare`.
+///
+/// Además de [`Max`] y [`Min`], cualquier función `Fn(&T, &T) -> Ordering`
+/// sirve como comparador.
+pub trait Comparator<T> {
+    fn compare(&self, a: &T, b: &T) -> Ordering;
+}
+
+/// Orden natural: montículo de máximos.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Max;
+
+/// Orden natural invertido: montículo de mínimos.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Min;
+
+/// Invierte otro comparador: con `Reverse(f)`, la cima es el menor según `f`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Reverse<C>(pub C);
+
+impl<T: Ord> Comparator<T> for Max {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+impl<T: Ord> Comparator<T> for Min {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        b.cmp(a)
+    }
+}
+
+impl<T, C: Comparator<T>> Comparator<T> for Reverse<C> {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self.0.compare(b, a)
+    }
+}
+
+impl<T, F> Comparator<T> for F
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self(a, b)
+    }
+}
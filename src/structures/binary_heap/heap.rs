@@ -0,0 +1,326 @@
+use super::comparator::{Comparator, Max};
+use std::cmp::Ordering;
+use std::mem;
+
+/// # Estructura de Datos: Montículo Binario (Binary Heap)
+///
+/// Cola de prioridad sobre un árbol binario completo guardado en un `Vec`:
+/// los hijos del nodo `i` están en `2i + 1` y `2i + 2`, y cada nodo es mayor
+/// o igual a sus hijos según el comparador `C`. Con [`Max`] (el comparador
+/// por defecto) la cima es el máximo; con [`Min`](super::comparator::Min),
+/// el mínimo; y con una función propia se puede ordenar por cualquier clave.
+///
+/// ## Operaciones
+///
+/// - `push`: O(log n). El elemento entra al final y sube mientras sea mayor
+///   que su padre.
+/// - `pop`: O(log n). La cima se cambia por el último elemento, que después
+///   baja hasta su lugar.
+/// - `peek`: O(1).
+/// - `heapify`: O(n). Convierte un `Vec` en montículo bajando cada nodo
+///   interno, desde el último hasta la raíz.
+/// - `into_sorted_vec`: O(n log n). Heap Sort sobre el propio `Vec`.
+/// - `merge`: Junta dos montículos, eligiendo entre insertar los elementos
+///   del menor uno por uno o reconstruir todo con `heapify`.
+///
+/// ## Ejemplo de Uso
+///
+/// ```rust
+/// use algoritmos_y_estructuras::structures::binary_heap::comparator::Min;
+/// use algoritmos_y_estructuras::structures::binary_heap::heap::BinaryHeap;
+///
+/// let mut heap = BinaryHeap::with_comparator(Min);
+/// for value in [5, 1, 8, 3, 2] {
+///     heap.push(value);
+/// }
+/// assert_eq!(heap.peek(), Some(&1));
+/// assert_eq!(heap.pop(), Some(1));
+/// assert_eq!(heap.pop(), Some(2));
+///
+/// // Con una función propia: primero la palabra más larga
+/// let words = vec!["sol", "montaña", "río"];
+/// let heap = BinaryHeap::heapify(words, |a: &&str, b: &&str| a.len().cmp(&b.len()));
+/// assert_eq!(heap.peek(), Some(&"montaña"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct BinaryHeap<T, C = Max> {
+    data: Vec<T>,
+    comparator: C,
+}
+
+impl<T: Ord> BinaryHeap<T, Max> {
+    /// Crea un montículo de máximos vacío.
+    pub fn new() -> Self {
+        Self::with_comparator(Max)
+    }
+}
+
+impl<T: Ord> Default for BinaryHeap<T, Max> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for BinaryHeap<T, Max> {
+    fn from(data: Vec<T>) -> Self {
+        Self::heapify(data, Max)
+    }
+}
+
+impl<T, C: Comparator<T>> BinaryHeap<T, C> {
+    /// Crea un montículo vacío ordenado según `comparator`.
+    pub fn with_comparator(comparator: C) -> Self {
+        BinaryHeap {
+            data: Vec::new(),
+            comparator,
+        }
+    }
+
+    /// Convierte `data` en un montículo en tiempo lineal.
+    pub fn heapify(mut data: Vec<T>, comparator: C) -> Self {
+        build_heap(
+            &mut data,
+            &mut |a, b| comparator.compare(a, b) == Ordering::Less,
+            &mut <[T]>::swap,
+        );
+        BinaryHeap { data, comparator }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn comparator(&self) -> &C {
+        &self.comparator
+    }
+
+    /// La cima del montículo, sin sacarla.
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.data.push(item);
+
+        // Sube mientras sea mayor que su padre
+        let mut node = self.data.len() - 1;
+        while node > 0 {
+            let parent = (node - 1) / 2;
+            if self
+                .comparator
+                .compare(&self.data[parent], &self.data[node])
+                != Ordering::Less
+            {
+                break;
+            }
+            self.data.swap(parent, node);
+            node = parent;
+        }
+    }
+
+    /// Saca y devuelve la cima del montículo.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.data.is_empty() {
+            return None;
+        }
+        let top = self.data.swap_remove(0);
+        let comparator = &self.comparator;
+        sift_down(
+            &mut self.data,
+            0,
+            &mut |a, b| comparator.compare(a, b) == Ordering::Less,
+            &mut <[T]>::swap,
+        );
+        Some(top)
+    }
+
+    /// Agrega todos los elementos de `other`, que queda vacío.
+    pub fn merge(&mut self, other: &mut Self) {
+        if self.data.len() < other.data.len() {
+            mem::swap(&mut self.data, &mut other.data);
+        }
+        let added = other.data.len();
+        if added == 0 {
+            return;
+        }
+
+        // Insertar uno por uno cuesta unas `added · log2(n)` comparaciones y
+        // reconstruir, unas `2 · (n + added)`: se elige lo más barato
+        let total = self.data.len() + added;
+        let rebuild = 2 * total < added * (self.data.len().ilog2() as usize + 1);
+        if rebuild {
+            self.data.append(&mut other.data);
+            let comparator = &self.comparator;
+            build_heap(
+                &mut self.data,
+                &mut |a, b| comparator.compare(a, b) == Ordering::Less,
+                &mut <[T]>::swap,
+            );
+        } else {
+            for item in other.data.drain(..) {
+                self.push(item);
+            }
+        }
+    }
+
+    /// Los elementos en orden ascendente según el comparador; con un
+    /// montículo de mínimos quedan de mayor a menor.
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let comparator = &self.comparator;
+        sort_heap(
+            &mut self.data,
+            &mut |a, b| comparator.compare(a, b) == Ordering::Less,
+            &mut <[T]>::swap,
+        );
+        self.data
+    }
+
+    /// Los elementos en el orden interno del montículo.
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+}
+
+/// Baja el elemento de `node` hasta que sea mayor o igual a sus hijos,
+/// según `is_less`. Los intercambios se hacen con `swap`, para que Heap Sort
+/// pueda observarlos.
+pub(crate) fn sift_down<T, F, S>(data: &mut [T], mut node: usize, is_less: &mut F, swap: &mut S)
+where
+    F: FnMut(&T, &T) -> bool,
+    S: FnMut(&mut [T], usize, usize),
+{
+    loop {
+        let mut child = 2 * node + 1;
+        if child >= data.len() {
+            break;
+        }
+        if child + 1 < data.len() && is_less(&data[child], &data[child + 1]) {
+            child += 1;
+        }
+        if !is_less(&data[node], &data[child]) {
+            break;
+        }
+        swap(data, node, child);
+        node = child;
+    }
+}
+
+/// Ordena `data` como montículo de máximos según `is_less`.
+pub(crate) fn build_heap<T, F, S>(data: &mut [T], is_less: &mut F, swap: &mut S)
+where
+    F: FnMut(&T, &T) -> bool,
+    S: FnMut(&mut [T], usize, usize),
+{
+    // Las hojas ya son montículos: se empieza por el último nodo interno
+    for node in (0..data.len() / 2).rev() {
+        sift_down(data, node, is_less, swap);
+    }
+}
+
+/// Ordena de menor a mayor un slice que ya es montículo de máximos: la cima
+/// va al final y el montículo se achica.
+pub(crate) fn sort_heap<T, F, S>(data: &mut [T], is_less: &mut F, swap: &mut S)
+where
+    F: FnMut(&T, &T) -> bool,
+    S: FnMut(&mut [T], usize, usize),
+{
+    for end in (1..data.len()).rev() {
+        swap(data, 0, end);
+        sift_down(&mut data[..end], 0, is_less, swap);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::sorting::random_values;
+    use crate::structures::binary_heap::comparator::Min;
+
+    fn drain<T, C: Comparator<T>>(mut heap: BinaryHeap<T, C>) -> Vec<T> {
+        let mut values = Vec::new();
+        while let Some(value) = heap.pop() {
+            values.push(value);
+        }
+        values
+    }
+
+    #[test]
+    fn test_push_pop() {
+        let values = random_values(500, 1_000, 1);
+        let mut expected = values.clone();
+        expected.sort();
+
+        let mut min_heap = BinaryHeap::with_comparator(Min);
+        let mut max_heap = BinaryHeap::new();
+        for &value in &values {
+            min_heap.push(value);
+            max_heap.push(value);
+        }
+        assert_eq!(min_heap.len(), 500);
+        assert_eq!(min_heap.peek(), expected.first());
+        assert_eq!(max_heap.peek(), expected.last());
+
+        assert_eq!(drain(min_heap), expected);
+        expected.reverse();
+        assert_eq!(drain(max_heap), expected);
+    }
+
+    #[test]
+    fn test_vacio() {
+        let mut heap: BinaryHeap<u32> = BinaryHeap::default();
+        assert!(heap.is_empty());
+        assert_eq!(heap.peek(), None);
+        assert_eq!(heap.pop(), None);
+        assert!(heap.into_sorted_vec().is_empty());
+    }
+
+    #[test]
+    fn test_heapify_e_into_sorted_vec() {
+        for len in 0..40 {
+            let values = random_values(len, 1_000, len as u64);
+            let mut expected = values.clone();
+            expected.sort();
+
+            let heap = BinaryHeap::from(values.clone());
+            assert_eq!(heap.into_sorted_vec(), expected);
+
+            // Con mínimos, el orden "ascendente" del comparador es al revés
+            let heap = BinaryHeap::heapify(values, Min);
+            expected.reverse();
+            assert_eq!(heap.into_sorted_vec(), expected);
+        }
+    }
+
+    #[test]
+    fn test_comparador_propio() {
+        // Pares (prioridad, nombre): primero la menor prioridad
+        let tasks = vec![(3, "c"), (1, "a"), (4, "d"), (2, "b")];
+        let by_priority = |a: &(u32, &str), b: &(u32, &str)| b.0.cmp(&a.0);
+        let heap = BinaryHeap::heapify(tasks, by_priority);
+        let names: Vec<&str> = drain(heap).into_iter().map(|(_, name)| name).collect();
+        assert_eq!(names, ["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn test_merge() {
+        // Tamaños parejos (reconstruye) y muy distintos (inserta)
+        for (first_len, second_len) in
+            [(0, 0), (0, 10), (10, 0), (300, 250), (1_000, 3), (3, 1_000)]
+        {
+            let first = random_values(first_len, 1_000, 1);
+            let second = random_values(second_len, 1_000, 2);
+            let mut expected: Vec<u32> = first.iter().chain(&second).copied().collect();
+            expected.sort();
+
+            let mut heap = BinaryHeap::heapify(first, Min);
+            let mut other = BinaryHeap::heapify(second, Min);
+            heap.merge(&mut other);
+            assert!(other.is_empty());
+            assert_eq!(drain(heap), expected);
+        }
+    }
+}
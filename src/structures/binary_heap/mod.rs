@@ -0,0 +1,2 @@
+pub mod comparator;
+pub mod heap;
@@ -1,3 +1,4 @@
+pub mod binary_heap;
 pub mod circular_buffer;
 pub mod queue;
 pub mod stack;